@@ -17,14 +17,18 @@ pub fn pydefault(_: TokenStream, item: TokenStream) -> TokenStream {
     let item = parse_macro_input!(item as Item);
     let python_new = quote! {
         #[new]
-        fn python_new(str: &str) -> Self {
-            Self::new(str)
+        fn python_new(str: &str) -> PyResult<Self> {
+            Ok(Self::new(str)?)
         }
     };
     let repr = quote! {
         fn __repr__(&self) -> String {
             format!("{:?}", self)
         }
+
+        fn __str__(&self) -> String {
+            self.to_string()
+        }
     };
     if let Some(ident) = extract_ident(&item) {
         let expander = quote! {
@@ -0,0 +1,294 @@
+//! Biological assemblies built from the BIOMT operators of REMARK 350.
+//!
+//! A PDB file holds the asymmetric unit, which need not be the functional molecule: the
+//! biological assembly may contain only some of its chains, or several copies of them.
+//! [`build`] applies the BIOMT operators of a [`BiologicalAssembly`] to the chains they
+//! name and returns the result as a new [`Structure`].
+//!
+//! Every copy of a chain must have an identifier of its own. The first copy of each chain
+//! keeps its identifier and later copies take ones from `A`–`Z`, `a`–`z` and `0`–`9`
+//! that are not used in the asymmetric unit. Once those run out, copies get the source
+//! chain identifier followed by the operator serial number, which is too long for the
+//! chain column and is stored in [`AtomRecord::auth_chain`] instead, as the mmCIF reader
+//! does. [`Assembly::chains`] records where each chain came from.
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::{assembly, reader::ReaderOptions};
+//!
+//! let contents = "\
+//! REMARK 350 BIOMOLECULE: 1
+//! REMARK 350 APPLY THE FOLLOWING TO CHAINS: A
+//! REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        0.00000
+//! REMARK 350   BIOMT2   1  0.000000  1.000000  0.000000        0.00000
+//! REMARK 350   BIOMT3   1  0.000000  0.000000  1.000000        0.00000
+//! REMARK 350   BIOMT1   2 -1.000000  0.000000  0.000000        0.00000
+//! REMARK 350   BIOMT2   2  0.000000 -1.000000  0.000000        0.00000
+//! REMARK 350   BIOMT3   2  0.000000  0.000000  1.000000        0.00000
+//! ATOM      1  CA  GLY A   1       5.000   0.000   0.000  1.00  0.00           C
+//! ATOM      2  CA  GLY B   1       0.000   9.000   0.000  1.00  0.00           C
+//! ";
+//! let records = ReaderOptions::new().read(contents).unwrap().records;
+//! let assemblies = assembly::build_all(&records);
+//!
+//! let chains = assemblies[0].structure.models()[0].chains();
+//! assert_eq!(chains.len(), 2);
+//! assert_eq!(chains[1].id, Some('C'));
+//! assert_eq!(chains[1].atoms().next().unwrap().x, -5.0);
+//! ```
+
+use crate::records::{atom::AtomRecord, Record};
+use crate::remarks::{BiologicalAssembly, Remarks};
+use crate::structure::Structure;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chain identifiers handed out to copies, in order.
+const CHAIN_IDS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// A biological assembly generated from the asymmetric unit.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Assembly {
+    /// Assembly number from REMARK 350
+    pub id: u32,
+    /// The assembled chains, in a single model
+    pub structure: Structure,
+    /// Origin of each chain in the assembly, in the order the chains were added
+    pub chains: Vec<ChainCopy>,
+}
+
+/// One chain of an assembly and the chain and operator it was generated from.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChainCopy {
+    /// Chain identifier in the asymmetric unit
+    pub source: String,
+    /// Serial number of the BIOMT operator applied
+    pub operator: u32,
+    /// Chain identifier in the assembly
+    pub chain: String,
+}
+
+/// The identifier of the chain an atom belongs to, falling back to the full chain
+/// identifier for those too long for the chain column.
+fn chain_label(atom: &AtomRecord) -> Option<String> {
+    match atom.chain_id {
+        Some(id) => Some(id.to_string()),
+        None => atom.auth_chain.clone(),
+    }
+}
+
+/// The ATOM and HETATM records of the first model in `records`, with whether each is a
+/// HETATM.
+fn first_model(records: &[Record]) -> Vec<(&AtomRecord, bool)> {
+    let mut atoms = Vec::new();
+    for record in records {
+        match record {
+            Record::Atom(atom) => atoms.push((atom, false)),
+            Record::Hetatm(atom) => atoms.push((atom, true)),
+            Record::Endmdl() if !atoms.is_empty() => break,
+            _ => {}
+        }
+    }
+    atoms
+}
+
+/// Build a biological assembly from the atoms of the first model in `records`.
+///
+/// Each operator is applied to the chains listed with it, in the order of REMARK 350.
+/// Chains named in REMARK 350 but absent from the coordinates are skipped.
+pub fn build(records: &[Record], assembly: &BiologicalAssembly) -> Assembly {
+    // Group the atoms by chain once, keeping the order in which chains first appear.
+    let mut groups: Vec<(String, Vec<(&AtomRecord, bool)>)> = Vec::new();
+    for (atom, hetero) in first_model(records) {
+        let Some(label) = chain_label(atom) else {
+            continue;
+        };
+        match groups.iter_mut().find(|(chain, _)| *chain == label) {
+            Some((_, atoms)) => atoms.push((atom, hetero)),
+            None => groups.push((label, vec![(atom, hetero)])),
+        }
+    }
+    let reserved: Vec<&String> = assembly
+        .transforms
+        .iter()
+        .flat_map(|transform| transform.chains.iter())
+        .chain(groups.iter().map(|(label, _)| label))
+        .collect();
+    let mut spare = CHAIN_IDS
+        .chars()
+        .map(String::from)
+        .filter(|id| !reserved.contains(&id));
+
+    let mut assembled = Vec::new();
+    let mut chains: Vec<ChainCopy> = Vec::new();
+    for transform in &assembly.transforms {
+        for operator in &transform.operators {
+            let matrix = operator.transform();
+            for source in &transform.chains {
+                let Some((_, selected)) = groups.iter().find(|(label, _)| label == source) else {
+                    continue;
+                };
+                let chain = match chains.iter().any(|copy| copy.chain == *source) {
+                    false => source.clone(),
+                    true => spare
+                        .next()
+                        .unwrap_or_else(|| format!("{}{}", source, operator.serial)),
+                };
+                let mut id = chain.chars();
+                let chain_id = match (id.next(), id.next()) {
+                    (Some(id), None) => Some(id),
+                    _ => None,
+                };
+                let auth_chain = chain_id.is_none().then(|| chain.clone());
+                for (atom, hetero) in selected {
+                    let mut atom = (*atom).clone();
+                    matrix.apply_atom(&mut atom);
+                    atom.chain_id = chain_id;
+                    atom.auth_chain = auth_chain.clone();
+                    assembled.push(match hetero {
+                        true => Record::Hetatm(atom),
+                        false => Record::Atom(atom),
+                    });
+                }
+                chains.push(ChainCopy {
+                    source: source.clone(),
+                    operator: operator.serial,
+                    chain,
+                });
+            }
+        }
+    }
+
+    Assembly {
+        id: assembly.id,
+        structure: Structure::new(&assembled),
+        chains,
+    }
+}
+
+/// Build every biological assembly described in the REMARK 350 records of `records`.
+///
+/// Malformed REMARK rows are skipped as in [`Remarks::new`], so the assemblies are built
+/// from whatever BIOMT operators could be read.
+pub fn build_all(records: &[Record]) -> Vec<Assembly> {
+    Remarks::new(records)
+        .assemblies
+        .iter()
+        .map(|assembly| build(records, assembly))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderOptions;
+
+    const CONTENTS: &str = "\
+REMARK 350 BIOMOLECULE: 1
+REMARK 350 APPLY THE FOLLOWING TO CHAINS: A, B, E
+REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        0.00000
+REMARK 350   BIOMT2   1  0.000000  1.000000  0.000000        0.00000
+REMARK 350   BIOMT3   1  0.000000  0.000000  1.000000        0.00000
+REMARK 350   BIOMT1   2  1.000000  0.000000  0.000000       10.00000
+REMARK 350   BIOMT2   2  0.000000  1.000000  0.000000        0.00000
+REMARK 350   BIOMT3   2  0.000000  0.000000  1.000000        0.00000
+REMARK 350 BIOMOLECULE: 2
+REMARK 350 APPLY THE FOLLOWING TO CHAINS: C
+REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        0.00000
+REMARK 350   BIOMT2   1  0.000000  1.000000  0.000000        0.00000
+REMARK 350   BIOMT3   1  0.000000  0.000000  1.000000        0.00000
+MODEL        1
+ATOM      1  CA  GLY A   1       1.000   2.000   3.000  1.00  0.00           C
+ATOM      2  CA  GLY B   1       4.000   5.000   6.000  1.00  0.00           C
+HETATM    3  ZN   ZN B 101       7.000   8.000   9.000  1.00  0.00          ZN
+ATOM      4  CA  GLY C   1       0.000   0.000   0.000  1.00  0.00           C
+ENDMDL
+MODEL        2
+ATOM      1  CA  GLY A   1      11.000  12.000  13.000  1.00  0.00           C
+ENDMDL
+";
+
+    #[test]
+    fn test_build_all() {
+        let records = ReaderOptions::new().read(CONTENTS).unwrap().records;
+        let assemblies = build_all(&records);
+        assert_eq!(assemblies.len(), 2);
+
+        let assembly = &assemblies[0];
+        assert_eq!(assembly.id, 1);
+        let labels: Vec<(&str, u32, &str)> = assembly
+            .chains
+            .iter()
+            .map(|copy| (copy.source.as_str(), copy.operator, copy.chain.as_str()))
+            .collect();
+        assert_eq!(
+            labels,
+            [("A", 1, "A"), ("B", 1, "B"), ("A", 2, "D"), ("B", 2, "F")]
+        );
+
+        let model = &assembly.structure.models()[0];
+        assert_eq!(assembly.structure.models().len(), 1);
+        assert_eq!(model.atoms().count(), 6);
+        let copy = model.chain('F').unwrap();
+        assert!(copy.residues()[1].hetero);
+        assert_eq!(copy.residues()[1].atoms[0].x, 17.0);
+        assert_eq!(model.chain('D').unwrap().atoms().next().unwrap().x, 11.0);
+
+        let model = &assemblies[1].structure.models()[0];
+        assert_eq!(model.chains().len(), 1);
+        assert_eq!(model.chains()[0].id, Some('C'));
+
+        // A malformed BIOMT row is skipped and the assemblies are still built.
+        let contents = CONTENTS.replace(
+            "REMARK 350 BIOMOLECULE: 2\n",
+            "REMARK 350   BIOMT1   3  1.0000x0  0.000000  0.000000        0.00000\n\
+             REMARK 350 BIOMOLECULE: 2\n",
+        );
+        let records = ReaderOptions::new().read(&contents).unwrap().records;
+        let assemblies = build_all(&records);
+        assert_eq!(assemblies.len(), 2);
+        assert_eq!(assemblies[0].chains.len(), 4);
+    }
+
+    #[test]
+    fn test_build_long_labels() {
+        let records = ReaderOptions::new().read(CONTENTS).unwrap().records;
+        let mut assembly = Remarks::new(&records).assemblies.remove(0);
+        let operator = assembly.transforms[0].operators[0].clone();
+        assembly.transforms[0].operators = (1..=70)
+            .map(|serial| crate::remarks::BiomtOperator {
+                serial,
+                ..operator.clone()
+            })
+            .collect();
+        assembly.transforms[0].chains = vec!["A".to_string()];
+
+        let built = build(&records, &assembly);
+        assert_eq!(built.chains.len(), 70);
+        // 62 identifiers less A, B and C used in the asymmetric unit.
+        assert_eq!(built.chains[59].chain, "9");
+        assert_eq!(built.chains[60].chain, "A61");
+        let long: Vec<_> = built
+            .structure
+            .atoms()
+            .filter(|atom| atom.chain_id.is_none())
+            .collect();
+        assert_eq!(long.len(), 10);
+        assert_eq!(long[0].auth_chain.as_deref(), Some("A61"));
+        assert_eq!(long[0].segment_id, None);
+
+        // Every copy is a chain of its own, holding the one residue of chain A.
+        let model = &built.structure.models()[0];
+        assert_eq!(model.chains().len(), 70);
+        assert!(model
+            .chains()
+            .iter()
+            .all(|chain| chain.residues().len() == 1 && chain.atoms().count() == 1));
+        let chain = model.chain_by_label("A70").unwrap();
+        assert_eq!((chain.id, chain.auth_chain.as_deref()), (None, Some("A70")));
+    }
+}
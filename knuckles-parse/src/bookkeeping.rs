@@ -0,0 +1,171 @@
+//! Completeness checks against the MASTER and END bookkeeping records.
+//!
+//! A PDB file ends with a MASTER record counting the records of selected types, followed
+//! by END. A file that was cut short, for example by an interrupted download, lacks both
+//! or has fewer records than MASTER announces. [`validate`] reports every such
+//! discrepancy.
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::{bookkeeping::{self, BookkeepingIssue}, reader::ReaderOptions};
+//!
+//! let contents = "\
+//! ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N
+//! MASTER        0    0    0    0    0    0    0    0    2    0    0    0
+//! END
+//! ";
+//! let report = ReaderOptions::new().read(contents).unwrap();
+//!
+//! assert_eq!(
+//!     bookkeeping::validate(&report.records),
+//!     vec![BookkeepingIssue::CountMismatch {
+//!         record: "ATOM/HETATM",
+//!         expected: 2,
+//!         found: 1,
+//!     }]
+//! );
+//! ```
+
+use crate::records::{master::MasterRecord, Record};
+
+/// A discrepancy between the bookkeeping records and the rest of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookkeepingIssue {
+    /// The file has no MASTER record
+    MissingMaster,
+    /// The file has no END record
+    MissingEnd,
+    /// Records follow the END record
+    TrailingRecords,
+    /// MASTER announces a different number of records of a type than were read
+    CountMismatch {
+        /// Record type or types being counted
+        record: &'static str,
+        /// Count given in the MASTER record
+        expected: u32,
+        /// Number of records read
+        found: u32,
+    },
+}
+
+impl std::fmt::Display for BookkeepingIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BookkeepingIssue::MissingMaster => write!(f, "no MASTER record"),
+            BookkeepingIssue::MissingEnd => write!(f, "no END record"),
+            BookkeepingIssue::TrailingRecords => write!(f, "records follow END"),
+            BookkeepingIssue::CountMismatch {
+                record,
+                expected,
+                found,
+            } => write!(
+                f,
+                "MASTER counts {} {} records but {} were read",
+                expected, record, found
+            ),
+        }
+    }
+}
+
+/// Check `records` against their MASTER and END records.
+///
+/// Without a MASTER record only the END record is checked. TURN records are deprecated
+/// and not parsed by this crate, so the TURN count is not compared. An empty result means
+/// the file is complete as far as its bookkeeping records can tell.
+pub fn validate(records: &[Record]) -> Vec<BookkeepingIssue> {
+    let mut issues = Vec::new();
+    match records
+        .iter()
+        .position(|record| matches!(record, Record::End()))
+    {
+        Some(end) if end + 1 < records.len() => issues.push(BookkeepingIssue::TrailingRecords),
+        Some(_) => {}
+        None => issues.push(BookkeepingIssue::MissingEnd),
+    }
+    let Some(master) = records.iter().find_map(|record| match record {
+        Record::Master(master) => Some(master),
+        _ => None,
+    }) else {
+        issues.insert(0, BookkeepingIssue::MissingMaster);
+        return issues;
+    };
+    let found = MasterRecord::from_records(records);
+    for ((record, expected), (_, found)) in master.counts().into_iter().zip(found.counts()) {
+        if record != "TURN" && expected != found {
+            issues.push(BookkeepingIssue::CountMismatch {
+                record,
+                expected,
+                found,
+            });
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderOptions;
+
+    const CONTENTS: &str = "\
+REMARK   2 RESOLUTION.    1.80 ANGSTROMS.
+SEQRES   1 A    2  MET ALA
+HELIX    1   1 MET A    1  ALA A    2  1                                   2
+ATOM      1  N   MET A   1      27.340  24.430   2.614  1.00  9.67           N
+ATOM      2  CA  ALA A   2      27.340  24.430   2.614  1.00  9.67           C
+TER       3      ALA A   2
+HETATM    4  O   HOH A 101      15.123  12.456  30.789  1.00 25.50           O
+CONECT    4    2
+MASTER        1    0    0    1    0    0    0    0    3    1    1    1
+END
+";
+
+    fn read(contents: &str) -> Vec<Record> {
+        ReaderOptions::new().read(contents).unwrap().records
+    }
+
+    #[test]
+    fn test_validate_complete() {
+        let records = read(CONTENTS);
+        assert_eq!(validate(&records), vec![]);
+        assert_eq!(
+            MasterRecord::from_records(&records).to_string(),
+            format!(
+                "{:<80}",
+                "MASTER        1    0    0    1    0    0    0    0    3    1    1    1"
+            )
+        );
+    }
+
+    #[test]
+    fn test_validate_truncated() {
+        let truncated: String = CONTENTS
+            .lines()
+            .take(4)
+            .map(|line| line.to_owned() + "\n")
+            .collect();
+        assert_eq!(
+            validate(&read(&truncated)),
+            vec![
+                BookkeepingIssue::MissingMaster,
+                BookkeepingIssue::MissingEnd
+            ]
+        );
+
+        let mut records = read(CONTENTS);
+        records.remove(4);
+        records.push(Record::End());
+        assert_eq!(
+            validate(&records),
+            vec![
+                BookkeepingIssue::TrailingRecords,
+                BookkeepingIssue::CountMismatch {
+                    record: "ATOM/HETATM",
+                    expected: 3,
+                    found: 2,
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//! Opening PDB and mmCIF files that may be compressed.
+//!
+//! Compression is detected from the magic bytes at the start of the stream rather than
+//! the file extension, so `pdb1abc.ent.gz`, `1abc.cif.zst` and plain files can all be
+//! opened the same way. Each format is decoded only when its cargo feature is enabled:
+//!
+//! | Format | Feature |
+//! |---|---|
+//! | gzip | `gzip` |
+//! | bzip2 | `bzip2` |
+//! | Zstandard | `zstd` |
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use knuckles_parse::{compression, reader::PdbReader};
+//!
+//! let reader = compression::open("pdb1abc.ent.gz").unwrap();
+//! for record in PdbReader::new(reader) {
+//!     println!("{}", record.unwrap());
+//! }
+//! ```
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+/// Compression formats that can be detected from a stream's magic bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// Uncompressed data
+    None,
+    /// gzip (`1f 8b`)
+    Gzip,
+    /// bzip2 (`BZh`)
+    Bzip2,
+    /// Zstandard (`28 b5 2f fd`)
+    Zstd,
+}
+
+impl Compression {
+    /// Detect the compression format from the first bytes of a stream.
+    ///
+    /// Anything without a recognized magic number is treated as uncompressed.
+    pub fn detect(header: &[u8]) -> Compression {
+        if header.starts_with(&[0x1f, 0x8b]) {
+            Compression::Gzip
+        } else if header.starts_with(b"BZh") {
+            Compression::Bzip2
+        } else if header.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Compression::Zstd
+        } else {
+            Compression::None
+        }
+    }
+
+    /// The cargo feature that enables decoding this format.
+    fn feature(&self) -> &'static str {
+        match self {
+            Compression::None => "",
+            Compression::Gzip => "gzip",
+            Compression::Bzip2 => "bzip2",
+            Compression::Zstd => "zstd",
+        }
+    }
+}
+
+/// Wrap a buffered stream so that it yields decompressed data.
+///
+/// # Errors
+///
+/// Returns an [`Unsupported`](std::io::ErrorKind::Unsupported) error if the stream is
+/// compressed with a format whose feature is not enabled, or any I/O error raised while
+/// reading the header.
+pub fn decompress<'a, R: BufRead + 'a>(mut reader: R) -> std::io::Result<Box<dyn BufRead + 'a>> {
+    let compression = Compression::detect(reader.fill_buf()?);
+    match compression {
+        Compression::None => Ok(Box::new(reader)),
+        #[cfg(feature = "gzip")]
+        Compression::Gzip => Ok(Box::new(BufReader::new(
+            flate2::bufread::MultiGzDecoder::new(reader),
+        ))),
+        #[cfg(feature = "bzip2")]
+        Compression::Bzip2 => Ok(Box::new(BufReader::new(
+            bzip2::bufread::MultiBzDecoder::new(reader),
+        ))),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => Ok(Box::new(BufReader::new(zstd::Decoder::with_buffer(
+            reader,
+        )?))),
+        #[allow(unreachable_patterns)]
+        compression => Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            format!(
+                "{:?} input requires the `{}` feature of knuckles-parse",
+                compression,
+                compression.feature()
+            ),
+        )),
+    }
+}
+
+/// Open a file for buffered reading, decompressing it if necessary.
+///
+/// # Errors
+///
+/// Returns any error from opening the file or from [`decompress`].
+pub fn open(path: impl AsRef<Path>) -> std::io::Result<Box<dyn BufRead>> {
+    decompress(BufReader::new(File::open(path)?))
+}
+
+/// Read a whole file into a string, decompressing it if necessary.
+///
+/// This suits the in-memory readers such as [`crate::pdbreader_single`]; use [`open`]
+/// with [`crate::reader::PdbReader`] to avoid holding the file in memory.
+///
+/// # Errors
+///
+/// Returns any error from [`open`], or an error if the contents are not valid UTF-8.
+pub fn read_to_string(path: impl AsRef<Path>) -> std::io::Result<String> {
+    let mut contents = String::new();
+    open(path)?.read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENTS: &str =
+        "ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N\n";
+
+    fn read(data: &[u8]) -> std::io::Result<String> {
+        let mut contents = String::new();
+        decompress(data)?.read_to_string(&mut contents)?;
+        Ok(contents)
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(Compression::detect(&[0x1f, 0x8b, 0x08]), Compression::Gzip);
+        assert_eq!(Compression::detect(b"BZh91AY"), Compression::Bzip2);
+        assert_eq!(
+            Compression::detect(&[0x28, 0xb5, 0x2f, 0xfd]),
+            Compression::Zstd
+        );
+        assert_eq!(Compression::detect(b"ATOM  "), Compression::None);
+        assert_eq!(Compression::detect(b""), Compression::None);
+    }
+
+    #[test]
+    fn test_decompress_plain() {
+        assert_eq!(read(CONTENTS.as_bytes()).unwrap(), CONTENTS);
+        assert_eq!(read(b"").unwrap(), "");
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_decompress_gzip() {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(CONTENTS.as_bytes()).unwrap();
+        assert_eq!(read(&encoder.finish().unwrap()).unwrap(), CONTENTS);
+    }
+
+    #[cfg(feature = "bzip2")]
+    #[test]
+    fn test_decompress_bzip2() {
+        use std::io::Write;
+
+        let mut encoder = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::fast());
+        encoder.write_all(CONTENTS.as_bytes()).unwrap();
+        assert_eq!(read(&encoder.finish().unwrap()).unwrap(), CONTENTS);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_decompress_zstd() {
+        let compressed = zstd::encode_all(CONTENTS.as_bytes(), 0).unwrap();
+        assert_eq!(read(&compressed).unwrap(), CONTENTS);
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn test_decompress_unsupported() {
+        let error = read(&[0x1f, 0x8b, 0x08]).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::Unsupported);
+    }
+}
@@ -0,0 +1,314 @@
+//! Error types produced while parsing PDB records.
+
+use std::ops::Range;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// The reason a field or line could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The line ends before the columns of a required field.
+    TooShort,
+    /// A required field is blank.
+    Missing,
+    /// A numeric field does not contain a valid number.
+    InvalidNumber,
+    /// A field holds a value that is not allowed for it.
+    InvalidValue,
+    /// The underlying reader failed; the error text holds the I/O error message.
+    Io,
+}
+
+impl std::fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            ParseErrorKind::TooShort => "line is too short",
+            ParseErrorKind::Missing => "required field is blank",
+            ParseErrorKind::InvalidNumber => "invalid number",
+            ParseErrorKind::InvalidValue => "invalid value",
+            ParseErrorKind::Io => "I/O error",
+        };
+        write!(f, "{}", reason)
+    }
+}
+
+/// An error describing where and why a PDB line failed to parse.
+///
+/// Column ranges are zero-based and half-open, matching the byte ranges used by the
+/// record parsers; the [`Display`](std::fmt::Display) output converts them to the
+/// one-based, inclusive columns used in the PDB format specification.
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::error::ParseErrorKind;
+/// use knuckles_parse::records::atom::AtomRecord;
+///
+/// let line = "ATOM      1  N   ALA A   1      20.154  abcdefg  27.462  1.00 11.18           N";
+/// let error = AtomRecord::try_from(line).unwrap_err();
+///
+/// assert_eq!(error.record, "ATOM");
+/// assert_eq!(error.columns, 38..46);
+/// assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Name of the record being parsed (e.g. "ATOM", "SEQRES")
+    pub record: &'static str,
+    /// One-based line number, if known
+    pub line: Option<usize>,
+    /// Zero-based, half-open column range of the offending field
+    pub columns: Range<usize>,
+    /// Text found in the offending field
+    pub text: String,
+    /// Reason the field could not be parsed
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// Create a new ParseError without a line number.
+    pub fn new(
+        record: &'static str,
+        columns: Range<usize>,
+        text: impl Into<String>,
+        kind: ParseErrorKind,
+    ) -> Self {
+        ParseError {
+            record,
+            line: None,
+            columns,
+            text: text.into(),
+            kind,
+        }
+    }
+
+    /// Attach a one-based line number to the error.
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        write!(
+            f,
+            "{} columns {}-{}: {}",
+            self.record,
+            self.columns.start + 1,
+            self.columns.end,
+            self.kind
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(feature = "python")]
+impl From<ParseError> for PyErr {
+    fn from(error: ParseError) -> Self {
+        pyo3::exceptions::PyValueError::new_err(error.to_string())
+    }
+}
+
+/// Fixed-column field accessor used by the record parsers.
+///
+/// Every accessor reports failures as a [`ParseError`] tagged with the record name and
+/// the columns of the field, so parsers never need to slice or unwrap directly.
+pub(crate) struct Columns<'a> {
+    record: &'static str,
+    line: &'a str,
+}
+
+impl<'a> Columns<'a> {
+    pub(crate) fn new(record: &'static str, line: &'a str) -> Self {
+        Columns { record, line }
+    }
+
+    pub(crate) fn error(&self, columns: Range<usize>, kind: ParseErrorKind) -> ParseError {
+        let text = self
+            .line
+            .get(columns.start.min(self.line.len())..columns.end.min(self.line.len()))
+            .unwrap_or_default()
+            .trim();
+        ParseError::new(self.record, columns, text, kind)
+    }
+
+    /// The raw, untrimmed text of a field, or `None` if the line does not reach it.
+    pub(crate) fn get(&self, columns: Range<usize>) -> Option<&'a str> {
+        self.line.get(columns)
+    }
+
+    /// The raw text of a required field.
+    pub(crate) fn raw(&self, columns: Range<usize>) -> Result<&'a str, ParseError> {
+        match self.line.get(columns.clone()) {
+            Some(text) => Ok(text),
+            None if self.line.len() < columns.end => {
+                Err(self.error(columns, ParseErrorKind::TooShort))
+            }
+            None => Err(self.error(columns, ParseErrorKind::InvalidValue)),
+        }
+    }
+
+    /// The trimmed text of a required field, which may be blank.
+    pub(crate) fn str(&self, columns: Range<usize>) -> Result<&'a str, ParseError> {
+        self.raw(columns).map(str::trim)
+    }
+
+    /// The trimmed text of a field that may be absent or blank.
+    pub(crate) fn opt_str(&self, columns: Range<usize>) -> Option<String> {
+        self.get(columns)
+            .map(|str| str.trim().to_string())
+            .filter(|item| !item.is_empty())
+    }
+
+    /// The trimmed text of a field that may be absent, blank or cut short by the end of
+    /// the line, as happens to trailing free-text fields when whitespace is stripped.
+    pub(crate) fn trailing_str(&self, columns: Range<usize>) -> Option<String> {
+        let end = columns.end.min(self.line.len());
+        self.get(columns.start..end)
+            .map(|str| str.trim().to_string())
+            .filter(|item| !item.is_empty())
+    }
+
+    /// The trimmed text of a field from `start` to the end of the line.
+    pub(crate) fn rest(&self, start: usize) -> String {
+        self.line
+            .get(start..)
+            .unwrap_or_default()
+            .trim()
+            .to_string()
+    }
+
+    /// A required, non-blank field parsed as `T`.
+    pub(crate) fn parse<T: std::str::FromStr>(
+        &self,
+        columns: Range<usize>,
+    ) -> Result<T, ParseError> {
+        let text = self.str(columns.clone())?;
+        if text.is_empty() {
+            return Err(self.error(columns, ParseErrorKind::Missing));
+        }
+        text.parse()
+            .map_err(|_| self.error(columns, ParseErrorKind::InvalidNumber))
+    }
+
+    /// An optional field parsed as `T`; blank fields are `None`, malformed ones are errors.
+    pub(crate) fn opt_parse<T: std::str::FromStr>(
+        &self,
+        columns: Range<usize>,
+    ) -> Result<Option<T>, ParseError> {
+        match self.get(columns.clone()).map(str::trim) {
+            None | Some("") => Ok(None),
+            Some(text) => text
+                .parse()
+                .map(Some)
+                .map_err(|_| self.error(columns, ParseErrorKind::InvalidNumber)),
+        }
+    }
+
+    /// A field parsed as `T`, falling back to the default when absent or malformed.
+    pub(crate) fn parse_or_default<T: std::str::FromStr + Default>(
+        &self,
+        columns: Range<usize>,
+    ) -> T {
+        self.get(columns)
+            .and_then(|str| str.trim().parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// A required, non-blank [hybrid-36](crate::hybrid36) field converted to `T`.
+    pub(crate) fn hybrid36<T: TryFrom<i32>>(&self, columns: Range<usize>) -> Result<T, ParseError> {
+        self.raw(columns.clone())?;
+        self.opt_hybrid36(columns.clone())?
+            .ok_or_else(|| self.error(columns, ParseErrorKind::Missing))
+    }
+
+    /// An optional [hybrid-36](crate::hybrid36) field; blank fields are `None`.
+    pub(crate) fn opt_hybrid36<T: TryFrom<i32>>(
+        &self,
+        columns: Range<usize>,
+    ) -> Result<Option<T>, ParseError> {
+        match self.get(columns.clone()).map(str::trim) {
+            None | Some("") => Ok(None),
+            Some(text) => crate::hybrid36::decode(columns.len(), text)
+                .and_then(|value| T::try_from(value).ok())
+                .map(Some)
+                .ok_or_else(|| self.error(columns, ParseErrorKind::InvalidNumber)),
+        }
+    }
+
+    /// A [hybrid-36](crate::hybrid36) field, falling back to the default when absent or
+    /// malformed.
+    pub(crate) fn hybrid36_or_default<T: TryFrom<i32> + Default>(
+        &self,
+        columns: Range<usize>,
+    ) -> T {
+        self.opt_hybrid36(columns)
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// A required single-character field, which may be a blank.
+    pub(crate) fn char(&self, column: usize) -> Result<char, ParseError> {
+        self.raw(column..column + 1)?
+            .chars()
+            .next()
+            .ok_or_else(|| self.error(column..column + 1, ParseErrorKind::TooShort))
+    }
+
+    /// An optional single-character field; blank or absent characters are `None`.
+    pub(crate) fn opt_char(&self, column: usize) -> Option<char> {
+        self.get(column..column + 1)
+            .and_then(|str| str.chars().next())
+            .filter(|c| *c != ' ')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns_errors() {
+        let columns = Columns::new("TEST", "TEST   12 ab");
+        assert_eq!(columns.parse::<u32>(7..9), Ok(12));
+        let error = columns.parse::<u32>(10..12).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+        assert_eq!(error.text, "ab");
+        let error = columns.parse::<u32>(10..14).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::TooShort);
+        assert_eq!(error.text, "ab");
+        let error = columns.parse::<u32>(4..7).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::Missing);
+        assert_eq!(columns.opt_parse::<u32>(4..7), Ok(None));
+        assert_eq!(columns.opt_char(20), None);
+        assert_eq!(columns.char(0), Ok('T'));
+
+        let columns = Columns::new("TEST", "TEST A000 -1 1a");
+        assert_eq!(columns.hybrid36::<i32>(5..9), Ok(10000));
+        assert_eq!(columns.opt_hybrid36::<u32>(10..12).unwrap_err().text, "-1");
+        assert_eq!(columns.hybrid36_or_default::<u32>(10..12), 0);
+        let error = columns.hybrid36::<i32>(13..15).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+    }
+
+    #[test]
+    fn test_error_display() {
+        let error =
+            ParseError::new("ATOM", 22..26, "12x", ParseErrorKind::InvalidNumber).with_line(7);
+        assert_eq!(
+            error.to_string(),
+            "line 7: ATOM columns 23-26: invalid number \"12x\""
+        );
+    }
+}
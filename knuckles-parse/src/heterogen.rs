@@ -0,0 +1,184 @@
+//! Per-compound descriptions assembled from the heterogen section of a PDB file.
+//!
+//! The heterogen section spreads the description of each non-standard residue over
+//! several records: HET gives every occurrence, HETNAM the chemical name, HETSYN its
+//! synonyms and FORMUL the formula. Names and synonyms may continue over several lines.
+//! [`describe`] collects them into one [`HetDescription`] per hetero-compound identifier.
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::{heterogen, reader::ReaderOptions};
+//!
+//! let contents = "\
+//! HET    NAG  A 501      14                                                       
+//! HETNAM     NAG 2-ACETAMIDO-2-DEOXY-BETA-D-
+//! HETNAM   2 NAG GLUCOPYRANOSE
+//! HETSYN     NAG N-ACETYL-BETA-D-GLUCOSAMINE; N-ACETYL-D-GLUCOSAMINE
+//! FORMUL   2  NAG    C8 H15 N O6
+//! ";
+//! let report = ReaderOptions::new().read(contents).unwrap();
+//! let descriptions = heterogen::describe(&report.records);
+//!
+//! assert_eq!(descriptions[0].name, "2-ACETAMIDO-2-DEOXY-BETA-D-GLUCOPYRANOSE");
+//! assert_eq!(descriptions[0].synonyms.len(), 2);
+//! assert_eq!(descriptions[0].formula.as_deref(), Some("C8 H15 N O6"));
+//! assert_eq!(descriptions[0].count, 1);
+//! ```
+
+use crate::records::Record;
+use crate::title::join_continued;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Everything the heterogen section says about one hetero-compound.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HetDescription {
+    /// Hetero-compound identifier
+    pub het_id: String,
+    /// Chemical name, with continuation lines merged
+    pub name: String,
+    /// Synonyms of the chemical name
+    pub synonyms: Vec<String>,
+    /// Chemical formula, without the leading number of occurrences
+    pub formula: Option<String>,
+    /// Number of occurrences, taken from FORMUL when given and otherwise from the number
+    /// of HET records
+    pub count: usize,
+    /// Whether FORMUL marks the compound as water
+    pub water: bool,
+}
+
+/// Collected lines of a single hetero-compound.
+#[derive(Default)]
+struct HetLines<'a> {
+    het_id: &'a str,
+    occurrences: usize,
+    name: Vec<&'a str>,
+    synonyms: Vec<&'a str>,
+    formula: Vec<&'a str>,
+    water: bool,
+}
+
+/// Describe every hetero-compound in the heterogen section of `records`.
+///
+/// Compounds are returned in order of first mention in any of the HET, HETNAM, HETSYN
+/// or FORMUL records, so water, which has a FORMUL record but no HET records, is
+/// included.
+pub fn describe(records: &[Record]) -> Vec<HetDescription> {
+    let mut compounds: Vec<HetLines> = Vec::new();
+    for record in records {
+        match record {
+            Record::Het(het) => entry(&mut compounds, &het.het_id).occurrences += 1,
+            Record::Hetnam(hetnam) => entry(&mut compounds, &hetnam.het_id)
+                .name
+                .push(&hetnam.text),
+            Record::Hetsyn(hetsyn) => entry(&mut compounds, &hetsyn.het_id)
+                .synonyms
+                .push(&hetsyn.text),
+            Record::Formul(formul) => {
+                let lines = entry(&mut compounds, &formul.het_id);
+                lines.formula.push(&formul.text);
+                lines.water |= formul.water;
+            }
+            _ => {}
+        }
+    }
+    compounds.into_iter().map(HetLines::describe).collect()
+}
+
+/// Find the lines collected for `het_id`, starting a new entry on first mention.
+fn entry<'a, 'b>(compounds: &'b mut Vec<HetLines<'a>>, het_id: &'a str) -> &'b mut HetLines<'a> {
+    let index = match compounds.iter().position(|lines| lines.het_id == het_id) {
+        Some(index) => index,
+        None => {
+            compounds.push(HetLines {
+                het_id,
+                ..Default::default()
+            });
+            compounds.len() - 1
+        }
+    };
+    &mut compounds[index]
+}
+
+impl HetLines<'_> {
+    fn describe(self) -> HetDescription {
+        let formula = join_continued(&self.formula);
+        let (count, formula) = split_count(&formula);
+        HetDescription {
+            het_id: self.het_id.to_string(),
+            name: join_continued(&self.name),
+            synonyms: join_continued(&self.synonyms)
+                .split(';')
+                .map(str::trim)
+                .filter(|synonym| !synonym.is_empty())
+                .map(String::from)
+                .collect(),
+            formula: (!formula.is_empty()).then(|| formula.to_string()),
+            count: count.unwrap_or(self.occurrences),
+            water: self.water,
+        }
+    }
+}
+
+/// Split a FORMUL formula such as `2(C8 H15 N O6)` into its count and formula.
+fn split_count(formula: &str) -> (Option<usize>, &str) {
+    if let Some((count, rest)) = formula.split_once('(') {
+        if let (Ok(count), Some(inner)) = (count.trim().parse(), rest.strip_suffix(')')) {
+            return (Some(count), inner);
+        }
+    }
+    (None, formula)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        let records: Vec<Record> = "\
+HET    SO4  A 301       5
+HET    SO4  B 302       5
+HET    HV5  A   3      13
+HETNAM     SO4 SULFATE ION
+HETNAM     HV5 3-METHYL-L-VALINE
+HETSYN     HV5 TERT-LEUCINE; (S)-2-AMINO-3,3-
+HETSYN   2 HV5 DIMETHYLBUTANOIC ACID
+FORMUL   2  SO4    2(O4 S 2-)
+FORMUL   3  HV5    C6 H13 N O2
+FORMUL   4  HOH   *150(H2 O)"
+            .lines()
+            .map(|line| Record::try_from(format!("{:<80}", line).as_str()).unwrap())
+            .collect();
+        let descriptions = describe(&records);
+        assert_eq!(descriptions.len(), 3);
+
+        assert_eq!(descriptions[0].het_id, "SO4");
+        assert_eq!(descriptions[0].name, "SULFATE ION");
+        assert_eq!(descriptions[0].formula.as_deref(), Some("O4 S 2-"));
+        assert_eq!(descriptions[0].count, 2);
+
+        assert_eq!(descriptions[1].het_id, "HV5");
+        assert_eq!(
+            descriptions[1].synonyms,
+            vec!["TERT-LEUCINE", "(S)-2-AMINO-3,3-DIMETHYLBUTANOIC ACID"]
+        );
+        assert_eq!(descriptions[1].formula.as_deref(), Some("C6 H13 N O2"));
+        assert_eq!(descriptions[1].count, 1);
+
+        assert_eq!(descriptions[2].het_id, "HOH");
+        assert!(descriptions[2].water);
+        assert_eq!(descriptions[2].count, 150);
+        assert_eq!(descriptions[2].name, "");
+    }
+
+    #[test]
+    fn test_split_count() {
+        assert_eq!(split_count("2(C8 H15 N O6)"), (Some(2), "C8 H15 N O6"));
+        assert_eq!(split_count("C6 H13 N O2"), (None, "C6 H13 N O2"));
+    }
+}
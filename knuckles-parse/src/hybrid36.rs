@@ -0,0 +1,145 @@
+//! Hybrid-36 encoding of numbers in fixed-width PDB fields.
+//!
+//! The wwPDB format only leaves 5 columns for atom serial numbers and 4 for residue
+//! sequence numbers. Hybrid-36, as used by CCTBX/Phenix and many MD packages, keeps
+//! plain decimal numbers while they fit and then continues with base-36 numbers
+//! starting at upper-case letters (`A0000`) followed by lower-case ones (`a0000`):
+//!
+//! | Width | Decimal | Upper-case | Lower-case |
+//! |---|---|---|---|
+//! | 5 | -9999 to 99999 | 100000 to 43770015 | 43770016 to 87440031 |
+//! | 4 | -999 to 9999 | 10000 to 1223055 | 1223056 to 2436111 |
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::hybrid36;
+//!
+//! assert_eq!(hybrid36::decode(5, "A0000"), Some(100000));
+//! assert_eq!(hybrid36::encode(4, 10000), Some("A000".to_string()));
+//! assert_eq!(hybrid36::encode(4, 42), Some("  42".to_string()));
+//! ```
+
+const DIGITS_UPPER: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS_LOWER: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Field widths supported; any `i32` fits in decimal once the field is wider.
+const WIDTHS: std::ops::RangeInclusive<usize> = 1..=9;
+
+/// Decode a hybrid-36 field of `width` columns.
+///
+/// Leading and trailing blanks are ignored. Returns `None` for blank fields, values
+/// that are not valid hybrid-36 for the width, or values that do not fit in an `i32`.
+pub fn decode(width: usize, text: &str) -> Option<i32> {
+    let text = text.trim();
+    let first = *text.as_bytes().first()?;
+    let value = if first.is_ascii_digit() || first == b'-' {
+        text.parse::<i64>().ok()?
+    } else {
+        // Base-36 numbers always fill the whole field.
+        if text.len() != width || !WIDTHS.contains(&width) {
+            return None;
+        }
+        let upper = first.is_ascii_uppercase();
+        let digits = if upper { DIGITS_UPPER } else { DIGITS_LOWER };
+        let mut value: i64 = 0;
+        for byte in text.bytes() {
+            let digit = digits.iter().position(|&d| d == byte)?;
+            value = value * 36 + digit as i64;
+        }
+        let block = 36_i64.pow(width as u32 - 1);
+        let offset = 10_i64.pow(width as u32) - 10 * block;
+        if upper {
+            value + offset
+        } else {
+            value + offset + 26 * block
+        }
+    };
+    i32::try_from(value).ok()
+}
+
+/// Encode `value` as a right-justified hybrid-36 field of `width` columns.
+///
+/// Returns `None` if `width` is not between 1 and 9, or if the value is outside the range
+/// representable in `width` columns.
+pub fn encode(width: usize, value: i32) -> Option<String> {
+    if !WIDTHS.contains(&width) {
+        return None;
+    }
+    let value = value as i64;
+    let decimal_max = 10_i64.pow(width as u32);
+    let decimal_min = -(10_i64.pow(width as u32 - 1) - 1);
+    if (decimal_min..decimal_max).contains(&value) {
+        return Some(format!("{:>1$}", value, width));
+    }
+    if value < decimal_min {
+        return None;
+    }
+    let block = 36_i64.pow(width as u32 - 1);
+    let value = value - decimal_max;
+    let (value, digits) = if value < 26 * block {
+        (value, DIGITS_UPPER)
+    } else if value < 52 * block {
+        (value - 26 * block, DIGITS_LOWER)
+    } else {
+        return None;
+    };
+    let mut value = value + 10 * block;
+    let mut encoded = vec![b'0'; width];
+    for byte in encoded.iter_mut().rev() {
+        *byte = digits[(value % 36) as usize];
+        value /= 36;
+    }
+    String::from_utf8(encoded).ok()
+}
+
+/// Encode `value` for a PDB line, filling the field with `*` when it does not fit.
+pub(crate) fn encode_field(width: usize, value: impl TryInto<i32>) -> String {
+    value
+        .try_into()
+        .ok()
+        .and_then(|value| encode(width, value))
+        .unwrap_or_else(|| "*".repeat(width))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode(5, "    1"), Some(1));
+        assert_eq!(decode(5, "99999"), Some(99999));
+        assert_eq!(decode(5, "A0000"), Some(100000));
+        assert_eq!(decode(5, "ZZZZZ"), Some(43770015));
+        assert_eq!(decode(5, "a0000"), Some(43770016));
+        assert_eq!(decode(5, "zzzzz"), Some(87440031));
+        assert_eq!(decode(4, "  -5"), Some(-5));
+        assert_eq!(decode(4, "A000"), Some(10000));
+        assert_eq!(decode(4, "a000"), Some(1223056));
+        assert_eq!(decode(4, "    "), None);
+        assert_eq!(decode(4, "A00"), None);
+        assert_eq!(decode(4, "Aa00"), None);
+        assert_eq!(decode(4, "1a"), None);
+        assert_eq!(decode(13, "ZZZZZZZZZZZZZ"), None);
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode(5, 1).as_deref(), Some("    1"));
+        assert_eq!(encode(5, 100000).as_deref(), Some("A0000"));
+        assert_eq!(encode(5, 87440031).as_deref(), Some("zzzzz"));
+        assert_eq!(encode(5, 87440032), None);
+        assert_eq!(encode(4, -999).as_deref(), Some("-999"));
+        assert_eq!(encode(4, -1000), None);
+        assert_eq!(encode_field(4, 2436112), "****");
+        for value in [9999, 10000, 10001, 1223055, 1223056, 2436111] {
+            assert_eq!(decode(4, &encode(4, value).unwrap()), Some(value));
+        }
+        assert_eq!(encode(1, 35).as_deref(), Some("Z"));
+        assert_eq!(decode(9, &encode(9, i32::MAX).unwrap()), Some(i32::MAX));
+        assert_eq!(encode(0, 1), None);
+        assert_eq!(encode(10, 1), None);
+        assert_eq!(encode_field(0, 1), "");
+    }
+}
@@ -12,6 +12,22 @@
 //! - **Python bindings**: Optional Python integration via PyO3
 //! - **Serialization**: Optional JSON serialization support via Serde
 //! - **Parallel processing**: Optional multi-threaded parsing with Rayon
+//! - **Diagnostics**: Strict or lenient reading with optional source spans via [`reader::ReaderOptions`]
+//! - **Compressed input**: gzip, bzip2 and zstd detection via [`compression::open`]
+//! - **Streaming**: Constant-memory reading of any `BufRead` source via [`reader::PdbReader`]
+//! - **Hierarchy**: Model, chain and residue grouping via [`structure::Structure`]
+//! - **Entry metadata**: Merged title-section records via [`title::TitleSection`]
+//! - **Remarks**: Resolution, R-factors, assemblies and missing residues via [`remarks::Remarks`]
+//! - **Assemblies**: Biological assemblies from REMARK 350 BIOMT operators via [`assembly::build`]
+//! - **Heterogens**: Per-compound names, synonyms and formulas via [`heterogen::describe`]
+//! - **Completeness**: MASTER and END cross-checks via [`bookkeeping::validate`]
+//! - **Crystallography**: Fractional coordinates and SCALEn checks via [`unit_cell::UnitCell`]
+//! - **Submitted frame**: ORIGXn coordinate transforms via [`transform::origx`]
+//! - **Symmetry**: Space-group operators and crystal packing mates via [`symmetry::SpaceGroup`]
+//! - **NCS**: Copies generated from MTRIXn operators via [`ncs::generate`]
+//! - **Writing**: Fixed-column PDB output via [`writer::write_pdb`]
+//! - **Hybrid-36**: Serial and residue numbers beyond the PDB column limits via [`hybrid36`]
+//! - **mmCIF**: PDBx/mmCIF input and output via [`mmcif::read_mmcif`] and [`mmcif::write_mmcif`]
 //!
 //! ## Example
 //!
@@ -30,18 +46,37 @@
 //! }
 //! ```
 
+pub mod assembly;
+pub mod bookkeeping;
+pub mod compression;
+pub mod error;
+pub mod heterogen;
+pub mod hybrid36;
+pub mod mmcif;
+pub mod ncs;
+pub mod reader;
 pub mod records;
-use records::Record;
+pub mod remarks;
+pub mod structure;
+pub mod symmetry;
+pub mod title;
+pub mod transform;
+pub mod unit_cell;
+pub mod writer;
+pub use error::ParseError;
+pub use records::Record;
 
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
 /// Parse PDB file contents using parallel processing.
 ///
-/// This function processes PDB file contents line-by-line using parallel processing
-/// to extract structured record data. It automatically handles atom serial number
-/// assignment for atoms that don't have them, which is necessary for some PDB files
-/// with more than 99,999 atoms.
+/// This function processes PDB file contents line-by-line on the Rayon thread pool
+/// and returns every record, in file order. Lines that fail to parse are skipped, and
+/// atom serial numbers left blank are backfilled, which is necessary for some PDB
+/// files with more than 99,999 atoms. The result is identical to
+/// [`pdbreader_single`]; use [`reader::ReaderOptions`] to keep only some record types
+/// or to see the skipped lines.
 ///
 /// # Arguments
 ///
@@ -67,43 +102,16 @@ use pyo3::prelude::*;
 /// ```
 #[cfg(feature = "parallel")]
 pub fn pdbreader_parallel(contents: &str) -> Vec<Record> {
-    use rayon::prelude::*;
-
-    let lines: Vec<&str> = contents.lines().collect();
-    let mut record: Vec<Record> = lines
-        .par_iter()
-        .filter_map(|line| {
-            if line.len() < 6 {
-                return None;
-            }
-            Record::try_from(*line).ok()
-        })
-        .collect();
-
-    // We then comb through the records and assign serial numbers to atoms that
-    // don't have them. This is necessary for some PDB files, which have more than 99999 atoms.
-    // NOTE: We don't need to use a second pass in the single threaded version because we can do it
-    // in the same pass.
-    let mut last = 0;
-    for atom in record.iter_mut() {
-        if let Record::Atom(atom) = atom {
-            if atom.serial == 0 {
-                last += 1;
-                atom.serial = last;
-            } else {
-                last = atom.serial;
-            }
-        }
-    }
-    record
+    read_all(contents, true)
 }
 
 /// Parse PDB file contents using single-threaded processing.
 ///
-/// This function processes PDB file contents line-by-line in a single thread
-/// to extract structured record data. It handles atom serial number assignment
-/// during the parsing process, making it more efficient than the parallel version
-/// for smaller files.
+/// This function processes PDB file contents line-by-line in a single thread and
+/// returns every record, in file order. Lines that fail to parse are skipped, and atom
+/// serial numbers left blank are backfilled. The result is identical to
+/// [`pdbreader_parallel`]; use [`reader::ReaderOptions`] to keep only some record
+/// types or to see the skipped lines.
 ///
 /// # Arguments
 ///
@@ -112,41 +120,27 @@ pub fn pdbreader_parallel(contents: &str) -> Vec<Record> {
 /// # Returns
 ///
 /// A vector of [`Record`] variants representing the parsed PDB records.
-/// Note: Currently only returns ATOM records, filtering out other record types.
 ///
 /// # Example
 ///
 /// ```rust
-/// use knuckles_parse::pdbreader_single;
+/// use knuckles_parse::{pdbreader_single, Record};
 ///
 /// let pdb_content = "ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N\n\
 ///                    HETATM    2  O   HOH A   2      15.123  12.456  30.789  1.00 25.50           O";
 /// let records = pdbreader_single(pdb_content);
-/// println!("Parsed {} atom records", records.len());
+/// assert!(matches!(records[1], Record::Hetatm(_)));
 /// ```
 pub fn pdbreader_single(contents: &str) -> Vec<Record> {
-    let mut last = 0;
-    contents
-        .lines()
-        .filter_map(|line| {
-            if line.len() < 6 {
-                return None;
-            }
-            let record = Record::try_from(line);
-            if let Ok(Record::Atom(mut atom)) = record {
-                if atom.serial == 0 {
-                    last += 1;
-                    atom.serial = last;
-                } else {
-                    last = atom.serial;
-                }
-                Some(Record::Atom(atom))
-            } else {
-                None
-            }
-            // Record::try_from(line).ok()
-        })
-        .collect()
+    read_all(contents, false)
+}
+
+/// Leniently read every record, skipping lines that fail to parse.
+fn read_all(contents: &str, parallel: bool) -> Vec<Record> {
+    reader::ReaderOptions::new()
+        .read_lines(contents, parallel)
+        .map(|report| report.records)
+        .unwrap_or_default()
 }
 
 #[cfg(feature = "python")]
@@ -158,16 +152,42 @@ mod knuckles_parse {
     #[pymodule_export]
     use crate::records::atom::AtomRecord;
     #[pymodule_export]
+    use crate::records::author::AuthorRecord;
+    #[pymodule_export]
+    use crate::records::caveat::CaveatRecord;
+    #[pymodule_export]
+    use crate::records::cispep::CispepRecord;
+    #[pymodule_export]
+    use crate::records::compnd::CompndRecord;
+    #[pymodule_export]
     use crate::records::connect::ConnectRecord;
     #[pymodule_export]
     use crate::records::crystal::CrystalRecord;
     #[pymodule_export]
     use crate::records::dbref::DBRefRecord;
     #[pymodule_export]
+    use crate::records::expdta::ExpdtaRecord;
+    #[pymodule_export]
+    use crate::records::formul::FormulRecord;
+    #[pymodule_export]
+    use crate::records::header::HeaderRecord;
+    #[pymodule_export]
+    use crate::records::helix::HelixRecord;
+    #[pymodule_export]
     use crate::records::het::HetRecord;
     #[pymodule_export]
     use crate::records::hetnam::HetnamRecord;
     #[pymodule_export]
+    use crate::records::hetsyn::HetsynRecord;
+    #[pymodule_export]
+    use crate::records::keywds::KeywdsRecord;
+    #[pymodule_export]
+    use crate::records::link::LinkRecord;
+    #[pymodule_export]
+    use crate::records::master::MasterRecord;
+    #[pymodule_export]
+    use crate::records::mdltyp::MdltypRecord;
+    #[pymodule_export]
     use crate::records::model::ModelRecord;
     #[pymodule_export]
     use crate::records::modres::ModresRecord;
@@ -176,22 +196,55 @@ mod knuckles_parse {
     #[pymodule_export]
     use crate::records::nummdl::NummdlRecord;
     #[pymodule_export]
+    use crate::records::obslte::ObslteRecord;
+    #[pymodule_export]
     use crate::records::origxn::OrigxnRecord;
     #[pymodule_export]
+    use crate::records::remark::RemarkRecord;
+    #[pymodule_export]
+    use crate::records::revdat::RevdatRecord;
+    #[pymodule_export]
     use crate::records::scalen::ScalenRecord;
     #[pymodule_export]
     use crate::records::seqadv::SeqAdvRecord;
     #[pymodule_export]
     use crate::records::seqres::SeqresRecord;
     #[pymodule_export]
+    use crate::records::sheet::SheetRecord;
+    #[pymodule_export]
+    use crate::records::site::SiteRecord;
+    #[pymodule_export]
+    use crate::records::site::SiteResidue;
+    #[pymodule_export]
+    use crate::records::source::SourceRecord;
+    #[pymodule_export]
+    use crate::records::split::SplitRecord;
+    #[pymodule_export]
+    use crate::records::sprsde::SprsdeRecord;
+    #[pymodule_export]
+    use crate::records::ssbond::SsbondRecord;
+    #[pymodule_export]
     use crate::records::term::TermRecord;
     #[pymodule_export]
+    use crate::records::title::TitleRecord;
+    #[pymodule_export]
+    use crate::records::unknown::UnknownRecord;
+    #[pymodule_export]
     use crate::records::Record;
 
     /// Creates a list of PDB records from a string
     #[pyfunction]
     fn pdbreader(contents: &str) -> Vec<Record> {
-        pdbreader_parallel(contents)
+        read_all(contents, true)
+    }
+
+    /// Writes a list of PDB records as fixed-column PDB text
+    #[pyfunction]
+    fn pdbwriter(records: Vec<PyRef<Record>>) -> String {
+        records
+            .iter()
+            .map(|record| format!("{}\n", &**record))
+            .collect()
     }
 
     #[pyfunction]
@@ -0,0 +1,735 @@
+//! mmCIF/PDBx support.
+//!
+//! [`read_mmcif`] maps the common mmCIF categories onto the same record types produced
+//! by the PDB readers, so code written against [`crate::records`] works for either
+//! format:
+//!
+//! | mmCIF category | Record |
+//! |---|---|
+//! | `_struct_ref`, `_struct_ref_seq` | [`Record::DBRef`] |
+//! | `_entity_poly`, `_entity_poly_seq` | [`Record::Seqres`] |
+//! | `_pdbx_struct_mod_residue` | [`Record::Modres`] |
+//! | `_cell`, `_symmetry` | [`Record::Crystal`] |
+//! | `_atom_sites` | [`Record::ScaleN`] |
+//! | `_atom_site` | [`Record::Atom`], [`Record::Hetatm`], [`Record::Model`], [`Record::Endmdl`] |
+//! | `_atom_site_anisotrop` | [`Record::Anisou`] |
+//!
+//! Author-assigned identifiers (`auth_*`) are preferred over label identifiers when both
+//! are present. Chain identifiers longer than one character do not fit in
+//! [`AtomRecord::chain_id`]; such atoms have no chain ID and carry the full identifier in
+//! [`AtomRecord::auth_chain`] instead.
+//!
+//! [`write_mmcif`] goes the other way, writing coordinates and cell parameters as
+//! `_atom_site`, `_cell` and `_symmetry` categories without the column limits of PDB
+//! files.
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::{mmcif::read_mmcif, reader::ReaderOptions, Record};
+//!
+//! let contents = "\
+//! data_1ABC
+//! loop_
+//! _atom_site.group_PDB
+//! _atom_site.id
+//! _atom_site.type_symbol
+//! _atom_site.label_atom_id
+//! _atom_site.label_comp_id
+//! _atom_site.label_asym_id
+//! _atom_site.label_seq_id
+//! _atom_site.Cartn_x
+//! _atom_site.Cartn_y
+//! _atom_site.Cartn_z
+//! _atom_site.occupancy
+//! _atom_site.B_iso_or_equiv
+//! ATOM 1 N N ALA A 1 20.154 16.967 27.462 1.00 11.18
+//! ";
+//! let report = read_mmcif(contents, &ReaderOptions::new()).unwrap();
+//! match &report.records[0] {
+//!     Record::Atom(atom) => assert_eq!(atom.x, 20.154),
+//!     _ => panic!("Expected an atom"),
+//! }
+//! ```
+
+mod tokenizer;
+mod writer;
+
+pub use writer::write_mmcif;
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::reader::{spanned_lines, ParseMode, ParseReport, ReaderOptions, Span};
+use crate::records::{
+    anisotropic::AnisotropicRecord,
+    atom::AtomRecord,
+    crystal::CrystalRecord,
+    dbref::{DBRefRecord, DBType},
+    model::ModelRecord,
+    modres::ModresRecord,
+    scalen::{ScaleN, ScalenRecord},
+    seqres::SeqresRecord,
+    Record, RecordKinds,
+};
+use tokenizer::{Block, Row};
+
+/// Read the first data block of an mmCIF file into PDB records.
+///
+/// Records are returned in PDB file order: DBREF, SEQRES, MODRES, CRYST1, SCALEn and
+/// then the coordinate section, with each ANISOU record following its atom. MODEL and
+/// ENDMDL records are only produced when the file contains more than one model.
+///
+/// Records are selected with [`ReaderOptions::kinds`] and [`ReaderOptions::filter`] as
+/// they are for PDB files; categories that only map onto unselected record types are not
+/// read at all. With [`ReaderOptions::spans`] set, each record gets the span of the row
+/// it was mapped from, such as its `_atom_site` row; a row split over several lines spans
+/// all of them. SEQRES records share the span of their `_entity_poly` row and an ENDMDL
+/// record takes that of the last atom in its model.
+///
+/// # Errors
+///
+/// CIF syntax errors are always returned. Rows that cannot be mapped onto a record
+/// follow `options`: they are collected as diagnostics in lenient mode and returned as
+/// the error in strict mode.
+pub fn read_mmcif(contents: &str, options: &ReaderOptions) -> Result<ParseReport, ParseError> {
+    let blocks = tokenizer::parse(contents)?;
+    let lines = match options.spans {
+        true => spanned_lines(contents)
+            .map(|(span, _)| span.bytes)
+            .collect(),
+        false => Vec::new(),
+    };
+    let mut reader = Reader {
+        report: ParseReport::default(),
+        options,
+        lines,
+    };
+    if let Some(block) = blocks.first() {
+        reader.read_block(block)?;
+    }
+    Ok(reader.report)
+}
+
+struct Reader<'o> {
+    report: ParseReport,
+    options: &'o ReaderOptions,
+    /// Byte range of each line, when spans are kept
+    lines: Vec<Range<usize>>,
+}
+
+impl Reader<'_> {
+    /// Whether records of type `kind` are to be read.
+    fn selected(&self, kind: RecordKinds) -> bool {
+        self.options.kinds.is_none_or(|kinds| kinds.contains(kind))
+    }
+
+    /// Keep a record mapped from `row` if its type is selected and it passes the filter.
+    fn keep(&mut self, row: &Row, record: Record) {
+        let filter = self.options.filter.as_ref();
+        if !self.selected(record.kind()) || filter.is_some_and(|filter| !filter(&record)) {
+            return;
+        }
+        self.report.records.push(record);
+        if self.options.spans {
+            let lines = row.lines();
+            self.report.spans.push(Span {
+                line: *lines.start(),
+                bytes: self.lines[lines.start() - 1].start..self.lines[lines.end() - 1].end,
+            });
+        }
+    }
+
+    /// Keep a successfully mapped record or handle the error according to the mode.
+    fn push(&mut self, row: &Row, result: Result<Record, ParseError>) -> Result<(), ParseError> {
+        match result {
+            Ok(record) => self.keep(row, record),
+            Err(error) => self.reject(error)?,
+        }
+        Ok(())
+    }
+
+    /// Handle a row that could not be mapped according to the mode.
+    fn reject(&mut self, error: ParseError) -> Result<(), ParseError> {
+        match self.options.mode {
+            ParseMode::Strict => return Err(error),
+            ParseMode::Lenient => self.report.diagnostics.push(error),
+        }
+        Ok(())
+    }
+
+    fn read_block(&mut self, block: &Block) -> Result<(), ParseError> {
+        let id_code = block
+            .rows("_entry")
+            .next()
+            .and_then(|row| row.str("id"))
+            .unwrap_or(block.name)
+            .to_string();
+        if self.selected(RecordKinds::DBREF) {
+            self.read_dbrefs(block, &id_code)?;
+        }
+        if self.selected(RecordKinds::SEQRES) {
+            self.read_seqres(block)?;
+        }
+        if self.selected(RecordKinds::MODRES) {
+            for row in block.rows("_pdbx_struct_mod_residue") {
+                self.push(&row, modres(&row, &id_code).map(Record::Modres))?;
+            }
+        }
+        let cell = block.rows("_cell").next();
+        if let Some(row) = cell.filter(|_| self.selected(RecordKinds::CRYST1)) {
+            let space_group = block
+                .rows("_symmetry")
+                .next()
+                .and_then(|row| row.str("space_group_name_h-m"))
+                .unwrap_or_default();
+            self.push(&row, crystal(&row, space_group).map(Record::Crystal))?;
+        }
+        let sites = block.rows("_atom_sites").next();
+        if let Some(row) = sites.filter(|_| self.selected(RecordKinds::SCALE)) {
+            for n in 1..=3 {
+                self.push(&row, scale(&row, n).map(Record::ScaleN))?;
+            }
+        }
+        self.read_atoms(block)
+    }
+
+    fn read_dbrefs(&mut self, block: &Block, id_code: &str) -> Result<(), ParseError> {
+        let references: HashMap<&str, Row> = block
+            .rows("_struct_ref")
+            .filter_map(|row| Some((row.str("id")?, row)))
+            .collect();
+        for row in block.rows("_struct_ref_seq") {
+            let reference = row
+                .str("ref_id")
+                .and_then(|id| references.get(id))
+                .ok_or_else(|| row.error("ref_id", ParseErrorKind::InvalidValue));
+            let result = reference.and_then(|reference| dbref(&row, reference, id_code));
+            self.push(&row, result.map(Record::DBRef))?;
+        }
+        Ok(())
+    }
+
+    fn read_seqres(&mut self, block: &Block) -> Result<(), ParseError> {
+        // Residues of each entity in order, keeping the first of any microheterogeneity.
+        let mut entities: Vec<(&str, Vec<(i32, &str)>)> = Vec::new();
+        for row in block.rows("_entity_poly_seq") {
+            let (Some(entity), Some(mon_id)) = (row.str("entity_id"), row.str("mon_id")) else {
+                self.reject(row.error("mon_id", ParseErrorKind::Missing))?;
+                continue;
+            };
+            let num = match row.parse_required::<i32>("num") {
+                Ok(num) => num,
+                Err(error) => {
+                    self.reject(error)?;
+                    continue;
+                }
+            };
+            let index = match entities.iter().position(|(id, _)| *id == entity) {
+                Some(index) => index,
+                None => {
+                    entities.push((entity, Vec::new()));
+                    entities.len() - 1
+                }
+            };
+            let residues = &mut entities[index].1;
+            if !residues.iter().any(|(existing, _)| *existing == num) {
+                residues.push((num, mon_id));
+            }
+        }
+        let strands: HashMap<&str, Row> = block
+            .rows("_entity_poly")
+            .filter_map(|row| Some((row.str("entity_id")?, row)))
+            .collect();
+        for (entity, mut residues) in entities {
+            residues.sort_by_key(|(num, _)| *num);
+            let Some(row) = strands.get(entity) else {
+                continue;
+            };
+            let Some(chains) = row.str("pdbx_strand_id") else {
+                continue;
+            };
+            for chain in chains.split(',').map(str::trim) {
+                let chain_id = match single_char(chain) {
+                    Some(chain_id) => chain_id,
+                    None => {
+                        self.reject(row.error("pdbx_strand_id", ParseErrorKind::InvalidValue))?;
+                        continue;
+                    }
+                };
+                for (index, names) in residues.chunks(13).enumerate() {
+                    let seqres = SeqresRecord {
+                        ser_num: index as u32 + 1,
+                        chain_id,
+                        num_res: residues.len() as i16,
+                        res_names: names.iter().map(|(_, name)| name.to_string()).collect(),
+                    };
+                    self.keep(row, Record::Seqres(seqres));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn read_atoms(&mut self, block: &Block) -> Result<(), ParseError> {
+        let mut anisotropic: HashMap<&str, Row> = block
+            .rows("_atom_site_anisotrop")
+            .filter_map(|row| Some((row.str("id")?, row)))
+            .collect();
+        let multiple_models = {
+            let mut models = block
+                .rows("_atom_site")
+                .filter_map(|row| row.str("pdbx_pdb_model_num"));
+            let first = models.next();
+            models.any(|model| Some(model) != first)
+        };
+        let mut current_model: Option<&str> = None;
+        let mut previous: Option<Row> = None;
+        for row in block.rows("_atom_site") {
+            if multiple_models {
+                let model = row.str("pdbx_pdb_model_num");
+                if model != current_model {
+                    if let Some(previous) = previous.filter(|_| current_model.is_some()) {
+                        self.keep(&previous, Record::Endmdl());
+                    }
+                    if self.selected(RecordKinds::MODEL) {
+                        let serial = row.parse_required("pdbx_pdb_model_num");
+                        let model = serial.map(|serial| Record::Model(ModelRecord { serial }));
+                        self.push(&row, model)?;
+                    }
+                    current_model = model;
+                }
+            }
+            previous = Some(row);
+            let hetero = row.str("group_pdb") == Some("HETATM");
+            let kind = match hetero {
+                true => RecordKinds::HETATM,
+                false => RecordKinds::ATOM,
+            };
+            // ANISOU records are built from their atom, so it is read for either.
+            if !self.selected(kind) && !self.selected(RecordKinds::ANISOU) {
+                continue;
+            }
+            let atom = atom(&row);
+            let anisou = match (&atom, row.str("id").and_then(|id| anisotropic.remove(id))) {
+                (Ok(atom), Some(anisou)) => Some((anisotropic_record(&anisou, atom), anisou)),
+                _ => None,
+            };
+            let atom = atom.map(|atom| match hetero {
+                true => Record::Hetatm(atom),
+                false => Record::Atom(atom),
+            });
+            self.push(&row, atom)?;
+            if let Some((anisou, anisou_row)) = anisou {
+                self.push(&anisou_row, anisou.map(Record::Anisou))?;
+            }
+        }
+        if let Some(previous) = previous.filter(|_| current_model.is_some()) {
+            self.keep(&previous, Record::Endmdl());
+        }
+        Ok(())
+    }
+}
+
+fn single_char(text: &str) -> Option<char> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+fn atom(row: &Row) -> Result<AtomRecord, ParseError> {
+    // A blank chain identifier is written as a quoted empty string by some programs.
+    let chain = row
+        .first_str(&["auth_asym_id", "label_asym_id"])
+        .filter(|chain| !chain.trim().is_empty());
+    let (chain_id, auth_chain) = match chain.map(|chain| (chain, single_char(chain))) {
+        Some((_, Some(chain_id))) => (Some(chain_id), None),
+        Some((chain, None)) => (None, Some(chain.to_string())),
+        None => (None, None),
+    };
+    let res_seq = match row.str("auth_seq_id") {
+        Some(_) => row.parse_required("auth_seq_id")?,
+        None => row.parse_required("label_seq_id")?,
+    };
+    Ok(AtomRecord {
+        serial: row.parse("id")?.unwrap_or_default(),
+        name: row
+            .first_str(&["auth_atom_id", "label_atom_id"])
+            .ok_or_else(|| row.error("label_atom_id", ParseErrorKind::Missing))?
+            .to_string(),
+        alt_loc: row.str("label_alt_id").and_then(single_char),
+        res_name: row
+            .first_str(&["auth_comp_id", "label_comp_id"])
+            .ok_or_else(|| row.error("label_comp_id", ParseErrorKind::Missing))?
+            .to_string(),
+        chain_id,
+        res_seq,
+        i_code: row.str("pdbx_pdb_ins_code").and_then(single_char),
+        x: row.parse_required("cartn_x")?,
+        y: row.parse_required("cartn_y")?,
+        z: row.parse_required("cartn_z")?,
+        occupancy: row.parse("occupancy")?.unwrap_or(1.0),
+        temp_factor: row.parse("b_iso_or_equiv")?.unwrap_or_default(),
+        element: row.str("type_symbol").map(str::to_string),
+        charge: row
+            .parse("pdbx_formal_charge")?
+            .filter(|charge: &i8| *charge != 0),
+        segment_id: None,
+        auth_chain,
+    })
+}
+
+fn anisotropic_record(row: &Row, atom: &AtomRecord) -> Result<AnisotropicRecord, ParseError> {
+    // PDB ANISOU records store U(i,j) as integers scaled by 10^4.
+    let u = |item: &str| -> Result<i32, ParseError> {
+        Ok((row.parse_required::<f32>(item)? * 10000.0).round() as i32)
+    };
+    Ok(AnisotropicRecord {
+        serial: atom.serial,
+        name: atom.name.clone(),
+        alt_loc: atom.alt_loc,
+        res_name: atom.res_name.clone(),
+        chain_id: atom.chain_id.unwrap_or(' '),
+        res_seq: atom.res_seq,
+        i_code: atom.i_code,
+        u00: u("u[1][1]")?,
+        u11: u("u[2][2]")?,
+        u22: u("u[3][3]")?,
+        u01: u("u[1][2]")?,
+        u02: u("u[1][3]")?,
+        u12: u("u[2][3]")?,
+        element: atom.element.clone(),
+        charge: atom.charge,
+    })
+}
+
+fn crystal(row: &Row, space_group: &str) -> Result<CrystalRecord, ParseError> {
+    Ok(CrystalRecord {
+        a: row.parse_required("length_a")?,
+        b: row.parse_required("length_b")?,
+        c: row.parse_required("length_c")?,
+        alpha: row.parse_required("angle_alpha")?,
+        beta: row.parse_required("angle_beta")?,
+        gamma: row.parse_required("angle_gamma")?,
+        space_group: space_group.to_string(),
+        z: row.parse("z_pdb")?.unwrap_or_default(),
+    })
+}
+
+fn scale(row: &Row, n: u16) -> Result<ScaleN, ParseError> {
+    let item = |column: u16| format!("fract_transf_matrix[{}][{}]", n, column);
+    let record = ScalenRecord {
+        n,
+        scalen: [
+            row.parse_required(&item(1))?,
+            row.parse_required(&item(2))?,
+            row.parse_required(&item(3))?,
+        ],
+        un: row.parse_required(&format!("fract_transf_vector[{}]", n))?,
+    };
+    Ok(match n {
+        1 => ScaleN::Scale1(record),
+        2 => ScaleN::Scale2(record),
+        _ => ScaleN::Scale3(record),
+    })
+}
+
+fn dbref(row: &Row, reference: &Row, id_code: &str) -> Result<DBRefRecord, ParseError> {
+    let database = DBType::new(reference.required("db_name")?)
+        .map_err(|_| reference.error("db_name", ParseErrorKind::InvalidValue))?;
+    let chain_id = single_char(row.required("pdbx_strand_id")?)
+        .ok_or_else(|| row.error("pdbx_strand_id", ParseErrorKind::InvalidValue))?;
+    Ok(DBRefRecord {
+        id_code: row.str("pdbx_pdb_id_code").unwrap_or(id_code).to_string(),
+        chain_id,
+        seq_begin: row.parse_required("pdbx_auth_seq_align_beg")?,
+        insert_begin: row.str("pdbx_seq_align_beg_ins_code").and_then(single_char),
+        seq_end: row.parse_required("pdbx_auth_seq_align_end")?,
+        insert_end: row.str("pdbx_seq_align_end_ins_code").and_then(single_char),
+        database,
+        db_accession: row
+            .str("pdbx_db_accession")
+            .or_else(|| reference.str("pdbx_db_accession"))
+            .unwrap_or_default()
+            .to_string(),
+        db_id_code: reference.str("db_code").unwrap_or_default().to_string(),
+        db_seq_begin: row.parse_required("db_align_beg")?,
+        i_dbns_beg: row.str("pdbx_db_align_beg_ins_code").and_then(single_char),
+        db_seq_end: row.parse_required("db_align_end")?,
+        db_ins_end: row.str("pdbx_db_align_end_ins_code").and_then(single_char),
+    })
+}
+
+fn modres(row: &Row, id_code: &str) -> Result<ModresRecord, ParseError> {
+    let chain_id = single_char(row.required("auth_asym_id")?)
+        .ok_or_else(|| row.error("auth_asym_id", ParseErrorKind::InvalidValue))?;
+    Ok(ModresRecord {
+        id_code: id_code.to_string(),
+        res_name: row
+            .first_str(&["auth_comp_id", "label_comp_id"])
+            .unwrap_or_default()
+            .to_string(),
+        chain_id,
+        seq_num: row.parse_required("auth_seq_id")?,
+        i_code: row.str("pdb_ins_code").and_then(single_char),
+        std_res_name: row.str("parent_comp_id").unwrap_or_default().to_string(),
+        comment: row.str("details").unwrap_or_default().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CIF: &str = "\
+data_1ABC
+_entry.id 1ABC
+#
+_cell.length_a 52.000
+_cell.length_b 58.600
+_cell.length_c 61.900
+_cell.angle_alpha 90.00
+_cell.angle_beta 90.00
+_cell.angle_gamma 90.00
+_cell.Z_PDB 8
+_symmetry.space_group_name_H-M 'P 21 21 21'
+#
+_struct_ref.id 1
+_struct_ref.db_name UNP
+_struct_ref.db_code UNG_VIBCH
+_struct_ref.pdbx_db_accession Q9KPK8
+#
+_struct_ref_seq.ref_id 1
+_struct_ref_seq.pdbx_strand_id A
+_struct_ref_seq.pdbx_auth_seq_align_beg 1
+_struct_ref_seq.pdbx_auth_seq_align_end 226
+_struct_ref_seq.db_align_beg 1
+_struct_ref_seq.db_align_end 226
+#
+_entity_poly.entity_id 1
+_entity_poly.pdbx_strand_id A,B
+#
+loop_
+_entity_poly_seq.entity_id
+_entity_poly_seq.num
+_entity_poly_seq.mon_id
+1 1 MET
+1 2 ALA
+1 2 GLY
+#
+_pdbx_struct_mod_residue.auth_asym_id A
+_pdbx_struct_mod_residue.auth_comp_id MSE
+_pdbx_struct_mod_residue.auth_seq_id 1
+_pdbx_struct_mod_residue.PDB_ins_code ?
+_pdbx_struct_mod_residue.parent_comp_id MET
+_pdbx_struct_mod_residue.details 'MODIFIED RESIDUE'
+#
+loop_
+_atom_site.group_PDB
+_atom_site.id
+_atom_site.type_symbol
+_atom_site.label_atom_id
+_atom_site.label_alt_id
+_atom_site.label_comp_id
+_atom_site.label_asym_id
+_atom_site.label_seq_id
+_atom_site.pdbx_PDB_ins_code
+_atom_site.Cartn_x
+_atom_site.Cartn_y
+_atom_site.Cartn_z
+_atom_site.occupancy
+_atom_site.B_iso_or_equiv
+_atom_site.pdbx_formal_charge
+_atom_site.auth_seq_id
+_atom_site.auth_asym_id
+_atom_site.pdbx_PDB_model_num
+ATOM   1 N N   . MET A 1 ? 20.154 16.967 27.462 1.00 11.18 ? 1   A  1
+ATOM   2 C CA  . MET A 1 ? 21.154 16.967 27.462 1.00 11.18 ? 1   A  1
+HETATM 3 O O   . HOH C . ? 15.123 12.456 30.789 1.00 25.50 ? 101 AA 1
+ATOM   4 N N   . MET A 1 ? 20.154 16.967 27.462 1.00 11.18 1 1   A  2
+#
+loop_
+_atom_site_anisotrop.id
+_atom_site_anisotrop.U[1][1]
+_atom_site_anisotrop.U[2][2]
+_atom_site_anisotrop.U[3][3]
+_atom_site_anisotrop.U[1][2]
+_atom_site_anisotrop.U[1][3]
+_atom_site_anisotrop.U[2][3]
+1 0.0688 0.1234 0.0806 -0.0019 -0.0049 0.0178
+";
+
+    #[test]
+    fn test_read_mmcif() {
+        let report = read_mmcif(CIF, &ReaderOptions::new()).unwrap();
+        assert!(report.diagnostics.is_empty());
+        let records = report.records;
+        let tags: Vec<&str> = records
+            .iter()
+            .map(|record| match record {
+                Record::DBRef(_) => "DBREF",
+                Record::Seqres(_) => "SEQRES",
+                Record::Modres(_) => "MODRES",
+                Record::Crystal(_) => "CRYST1",
+                Record::Model(_) => "MODEL",
+                Record::Atom(_) => "ATOM",
+                Record::Anisou(_) => "ANISOU",
+                Record::Hetatm(_) => "HETATM",
+                Record::Endmdl() => "ENDMDL",
+                _ => "OTHER",
+            })
+            .collect();
+        assert_eq!(
+            tags,
+            vec![
+                "DBREF", "SEQRES", "SEQRES", "MODRES", "CRYST1", "MODEL", "ATOM", "ANISOU", "ATOM",
+                "HETATM", "ENDMDL", "MODEL", "ATOM", "ENDMDL"
+            ]
+        );
+
+        match &records[0] {
+            Record::DBRef(dbref) => {
+                assert_eq!(dbref.id_code, "1ABC");
+                assert_eq!(dbref.database, DBType::UNP);
+                assert_eq!(dbref.db_accession, "Q9KPK8");
+                assert_eq!(dbref.seq_end, 226);
+            }
+            _ => panic!("Expected DBREF"),
+        }
+        match &records[2] {
+            Record::Seqres(seqres) => {
+                assert_eq!(seqres.chain_id, 'B');
+                assert_eq!(seqres.res_names, vec!["MET", "ALA"]);
+            }
+            _ => panic!("Expected SEQRES"),
+        }
+        match &records[4] {
+            Record::Crystal(crystal) => {
+                assert_eq!(crystal.space_group, "P 21 21 21");
+                assert_eq!(crystal.z, 8);
+            }
+            _ => panic!("Expected CRYST1"),
+        }
+        match &records[7] {
+            Record::Anisou(anisou) => {
+                assert_eq!(anisou.u00, 688);
+                assert_eq!(anisou.u01, -19);
+            }
+            _ => panic!("Expected ANISOU"),
+        }
+        match &records[9] {
+            Record::Hetatm(atom) => {
+                assert_eq!(atom.res_seq, 101);
+                assert_eq!(atom.chain_id, None);
+                assert_eq!(atom.auth_chain, Some("AA".to_string()));
+                assert_eq!(atom.segment_id, None);
+            }
+            _ => panic!("Expected HETATM"),
+        }
+        match &records[12] {
+            Record::Atom(atom) => assert_eq!(atom.charge, Some(1)),
+            _ => panic!("Expected ATOM"),
+        }
+    }
+
+    #[test]
+    fn test_read_mmcif_long_chains() {
+        let contents = "\
+data_1ABC
+loop_
+_atom_site.group_PDB
+_atom_site.id
+_atom_site.label_atom_id
+_atom_site.label_comp_id
+_atom_site.auth_asym_id
+_atom_site.label_seq_id
+_atom_site.Cartn_x
+_atom_site.Cartn_y
+_atom_site.Cartn_z
+ATOM 1 CA GLY AA 1 1.0 2.0 3.0
+ATOM 2 CA GLY AB 1 4.0 5.0 6.0
+ATOM 3 CA GLY '' 1 7.0 8.0 9.0
+";
+        let records = read_mmcif(contents, &ReaderOptions::new()).unwrap().records;
+        match &records[2] {
+            Record::Atom(atom) => assert_eq!((atom.chain_id, &atom.auth_chain), (None, &None)),
+            _ => panic!("Expected ATOM"),
+        }
+
+        // Chains with long identifiers stay apart rather than merging into one chain.
+        let structure = crate::structure::Structure::new(&records);
+        let model = &structure.models()[0];
+        assert_eq!(model.chains().len(), 3);
+        assert!(model
+            .chains()
+            .iter()
+            .all(|chain| chain.residues().len() == 1));
+        assert_eq!(
+            model
+                .chain_by_label("AB")
+                .unwrap()
+                .atoms()
+                .next()
+                .unwrap()
+                .x,
+            4.0
+        );
+    }
+
+    #[test]
+    fn test_read_mmcif_selection() {
+        let options = ReaderOptions::new().kinds(RecordKinds::HETATM | RecordKinds::ANISOU);
+        let records = read_mmcif(CIF, &options).unwrap().records;
+        let kinds: Vec<RecordKinds> = records.iter().map(Record::kind).collect();
+        assert_eq!(kinds, [RecordKinds::ANISOU, RecordKinds::HETATM]);
+
+        // Unselected categories are not read, so their errors are not reported.
+        let contents = CIF.replace("_cell.length_a 52.000", "_cell.length_a 5x.000");
+        let options = ReaderOptions::new()
+            .kinds(RecordKinds::ATOM)
+            .mode(ParseMode::Strict)
+            .filter(|record| matches!(record, Record::Atom(atom) if atom.name == "CA"));
+        let records = read_mmcif(&contents, &options).unwrap().records;
+        assert_eq!(records.len(), 1);
+        assert!(matches!(&records[0], Record::Atom(atom) if atom.serial == 2));
+    }
+
+    #[test]
+    fn test_read_mmcif_spans() {
+        let options = ReaderOptions::new().spans(true);
+        let report = read_mmcif(CIF, &options).unwrap();
+        assert_eq!(report.spans.len(), report.records.len());
+        let span = |index: usize| &CIF[report.spans[index].bytes.clone()];
+        assert_eq!(report.spans[6].line, 62);
+        assert!(span(6).starts_with("ATOM   1 N N   . MET A 1 ?"));
+        assert!(span(7).starts_with("1 0.0688 0.1234"));
+        assert!(span(9).starts_with("HETATM 3 O O"));
+        assert_eq!(span(10), span(9));
+        // A category written as key-value pairs is one row over several lines.
+        assert!(span(4).starts_with("_cell.length_a 52.000\n"));
+        assert!(span(4).ends_with("\n_cell.Z_PDB 8"));
+
+        // A loop row split over two lines covers both.
+        let contents = CIF.replace("11.18 ? 1   A  1\nATOM   2", "11.18 ?\n1 A 1\nATOM   2");
+        let report = read_mmcif(&contents, &options).unwrap();
+        assert_eq!(report.spans[6].line, 62);
+        assert!(contents[report.spans[6].bytes.clone()].ends_with("11.18 ?\n1 A 1"));
+    }
+
+    #[test]
+    fn test_read_mmcif_modes() {
+        let contents = CIF.replace("21.154", "2x.154");
+        let report = read_mmcif(&contents, &ReaderOptions::new()).unwrap();
+        assert_eq!(report.diagnostics.len(), 1);
+        assert_eq!(report.diagnostics[0].record, "_atom_site");
+        assert_eq!(report.diagnostics[0].line, Some(63));
+        assert_eq!(report.diagnostics[0].text, "2x.154");
+
+        let options = ReaderOptions::new().mode(ParseMode::Strict);
+        let error = read_mmcif(&contents, &options).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+    }
+}
@@ -0,0 +1,431 @@
+//! Tokenizer and data block parser for the CIF syntax used by mmCIF/PDBx files.
+
+use std::borrow::Cow;
+use std::ops::{Range, RangeInclusive};
+
+use crate::error::{ParseError, ParseErrorKind};
+
+/// A single value in a CIF file, with its position for error reporting.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Value<'a> {
+    /// The value's text, or `None` for the unquoted null markers `.` and `?`
+    pub text: Option<Cow<'a, str>>,
+    /// One-based line number the value starts on
+    pub line: usize,
+    /// Zero-based column range of the value within its line
+    pub columns: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token<'a> {
+    Data(&'a str),
+    Loop,
+    Tag(&'a str, usize, Range<usize>),
+    Value(Value<'a>),
+}
+
+/// A category such as `_atom_site`, holding either a single row of key-value items
+/// or the rows of a `loop_`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Category<'a> {
+    /// Lowercase category name including the leading underscore
+    pub name: String,
+    /// Lowercase item names without the category prefix
+    pub items: Vec<String>,
+    pub rows: Vec<Vec<Value<'a>>>,
+}
+
+/// A `data_` block and its categories in file order.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Block<'a> {
+    pub name: &'a str,
+    pub categories: Vec<Category<'a>>,
+}
+
+/// A row of a category with item lookup by name.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Row<'c, 'a> {
+    record: &'static str,
+    category: &'c Category<'a>,
+    values: &'c [Value<'a>],
+}
+
+impl<'a> Block<'a> {
+    /// Look up a category by its lowercase name (e.g. `_atom_site`).
+    pub fn category(&self, name: &str) -> Option<&Category<'a>> {
+        self.categories
+            .iter()
+            .find(|category| category.name == name)
+    }
+
+    /// Iterate over the rows of a category, or nothing if it is absent.
+    ///
+    /// `record` must be the category name; it tags errors raised from the rows.
+    pub fn rows<'c>(&'c self, record: &'static str) -> impl Iterator<Item = Row<'c, 'a>> {
+        self.category(record).into_iter().flat_map(move |category| {
+            category.rows.iter().map(move |values| Row {
+                record,
+                category,
+                values,
+            })
+        })
+    }
+}
+
+impl<'c, 'a> Row<'c, 'a> {
+    fn value(&self, item: &str) -> Option<&'c Value<'a>> {
+        let index = self.category.items.iter().position(|name| name == item)?;
+        self.values.get(index)
+    }
+
+    /// The line this row starts on.
+    pub fn line(&self) -> usize {
+        self.values.first().map_or(0, |value| value.line)
+    }
+
+    /// The lines this row starts and ends on, by where its first and last values start.
+    pub fn lines(&self) -> RangeInclusive<usize> {
+        let last = self.values.last().map_or(0, |value| value.line);
+        self.line()..=last
+    }
+
+    /// The text of an item, or `None` if it is absent or null.
+    pub fn str(&self, item: &str) -> Option<&'c str> {
+        self.value(item).and_then(|value| value.text.as_deref())
+    }
+
+    /// The text of the first of several items that is present and not null.
+    pub fn first_str(&self, items: &[&str]) -> Option<&'c str> {
+        items.iter().find_map(|item| self.str(item))
+    }
+
+    /// The text of a required item.
+    pub fn required(&self, item: &str) -> Result<&'c str, ParseError> {
+        self.str(item)
+            .ok_or_else(|| self.error(item, ParseErrorKind::Missing))
+    }
+
+    /// An optional item parsed as `T`; absent or null items are `None`.
+    pub fn parse<T: std::str::FromStr>(&self, item: &str) -> Result<Option<T>, ParseError> {
+        match self.str(item) {
+            None => Ok(None),
+            Some(text) => text
+                .parse()
+                .map(Some)
+                .map_err(|_| self.error(item, ParseErrorKind::InvalidNumber)),
+        }
+    }
+
+    /// A required item parsed as `T`.
+    pub fn parse_required<T: std::str::FromStr>(&self, item: &str) -> Result<T, ParseError> {
+        self.parse(item)?
+            .ok_or_else(|| self.error(item, ParseErrorKind::Missing))
+    }
+
+    /// An error pointing at `item`, or at the start of the row if the item is absent.
+    pub fn error(&self, item: &str, kind: ParseErrorKind) -> ParseError {
+        match self.value(item) {
+            Some(value) => ParseError::new(
+                self.record,
+                value.columns.clone(),
+                value.text.as_deref().unwrap_or_default(),
+                kind,
+            )
+            .with_line(value.line),
+            None => ParseError::new(self.record, 0..0, item, kind).with_line(self.line()),
+        }
+    }
+}
+
+/// Parse CIF text into its data blocks.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] tagged with record `"mmCIF"` for syntax errors such as
+/// unterminated quotes or text fields, values without a tag, or loops whose value
+/// count is not a multiple of their tag count.
+pub(crate) fn parse(contents: &str) -> Result<Vec<Block<'_>>, ParseError> {
+    let tokens = tokenize(contents)?;
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Data(name) => blocks.push(Block {
+                name,
+                categories: Vec::new(),
+            }),
+            Token::Tag(tag, line, columns) => {
+                let value = match tokens.next() {
+                    Some(Token::Value(value)) => value,
+                    _ => return Err(syntax_error(tag, line, columns, ParseErrorKind::Missing)),
+                };
+                let block = current_block(&mut blocks, tag, line, &columns)?;
+                let (category, item) = split_tag(tag);
+                match block
+                    .categories
+                    .iter_mut()
+                    .find(|existing| existing.name == category && existing.rows.len() == 1)
+                {
+                    Some(existing) => {
+                        existing.items.push(item);
+                        existing.rows[0].push(value);
+                    }
+                    None => block.categories.push(Category {
+                        name: category,
+                        items: vec![item],
+                        rows: vec![vec![value]],
+                    }),
+                }
+            }
+            Token::Loop => {
+                let mut tags = Vec::new();
+                while let Some(Token::Tag(..)) = tokens.peek() {
+                    if let Some(Token::Tag(tag, line, columns)) = tokens.next() {
+                        tags.push((tag, line, columns));
+                    }
+                }
+                let Some((first, line, columns)) = tags.first().cloned() else {
+                    return Err(syntax_error("loop_", 0, 0..5, ParseErrorKind::Missing));
+                };
+                let mut values = Vec::new();
+                while let Some(Token::Value(_)) = tokens.peek() {
+                    if let Some(Token::Value(value)) = tokens.next() {
+                        values.push(value);
+                    }
+                }
+                if values.len() % tags.len() != 0 {
+                    return Err(syntax_error(
+                        first,
+                        line,
+                        columns,
+                        ParseErrorKind::InvalidValue,
+                    ));
+                }
+                let block = current_block(&mut blocks, first, line, &columns)?;
+                let (category, _) = split_tag(first);
+                let items = tags.iter().map(|(tag, ..)| split_tag(tag).1).collect();
+                let mut rows = Vec::with_capacity(values.len() / tags.len());
+                let mut values = values.into_iter();
+                loop {
+                    let row: Vec<Value> = values.by_ref().take(tags.len()).collect();
+                    if row.is_empty() {
+                        break;
+                    }
+                    rows.push(row);
+                }
+                block.categories.push(Category {
+                    name: category,
+                    items,
+                    rows,
+                });
+            }
+            Token::Value(value) => {
+                return Err(ParseError::new(
+                    "mmCIF",
+                    value.columns,
+                    value.text.unwrap_or_default(),
+                    ParseErrorKind::InvalidValue,
+                )
+                .with_line(value.line))
+            }
+        }
+    }
+    Ok(blocks)
+}
+
+fn current_block<'b, 'a>(
+    blocks: &'b mut [Block<'a>],
+    tag: &str,
+    line: usize,
+    columns: &Range<usize>,
+) -> Result<&'b mut Block<'a>, ParseError> {
+    blocks
+        .last_mut()
+        .ok_or_else(|| syntax_error(tag, line, columns.clone(), ParseErrorKind::InvalidValue))
+}
+
+fn syntax_error(
+    text: &str,
+    line: usize,
+    columns: Range<usize>,
+    kind: ParseErrorKind,
+) -> ParseError {
+    ParseError::new("mmCIF", columns, text, kind).with_line(line)
+}
+
+/// Split `_category.item` into lowercase `_category` and `item`.
+fn split_tag(tag: &str) -> (String, String) {
+    let tag = tag.to_ascii_lowercase();
+    match tag.split_once('.') {
+        Some((category, item)) => (category.to_string(), item.to_string()),
+        None => (tag, String::new()),
+    }
+}
+
+fn tokenize(contents: &str) -> Result<Vec<Token<'_>>, ParseError> {
+    let mut tokens = Vec::new();
+    // Start line and lines of an open `;` text field.
+    let mut text_field: Option<(usize, Vec<&str>)> = None;
+    for (index, line) in contents.lines().enumerate() {
+        let number = index + 1;
+        if let Some(rest) = line.strip_prefix(';') {
+            match text_field.take() {
+                Some((start, mut lines)) => {
+                    // Text usually starts on the line after the opening semicolon.
+                    if lines[0].trim().is_empty() {
+                        lines.remove(0);
+                    }
+                    tokens.push(Token::Value(Value {
+                        text: Some(Cow::Owned(lines.join("\n"))),
+                        line: start,
+                        columns: 0..1,
+                    }))
+                }
+                None => text_field = Some((number, vec![rest])),
+            }
+            continue;
+        }
+        if let Some((_, lines)) = text_field.as_mut() {
+            lines.push(line);
+            continue;
+        }
+        tokenize_line(line, number, &mut tokens)?;
+    }
+    match text_field {
+        Some((start, _)) => Err(syntax_error(";", start, 0..1, ParseErrorKind::TooShort)),
+        None => Ok(tokens),
+    }
+}
+
+fn tokenize_line<'a>(
+    line: &'a str,
+    number: usize,
+    tokens: &mut Vec<Token<'a>>,
+) -> Result<(), ParseError> {
+    let bytes = line.as_bytes();
+    let mut start = 0;
+    while start < bytes.len() {
+        if bytes[start].is_ascii_whitespace() {
+            start += 1;
+            continue;
+        }
+        if bytes[start] == b'#' {
+            break;
+        }
+        if bytes[start] == b'\'' || bytes[start] == b'"' {
+            // A quoted value only ends at a matching quote followed by whitespace.
+            let quote = bytes[start];
+            let end = (start + 1..bytes.len())
+                .find(|&i| {
+                    bytes[i] == quote && bytes.get(i + 1).is_none_or(|c| c.is_ascii_whitespace())
+                })
+                .ok_or_else(|| {
+                    syntax_error(
+                        &line[start..],
+                        number,
+                        start..bytes.len(),
+                        ParseErrorKind::TooShort,
+                    )
+                })?;
+            tokens.push(Token::Value(Value {
+                text: Some(Cow::Borrowed(&line[start + 1..end])),
+                line: number,
+                columns: start..end + 1,
+            }));
+            start = end + 1;
+            continue;
+        }
+        let end = (start..bytes.len())
+            .find(|&i| bytes[i].is_ascii_whitespace())
+            .unwrap_or(bytes.len());
+        let word = &line[start..end];
+        let token = if word.starts_with('_') {
+            Token::Tag(word, number, start..end)
+        } else if word.eq_ignore_ascii_case("loop_") {
+            Token::Loop
+        } else if word
+            .get(..5)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("data_"))
+        {
+            Token::Data(&word[5..])
+        } else {
+            Token::Value(Value {
+                text: match word {
+                    "." | "?" => None,
+                    _ => Some(Cow::Borrowed(word)),
+                },
+                line: number,
+                columns: start..end,
+            })
+        };
+        tokens.push(token);
+        start = end;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CIF: &str = "\
+data_1ABC
+# a comment
+_entry.id 1ABC
+_cell.length_a 52.000
+_cell.length_b   58.600 # trailing comment
+_struct.title
+;A multi-line
+title field
+;
+loop_
+_atom_site.group_PDB
+_atom_site.auth_atom_id
+_atom_site.label_alt_id
+ATOM \"O5'\" .
+HETATM 'it''s' ?
+";
+
+    #[test]
+    fn test_parse_blocks() {
+        let blocks = parse(CIF).unwrap();
+        assert_eq!(blocks.len(), 1);
+        let block = &blocks[0];
+        assert_eq!(block.name, "1ABC");
+        let cell: Vec<_> = block.rows("_cell").collect();
+        assert_eq!(cell.len(), 1);
+        assert_eq!(cell[0].str("length_b"), Some("58.600"));
+        assert_eq!(cell[0].parse::<f32>("length_a"), Ok(Some(52.0)));
+        let title = block.rows("_struct").next().unwrap();
+        assert_eq!(title.str("title"), Some("A multi-line\ntitle field"));
+
+        let atoms: Vec<_> = block.rows("_atom_site").collect();
+        assert_eq!(atoms.len(), 2);
+        assert_eq!(atoms[0].str("auth_atom_id"), Some("O5'"));
+        assert_eq!(atoms[0].str("label_alt_id"), None);
+        assert_eq!(atoms[1].str("auth_atom_id"), Some("it''s"));
+        assert_eq!(atoms[1].line(), 15);
+    }
+
+    #[test]
+    fn test_parse_non_ascii() {
+        let blocks = parse("data_x\n_struct.title ÅÅÅÅ\nloop_\n_a.b\nééé\n").unwrap();
+        let title = blocks[0].rows("_struct").next().unwrap();
+        assert_eq!(title.str("title"), Some("ÅÅÅÅ"));
+        assert_eq!(blocks[0].rows("_a").next().unwrap().str("b"), Some("ééé"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("data_x\n_cell.length_a 'unterminated\n").unwrap_err();
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.kind, ParseErrorKind::TooShort);
+
+        let error = parse("data_x\nloop_\n_a.b\n_a.c\n1 2 3\n").unwrap_err();
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.kind, ParseErrorKind::InvalidValue);
+
+        let error = parse("data_x\n;never closed\n").unwrap_err();
+        assert_eq!(error.line, Some(2));
+    }
+}
@@ -0,0 +1,395 @@
+//! Serialization of records to mmCIF text.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::Write;
+
+use crate::records::{anisotropic::AnisotropicRecord, atom::AtomRecord, Record};
+
+/// Items of the `_atom_site` loop, in output order.
+const ATOM_SITE_ITEMS: [&str; 20] = [
+    "group_PDB",
+    "id",
+    "type_symbol",
+    "label_atom_id",
+    "label_alt_id",
+    "label_comp_id",
+    "label_asym_id",
+    "label_seq_id",
+    "pdbx_PDB_ins_code",
+    "Cartn_x",
+    "Cartn_y",
+    "Cartn_z",
+    "occupancy",
+    "B_iso_or_equiv",
+    "pdbx_formal_charge",
+    "auth_seq_id",
+    "auth_comp_id",
+    "auth_asym_id",
+    "auth_atom_id",
+    "pdbx_PDB_model_num",
+];
+
+/// Residue names of water, which has no place in a polymer sequence.
+const WATER: [&str; 3] = ["HOH", "DOD", "WAT"];
+
+/// Items of the `_atom_site_anisotrop` loop, in output order.
+const ANISOTROP_ITEMS: [&str; 8] = [
+    "id",
+    "type_symbol",
+    "U[1][1]",
+    "U[2][2]",
+    "U[3][3]",
+    "U[1][2]",
+    "U[1][3]",
+    "U[2][3]",
+];
+
+/// Write records as a single mmCIF data block named `name`.
+///
+/// The block contains `_entry`, `_cell` and `_symmetry` (when a CRYST1 record is
+/// present), an `_atom_site` loop with every ATOM and HETATM record, and an
+/// `_atom_site_anisotrop` loop for any ANISOU records. Atoms are numbered by the MODEL
+/// record preceding them, or model 1 when there is none. `_atom_site.id` must be unique
+/// across the whole file, so atoms are given running ids in place of their serial
+/// numbers and each ANISOU record is matched to the atom with its serial in the same
+/// model; ANISOU records without such an atom are dropped. Unlike PDB output, residue
+/// numbers and chain identifiers are written in full, so nothing is truncated for large
+/// structures. Other record types are not written.
+///
+/// # Errors
+///
+/// Returns any I/O error raised by `writer`.
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::{mmcif::write_mmcif, pdbreader_single};
+///
+/// let records = pdbreader_single(
+///     "ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N",
+/// );
+/// let mut output = Vec::new();
+/// write_mmcif(&mut output, "1ABC", &records).unwrap();
+/// let output = String::from_utf8(output).unwrap();
+/// assert!(output.starts_with("data_1ABC\n"));
+/// assert!(output.contains(" N . ALA A 1 ? 20.154 16.967 27.462 1.00 11.18 ? 1 ALA A N 1\n"));
+/// ```
+pub fn write_mmcif<W: Write>(mut writer: W, name: &str, records: &[Record]) -> std::io::Result<()> {
+    writeln!(writer, "data_{}", name)?;
+    writeln!(writer, "#")?;
+    writeln!(writer, "_entry.id {}", quote(name))?;
+    writeln!(writer, "#")?;
+
+    let crystal = records.iter().find_map(|record| match record {
+        Record::Crystal(crystal) => Some(crystal),
+        _ => None,
+    });
+    if let Some(crystal) = crystal {
+        writeln!(writer, "_cell.entry_id {}", quote(name))?;
+        writeln!(writer, "_cell.length_a {:.3}", crystal.a)?;
+        writeln!(writer, "_cell.length_b {:.3}", crystal.b)?;
+        writeln!(writer, "_cell.length_c {:.3}", crystal.c)?;
+        writeln!(writer, "_cell.angle_alpha {:.2}", crystal.alpha)?;
+        writeln!(writer, "_cell.angle_beta {:.2}", crystal.beta)?;
+        writeln!(writer, "_cell.angle_gamma {:.2}", crystal.gamma)?;
+        writeln!(writer, "_cell.Z_PDB {}", crystal.z)?;
+        writeln!(writer, "#")?;
+        writeln!(writer, "_symmetry.entry_id {}", quote(name))?;
+        writeln!(
+            writer,
+            "_symmetry.space_group_name_H-M {}",
+            quote(&crystal.space_group)
+        )?;
+        writeln!(writer, "#")?;
+    }
+
+    let mut model = 1;
+    let mut atoms = Vec::new();
+    let mut anisotropic = Vec::new();
+    // Running id of each serial number in the current model.
+    let mut ids: HashMap<u32, usize> = HashMap::new();
+    for record in records {
+        match record {
+            Record::Model(record) => {
+                model = record.serial;
+                ids.clear();
+            }
+            Record::Atom(atom) | Record::Hetatm(atom) => {
+                let group = match record {
+                    Record::Hetatm(_) => "HETATM",
+                    _ => "ATOM",
+                };
+                atoms.push((group, atom, model));
+                ids.insert(atom.serial, atoms.len());
+            }
+            Record::Anisou(anisou) => {
+                if let Some(&id) = ids.get(&anisou.serial) {
+                    anisotropic.push((id, anisou));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if !atoms.is_empty() {
+        write_loop_header(&mut writer, "_atom_site", &ATOM_SITE_ITEMS)?;
+        for (index, (group, atom, model)) in atoms.into_iter().enumerate() {
+            write_atom_site(&mut writer, index + 1, group, atom, model)?;
+        }
+        writeln!(writer, "#")?;
+    }
+    if !anisotropic.is_empty() {
+        write_loop_header(&mut writer, "_atom_site_anisotrop", &ANISOTROP_ITEMS)?;
+        for (id, anisou) in anisotropic {
+            write_anisotrop(&mut writer, id, anisou)?;
+        }
+        writeln!(writer, "#")?;
+    }
+    Ok(())
+}
+
+fn write_loop_header<W: Write>(
+    writer: &mut W,
+    category: &str,
+    items: &[&str],
+) -> std::io::Result<()> {
+    writeln!(writer, "loop_")?;
+    for item in items {
+        writeln!(writer, "{}.{}", category, item)?;
+    }
+    Ok(())
+}
+
+fn write_atom_site<W: Write>(
+    writer: &mut W,
+    id: usize,
+    group: &str,
+    atom: &AtomRecord,
+    model: u32,
+) -> std::io::Result<()> {
+    // Chain identifiers too long for the PDB column are kept in `auth_chain` by the reader.
+    let chain = match (atom.chain_id, &atom.auth_chain) {
+        (Some(chain_id), _) => Some(chain_id.to_string()),
+        (None, auth_chain) => auth_chain.clone(),
+    };
+    let chain = optional(chain, ".");
+    let alt_loc = optional(atom.alt_loc.map(String::from), ".");
+    // Only polymer residues have a place in the entity sequence.
+    let label_seq_id = match group == "HETATM" || WATER.contains(&atom.res_name.as_str()) {
+        true => Cow::Borrowed("."),
+        false => Cow::Owned(atom.res_seq.to_string()),
+    };
+    writeln!(
+        writer,
+        "{} {} {} {} {} {} {} {} {} {:.3} {:.3} {:.3} {:.2} {:.2} {} {} {} {} {} {}",
+        group,
+        id,
+        optional(atom.element.clone(), "?"),
+        quote(&atom.name),
+        alt_loc,
+        quote(&atom.res_name),
+        chain,
+        label_seq_id,
+        optional(atom.i_code.map(String::from), "?"),
+        atom.x,
+        atom.y,
+        atom.z,
+        atom.occupancy,
+        atom.temp_factor,
+        optional(atom.charge.map(|charge| charge.to_string()), "?"),
+        atom.res_seq,
+        quote(&atom.res_name),
+        chain,
+        quote(&atom.name),
+        model,
+    )
+}
+
+fn write_anisotrop<W: Write>(
+    writer: &mut W,
+    id: usize,
+    anisou: &AnisotropicRecord,
+) -> std::io::Result<()> {
+    // PDB ANISOU records store U(i,j) as integers scaled by 10^4.
+    let u = |value: i32| value as f64 / 10000.0;
+    writeln!(
+        writer,
+        "{} {} {:.4} {:.4} {:.4} {:.4} {:.4} {:.4}",
+        id,
+        optional(anisou.element.clone(), "?"),
+        u(anisou.u00),
+        u(anisou.u11),
+        u(anisou.u22),
+        u(anisou.u01),
+        u(anisou.u02),
+        u(anisou.u12),
+    )
+}
+
+/// Quote an optional value, writing `null` (`.` or `?`) when it is absent or blank.
+fn optional(value: Option<String>, null: &'static str) -> Cow<'static, str> {
+    match value {
+        Some(value) if !value.trim().is_empty() => Cow::Owned(quote(&value).into_owned()),
+        _ => Cow::Borrowed(null),
+    }
+}
+
+/// Quote a value if it would otherwise be read as something other than plain text.
+fn quote(value: &str) -> Cow<'_, str> {
+    let reserved = value.is_empty()
+        || value == "."
+        || value == "?"
+        || value.starts_with(['_', '#', '$', '\'', '"', '[', ']', ';'])
+        || value.eq_ignore_ascii_case("loop_")
+        || value.eq_ignore_ascii_case("stop_")
+        || value.eq_ignore_ascii_case("global_")
+        || value.get(..5).is_some_and(|prefix| {
+            prefix.eq_ignore_ascii_case("data_") || prefix.eq_ignore_ascii_case("save_")
+        });
+    if !reserved && !value.contains(char::is_whitespace) {
+        return Cow::Borrowed(value);
+    }
+    // A quote only closes a value when followed by whitespace, so pick one that doesn't.
+    if !value.contains("' ") && !value.ends_with('\'') {
+        Cow::Owned(format!("'{}'", value))
+    } else {
+        Cow::Owned(format!("\"{}\"", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mmcif::read_mmcif;
+    use crate::reader::ReaderOptions;
+
+    const CONTENTS: &str = "\
+CRYST1   52.000   58.600   61.900  90.00  90.00  90.00 P 21 21 21    8
+MODEL        1
+ATOM      1  N   MET A   1      27.340  24.430   2.614  1.00  9.67           N
+ANISOU    1  N   MET A   1      688   1234    806    -19    -49    178       N
+ATOM      2  CA AMET A   1      27.340  24.430   2.614  0.50  9.67           C
+HETATM    3  O5' HOH A 101      15.123  12.456  30.789  1.00 25.50           O
+ENDMDL
+MODEL        2
+ATOM      1  N   MET A   1      27.340  24.430   2.614  1.00  9.67           N
+ENDMDL
+";
+
+    #[test]
+    fn test_write_mmcif_round_trip() {
+        let records = ReaderOptions::new().read(CONTENTS).unwrap().records;
+        let mut output = Vec::new();
+        write_mmcif(&mut output, "1ABC", &records).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("_symmetry.space_group_name_H-M 'P 21 21 21'\n"));
+
+        let report = read_mmcif(&output, &ReaderOptions::new()).unwrap();
+        assert!(report.diagnostics.is_empty());
+        assert_eq!(report.records.len(), records.len());
+        let mut id = 0;
+        for (read, written) in report.records.iter().zip(&records) {
+            match (read, written) {
+                (Record::Atom(read), Record::Atom(written))
+                | (Record::Hetatm(read), Record::Hetatm(written)) => {
+                    // Serial numbers come back as the running ids they were written as.
+                    id += 1;
+                    assert_eq!(read.serial, id);
+                    assert_eq!(read.name, written.name);
+                    assert_eq!(read.alt_loc, written.alt_loc);
+                    assert_eq!(read.chain_id, written.chain_id);
+                    assert_eq!(read.res_seq, written.res_seq);
+                    assert_eq!(read.occupancy, written.occupancy);
+                }
+                (Record::Anisou(read), Record::Anisou(written)) => {
+                    assert_eq!(read.serial, id);
+                    assert_eq!(read.u00, written.u00);
+                    assert_eq!(read.u01, written.u01);
+                }
+                (Record::Crystal(read), Record::Crystal(written)) => {
+                    assert_eq!(read.space_group, written.space_group);
+                }
+                (Record::Model(read), Record::Model(written)) => {
+                    assert_eq!(read.serial, written.serial);
+                }
+                (Record::Endmdl(), Record::Endmdl()) => {}
+                _ => panic!("Record order changed: {:?} != {:?}", read, written),
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_large_values() {
+        let mut records = ReaderOptions::new().read(CONTENTS).unwrap().records;
+        if let Record::Atom(atom) = &mut records[2] {
+            atom.serial = 123456;
+            atom.chain_id = None;
+            atom.auth_chain = Some("AAA".to_string());
+        }
+        let mut output = Vec::new();
+        write_mmcif(&mut output, "big", &records).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("ATOM 1 N N . MET AAA 1 ? "));
+    }
+
+    #[test]
+    fn test_write_unique_ids() {
+        let contents = CONTENTS.replace(
+            "ENDMDL\nMODEL        2\nATOM      1  N   MET A   1      27.340  24.430   2.614  1.00  9.67           N\n",
+            "ENDMDL\nMODEL        2\nATOM      1  N   MET A   1      27.340  24.430   2.614  1.00  9.67           N\n\
+             ANISOU    1  N   MET A   1      100    200    300      0      0      0       N\n\
+             ANISOU    9  N   MET A   1      100    200    300      0      0      0       N\n",
+        );
+        let records = ReaderOptions::new().read(&contents).unwrap().records;
+        let mut output = Vec::new();
+        write_mmcif(&mut output, "ids", &records).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        let ids: Vec<&str> = output
+            .lines()
+            .filter(|line| line.starts_with("ATOM ") || line.starts_with("HETATM "))
+            .map(|line| line.split(' ').nth(1).unwrap())
+            .collect();
+        assert_eq!(ids, ["1", "2", "3", "4"]);
+        // Each ANISOU row points at the atom of its own model; the unmatched one is dropped.
+        assert!(output.contains("\n1 N 0.0688 "));
+        assert!(output.contains("\n4 N 0.0100 "));
+        assert_eq!(output.matches(" N 0.0100 ").count(), 1);
+        // Water has no place in the polymer sequence.
+        assert!(output.contains("HETATM 3 O O5' . HOH A . ? "));
+        assert!(output.contains("ATOM 1 N N . MET A 1 ? "));
+    }
+
+    #[test]
+    fn test_write_segment_id_is_not_chain() {
+        let mut records = ReaderOptions::new().read(CONTENTS).unwrap().records;
+        if let Record::Atom(atom) = &mut records[2] {
+            atom.chain_id = None;
+            atom.segment_id = Some("PROT".to_string());
+        }
+        let mut output = Vec::new();
+        write_mmcif(&mut output, "seg", &records).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(!output.contains("PROT"));
+        assert!(output.contains(" N . MET . 1 ? "));
+        assert!(output.contains(" 1 MET . N 1\n"));
+
+        let report = read_mmcif(&output, &ReaderOptions::new()).unwrap();
+        match &report.records[2] {
+            Record::Atom(atom) => assert_eq!((atom.chain_id, &atom.auth_chain), (None, &None)),
+            _ => panic!("Expected ATOM"),
+        }
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("CA"), "CA");
+        assert_eq!(quote("O5'"), "O5'");
+        assert_eq!(quote("P 1"), "'P 1'");
+        assert_eq!(quote("it' s"), "\"it' s\"");
+        assert_eq!(quote("."), "'.'");
+        assert_eq!(quote("data_x"), "'data_x'");
+        assert_eq!(quote(""), "''");
+    }
+}
@@ -0,0 +1,193 @@
+//! Non-crystallographic symmetry from MTRIXn records.
+//!
+//! Each set of MTRIX1, MTRIX2 and MTRIX3 records sharing a serial number describes one
+//! non-crystallographic symmetry (NCS) operator. When the copy it produces is already in
+//! the file, the records are flagged as given; otherwise only one copy of the molecule is
+//! deposited and the rest have to be generated. Entries of large symmetric particles such
+//! as virus capsids often rely on this.
+//!
+//! [`operators`] collects the operators and [`generate`] applies those that are not given
+//! to the deposited atoms.
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::{ncs, reader::ReaderOptions};
+//!
+//! let contents = "\
+//! MTRIX1   1  1.000000  0.000000  0.000000        0.00000    1
+//! MTRIX2   1  0.000000  1.000000  0.000000        0.00000    1
+//! MTRIX3   1  0.000000  0.000000  1.000000        0.00000    1
+//! MTRIX1   2 -1.000000  0.000000  0.000000       20.00000
+//! MTRIX2   2  0.000000 -1.000000  0.000000        0.00000
+//! MTRIX3   2  0.000000  0.000000  1.000000        0.00000
+//! ATOM      1  CA  GLY A   1       5.000   1.000   2.000  1.00  0.00           C
+//! ";
+//! let records = ReaderOptions::new().read(contents).unwrap().records;
+//!
+//! assert_eq!(ncs::operators(&records).len(), 2);
+//! let copies = ncs::generate(&records);
+//! assert_eq!(copies.len(), 1);
+//! assert_eq!(copies[0].operator, 2);
+//! ```
+
+use crate::records::{
+    mtrixn::{MtrixN, MtrixnRecord},
+    Record,
+};
+use crate::transform::{transform_atoms, Transform};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An NCS operator assembled from the three MTRIXn records with the same serial number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NcsOperator {
+    /// Serial number shared by the three records
+    pub serial: u32,
+    /// The rigid transform in Cartesian coordinates
+    pub transform: Transform,
+    /// Whether the copy produced by the operator is already in the file
+    pub given: bool,
+}
+
+/// A copy of the deposited atoms generated by an NCS operator.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NcsCopy {
+    /// Serial number of the operator
+    pub operator: u32,
+    /// ATOM and HETATM records with transformed coordinates
+    pub records: Vec<Record>,
+}
+
+/// Collect the NCS operators of the MTRIXn records in `records`, in order of serial number
+/// first appearance.
+///
+/// Operators missing one of the three rows are left out. An operator counts as given when
+/// any of its rows is flagged as given.
+pub fn operators(records: &[Record]) -> Vec<NcsOperator> {
+    let mut rows: Vec<(u32, [Option<&MtrixnRecord>; 3])> = Vec::new();
+    for record in records {
+        // The row comes from the record type, as `n` is a public field that may be anything.
+        let (n, row) = match record {
+            Record::MtrixN(MtrixN::Mtrix1(row)) => (0, row),
+            Record::MtrixN(MtrixN::Mtrix2(row)) => (1, row),
+            Record::MtrixN(MtrixN::Mtrix3(row)) => (2, row),
+            _ => continue,
+        };
+        let index = match rows
+            .iter()
+            .position(|(serial, _)| *serial == row.serial_number)
+        {
+            Some(index) => index,
+            None => {
+                rows.push((row.serial_number, [None; 3]));
+                rows.len() - 1
+            }
+        };
+        rows[index].1[n] = Some(row);
+    }
+    rows.into_iter()
+        .filter_map(|(serial, rows)| {
+            let [Some(first), Some(second), Some(third)] = rows else {
+                return None;
+            };
+            let rows = [first, second, third];
+            Some(NcsOperator {
+                serial,
+                transform: Transform::from_rows(rows.map(|row| (row.matrix, row.vn))),
+                given: rows.iter().any(|row| row.i_given),
+            })
+        })
+        .collect()
+}
+
+/// Generate the copies of the deposited ATOM and HETATM records for every NCS operator
+/// whose copy is not given.
+///
+/// Copies keep the chain identifiers of the deposited atoms; [`NcsCopy::operator`] tells
+/// them apart. Identity operators, which some files list without the given flag, are
+/// skipped.
+pub fn generate(records: &[Record]) -> Vec<NcsCopy> {
+    operators(records)
+        .into_iter()
+        .filter(|operator| !operator.given && !operator.transform.is_identity(1e-5))
+        .map(|operator| NcsCopy {
+            operator: operator.serial,
+            records: transform_atoms(records, &operator.transform),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records(lines: &[&str]) -> Vec<Record> {
+        lines
+            .iter()
+            .map(|line| Record::try_from(*line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_operators() {
+        let records = records(&[
+            "MTRIX1   1  1.000000  0.000000  0.000000        0.00000    1",
+            "MTRIX2   1  0.000000  1.000000  0.000000        0.00000    1",
+            "MTRIX3   1  0.000000  0.000000  1.000000        0.00000    1",
+            "MTRIX1   2  0.000000 -1.000000  0.000000       10.00000",
+            "MTRIX2   2  1.000000  0.000000  0.000000        0.00000",
+            "MTRIX3   2  0.000000  0.000000  1.000000       -5.00000",
+            "MTRIX1   3  1.000000  0.000000  0.000000        0.00000",
+            "MTRIX2   3  0.000000  1.000000  0.000000        0.00000",
+        ]);
+        let operators = operators(&records);
+        assert_eq!(operators.len(), 2);
+        assert!(operators[0].given);
+        assert!(operators[0].transform.is_identity(0.0));
+        assert_eq!(operators[1].serial, 2);
+        assert!(!operators[1].given);
+        assert_eq!(
+            operators[1].transform.apply([1.0, 2.0, 3.0]),
+            [8.0, 1.0, -2.0]
+        );
+
+        // The row follows the record type even when `n` disagrees.
+        let mut records = records;
+        if let Record::MtrixN(MtrixN::Mtrix1(row)) = &mut records[3] {
+            row.n = 0;
+        }
+        assert_eq!(super::operators(&records), operators);
+    }
+
+    #[test]
+    fn test_generate() {
+        let records = records(&[
+            "MTRIX1   1  1.000000  0.000000  0.000000        0.00000",
+            "MTRIX2   1  0.000000  1.000000  0.000000        0.00000",
+            "MTRIX3   1  0.000000  0.000000  1.000000        0.00000",
+            "MTRIX1   2 -1.000000  0.000000  0.000000       20.00000",
+            "MTRIX2   2  0.000000 -1.000000  0.000000        0.00000",
+            "MTRIX3   2  0.000000  0.000000  1.000000        0.00000",
+            "MTRIX1   3 -1.000000  0.000000  0.000000        0.00000    1",
+            "MTRIX2   3  0.000000  1.000000  0.000000        0.00000    1",
+            "MTRIX3   3  0.000000  0.000000 -1.000000        0.00000    1",
+            "ATOM      1  CA  GLY A   1       5.000   1.000   2.000  1.00  0.00           C",
+            "TER       2      GLY A   1",
+            "HETATM    3  ZN   ZN A 101       1.000   1.000   1.000  1.00  0.00          ZN",
+        ]);
+        let copies = generate(&records);
+        assert_eq!(copies.len(), 1);
+        assert_eq!(copies[0].operator, 2);
+        assert_eq!(copies[0].records.len(), 2);
+        let Record::Atom(atom) = &copies[0].records[0] else {
+            panic!("expected an atom");
+        };
+        assert_eq!((atom.x, atom.y, atom.z), (15.0, -1.0, 2.0));
+        assert_eq!(atom.chain_id, Some('A'));
+        assert!(matches!(copies[0].records[1], Record::Hetatm(_)));
+    }
+}
@@ -0,0 +1,594 @@
+//! Configurable PDB reading with strict and lenient error handling.
+//!
+//! [`ReaderOptions`] controls how lines that fail to parse are treated. In
+//! [`ParseMode::Lenient`] mode they are skipped and collected as diagnostics, while in
+//! [`ParseMode::Strict`] mode the first malformed line aborts the read.
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::reader::{ParseMode, ReaderOptions};
+//!
+//! let contents = "ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N\n\
+//!                 ATOM      2  CA  ALA A   1      20.987  abcdefg  27.890  1.00 11.85           C";
+//!
+//! let report = ReaderOptions::new().read(contents).unwrap();
+//! assert_eq!(report.records.len(), 1);
+//! assert_eq!(report.diagnostics[0].line, Some(2));
+//!
+//! let error = ReaderOptions::new()
+//!     .mode(ParseMode::Strict)
+//!     .read(contents)
+//!     .unwrap_err();
+//! assert_eq!(error.line, Some(2));
+//! ```
+//!
+//! For files too large to hold in memory, [`PdbReader`] parses any [`BufRead`] source one
+//! line at a time.
+//!
+//! Both readers can report the [`Span`] each record was read from, for pointing back into
+//! the source text.
+
+use std::fmt;
+use std::io::BufRead;
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::records::{Record, RecordKinds};
+
+/// How a reader reacts to lines that fail to parse.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Skip lines that fail to parse and report them as diagnostics
+    #[default]
+    Lenient,
+    /// Stop at the first line that fails to parse
+    Strict,
+}
+
+/// A predicate selecting which parsed records to keep.
+pub type RecordFilter = Arc<dyn Fn(&Record) -> bool + Send + Sync>;
+
+/// Options controlling how PDB contents are read.
+#[derive(Clone, Default)]
+pub struct ReaderOptions {
+    /// How lines that fail to parse are handled
+    pub mode: ParseMode,
+    /// Whether to record the span of every parsed record
+    pub spans: bool,
+    /// Which parsed records to keep; all of them when unset
+    pub filter: Option<RecordFilter>,
+    /// Which record types to parse; all of them when unset
+    pub kinds: Option<RecordKinds>,
+}
+
+impl fmt::Debug for ReaderOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReaderOptions")
+            .field("mode", &self.mode)
+            .field("spans", &self.spans)
+            .field("filter", &self.filter.as_ref().map(|_| ".."))
+            .field("kinds", &self.kinds)
+            .finish()
+    }
+}
+
+/// Where in the source text a record was read from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Span {
+    /// One-based line number
+    pub line: usize,
+    /// Byte range of the line within the source, excluding the line terminator; an mmCIF
+    /// row split over several lines covers all of them
+    pub bytes: Range<usize>,
+}
+
+/// The records read from a PDB file along with any lines that were skipped.
+#[derive(Debug, Default)]
+pub struct ParseReport {
+    /// Successfully parsed records, in file order
+    pub records: Vec<Record>,
+    /// Lines that were skipped, each tagged with its line number
+    pub diagnostics: Vec<ParseError>,
+    /// Span of each record in `records`, at the same index; empty unless
+    /// [`ReaderOptions::spans`] is set
+    pub spans: Vec<Span>,
+}
+
+impl ReaderOptions {
+    /// Create options with the default, lenient, parse mode.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how lines that fail to parse are handled.
+    pub fn mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set whether the span of every parsed record is kept in [`ParseReport::spans`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use knuckles_parse::reader::ReaderOptions;
+    ///
+    /// let contents = "TER\nEND\n";
+    /// let report = ReaderOptions::new().spans(true).read(contents).unwrap();
+    ///
+    /// assert_eq!(report.spans[1].line, 2);
+    /// assert_eq!(&contents[report.spans[1].bytes.clone()], "END");
+    /// ```
+    pub fn spans(mut self, spans: bool) -> Self {
+        self.spans = spans;
+        self
+    }
+
+    /// Keep only the records for which `filter` returns true.
+    ///
+    /// Atom serial numbers are backfilled before filtering, so they do not depend on the
+    /// filter. The filter may capture its environment, such as a set of residue names to
+    /// keep. To select records by type, [`ReaderOptions::kinds`] is cheaper since it
+    /// skips the other lines without parsing them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use knuckles_parse::{reader::ReaderOptions, Record};
+    ///
+    /// let contents = "ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N\n\
+    ///                 TER\n";
+    /// let report = ReaderOptions::new()
+    ///     .filter(|record| matches!(record, Record::Atom(_)))
+    ///     .read(contents)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(report.records.len(), 1);
+    /// ```
+    pub fn filter(mut self, filter: impl Fn(&Record) -> bool + Send + Sync + 'static) -> Self {
+        self.filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Parse only lines whose record type is in `kinds`.
+    ///
+    /// Other lines are skipped after looking at their record name, before any field is
+    /// parsed, so they produce neither records nor diagnostics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use knuckles_parse::{reader::ReaderOptions, records::RecordKinds};
+    ///
+    /// let contents = "ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N\n\
+    ///                 ANISOU    1  N   ALA A   1      688   1234    806    -19    -49    178       N\n";
+    /// let report = ReaderOptions::new()
+    ///     .kinds(RecordKinds::ATOM | RecordKinds::HETATM)
+    ///     .read(contents)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(report.records.len(), 1);
+    /// ```
+    pub fn kinds(mut self, kinds: RecordKinds) -> Self {
+        self.kinds = Some(kinds);
+        self
+    }
+
+    /// Read PDB file contents according to these options.
+    ///
+    /// Blank lines are ignored. Lines with a record type this crate does not support are
+    /// kept as [`Record::Unknown`]. Atom serial numbers that are blank, as in files with
+    /// more than 99,999 atoms, are backfilled by counting on from the previous atom.
+    ///
+    /// When the `parallel` feature is enabled the lines are parsed on the Rayon thread
+    /// pool; the result is identical either way.
+    ///
+    /// # Errors
+    ///
+    /// In [`ParseMode::Strict`] mode, returns the first error in file order. Lenient
+    /// reads never fail.
+    pub fn read(&self, contents: &str) -> Result<ParseReport, ParseError> {
+        self.read_lines(contents, cfg!(feature = "parallel"))
+    }
+
+    /// Read PDB file contents, parsing on the Rayon thread pool only when `parallel` is
+    /// set and the `parallel` feature is enabled.
+    pub(crate) fn read_lines(
+        &self,
+        contents: &str,
+        parallel: bool,
+    ) -> Result<ParseReport, ParseError> {
+        let mut report = ParseReport::default();
+        let mut serials = SerialBackfill::default();
+        for (span, result) in self.parse_lines(contents, parallel) {
+            match result {
+                Ok(mut record) => {
+                    serials.apply(&mut record);
+                    if self.filter.as_ref().is_some_and(|filter| !filter(&record)) {
+                        continue;
+                    }
+                    report.records.push(record);
+                    if self.spans {
+                        report.spans.push(span);
+                    }
+                }
+                Err(error) => {
+                    let error = error.with_line(span.line);
+                    if self.mode == ParseMode::Strict {
+                        return Err(error);
+                    }
+                    report.diagnostics.push(error);
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Parse every non-blank line of a selected record type, pairing each result with
+    /// the span of its line.
+    #[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+    fn parse_lines(
+        &self,
+        contents: &str,
+        parallel: bool,
+    ) -> Vec<(Span, Result<Record, ParseError>)> {
+        let selected = |line: &str| !line.trim().is_empty() && is_selected(self.kinds, line);
+        #[cfg(feature = "parallel")]
+        if parallel {
+            use rayon::prelude::*;
+
+            let lines: Vec<(Span, &str)> = spanned_lines(contents).collect();
+            return lines
+                .into_par_iter()
+                .filter(|(_, line)| selected(line))
+                .map(|(span, line)| (span, Record::try_from(line)))
+                .collect();
+        }
+        spanned_lines(contents)
+            .filter(|(_, line)| selected(line))
+            .map(|(span, line)| (span, Record::try_from(line)))
+            .collect()
+    }
+}
+
+/// Whether the record type of `line` is in `kinds`, treating no selection as all types.
+fn is_selected(kinds: Option<RecordKinds>, line: &str) -> bool {
+    kinds.is_none_or(|kinds| kinds.contains(RecordKinds::of_line(line)))
+}
+
+/// Split `contents` into lines like [`str::lines`], pairing each with its span.
+pub(crate) fn spanned_lines(contents: &str) -> impl Iterator<Item = (Span, &str)> {
+    let mut start = 0;
+    contents
+        .split_inclusive('\n')
+        .enumerate()
+        .map(move |(index, raw)| {
+            let line = raw
+                .strip_suffix('\n')
+                .map(|line| line.strip_suffix('\r').unwrap_or(line))
+                .unwrap_or(raw);
+            let span = Span {
+                line: index + 1,
+                bytes: start..start + line.len(),
+            };
+            start += raw.len();
+            (span, line)
+        })
+}
+
+/// Streaming PDB reader yielding one record per line of a [`BufRead`] source.
+///
+/// Only the current line is held in memory, so arbitrarily large files (such as
+/// multi-model trajectories) can be processed record by record. Blank lines are skipped,
+/// errors carry their line number, and atom serial numbers are backfilled across the
+/// stream in the same way as the other readers. It is up to the caller whether an error
+/// ends the read; iteration stops after an I/O error. Bytes that are not valid UTF-8 are
+/// replaced with U+FFFD and the line is parsed as usual.
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::{reader::PdbReader, Record};
+///
+/// let contents = "MODEL        1\n\
+///                 ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N\n\
+///                 ENDMDL\n";
+/// let atoms = PdbReader::new(contents.as_bytes())
+///     .filter_map(Result::ok)
+///     .filter(|record| matches!(record, Record::Atom(_)))
+///     .count();
+/// assert_eq!(atoms, 1);
+/// ```
+pub struct PdbReader<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    line: usize,
+    offset: usize,
+    span: Span,
+    kinds: Option<RecordKinds>,
+    serials: SerialBackfill,
+    done: bool,
+}
+
+impl<R: BufRead> PdbReader<R> {
+    /// Create a reader over a buffered source, such as a `BufReader<File>`.
+    pub fn new(reader: R) -> Self {
+        PdbReader {
+            reader,
+            buffer: Vec::new(),
+            line: 0,
+            offset: 0,
+            span: Span::default(),
+            kinds: None,
+            serials: SerialBackfill::default(),
+            done: false,
+        }
+    }
+
+    /// Parse only lines whose record type is in `kinds`, skipping the others as
+    /// [`ReaderOptions::kinds`] does.
+    pub fn kinds(mut self, kinds: RecordKinds) -> Self {
+        self.kinds = Some(kinds);
+        self
+    }
+
+    /// The one-based number of the last line read.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The span of the last line read, which for a parsed record or parse error is the
+    /// line it came from.
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl<R: BufRead> Iterator for PdbReader<R> {
+    type Item = Result<Record, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.buffer.clear();
+            match self.reader.read_until(b'\n', &mut self.buffer) {
+                Ok(0) => self.done = true,
+                Ok(read) => {
+                    self.line += 1;
+                    // Bytes that are not UTF-8 are replaced rather than ending the read, as
+                    // they usually sit in a free-text field of a single record.
+                    let text = String::from_utf8_lossy(&self.buffer);
+                    let line = text.trim_end_matches(['\n', '\r']);
+                    let ending = self.buffer.iter().rev();
+                    let length = read - ending.take_while(|b| matches!(b, b'\n' | b'\r')).count();
+                    self.span = Span {
+                        line: self.line,
+                        bytes: self.offset..self.offset + length,
+                    };
+                    self.offset += read;
+                    if line.trim().is_empty() || !is_selected(self.kinds, line) {
+                        continue;
+                    }
+                    let result = match Record::try_from(line) {
+                        Ok(mut record) => {
+                            self.serials.apply(&mut record);
+                            Ok(record)
+                        }
+                        Err(error) => Err(error.with_line(self.line)),
+                    };
+                    return Some(result);
+                }
+                Err(error) => {
+                    self.done = true;
+                    let error = ParseError::new("PDB", 0..0, error.to_string(), ParseErrorKind::Io);
+                    return Some(Err(error.with_line(self.line + 1)));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Running state for backfilling atom serial numbers one record at a time.
+///
+/// This is necessary for some PDB files, which have more than 99999 atoms and leave
+/// the serial field blank once it overflows.
+#[derive(Debug, Default)]
+struct SerialBackfill {
+    last: u32,
+}
+
+impl SerialBackfill {
+    fn apply(&mut self, record: &mut Record) {
+        if let Record::Atom(atom) = record {
+            if atom.serial == 0 {
+                self.last += 1;
+                atom.serial = self.last;
+            } else {
+                self.last = atom.serial;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENTS: &str = "\
+JRNL        AUTH   A.UTHOR
+ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N
+ATOM      2  CA  ALA A   1      20.987  18.149  2x.890  1.00 11.85           C
+
+ATOM         C   ALA A   1      21.987  18.149  27.890  1.00 11.85           C
+TER
+";
+
+    #[test]
+    fn test_lenient_read() {
+        let report = ReaderOptions::new().read(CONTENTS).unwrap();
+        assert_eq!(report.records.len(), 4);
+        assert_eq!(report.diagnostics.len(), 1);
+        assert_eq!(report.diagnostics[0].line, Some(3));
+        assert_eq!(report.diagnostics[0].columns, 46..54);
+        assert!(matches!(report.records[0], Record::Unknown(_)));
+        match &report.records[2] {
+            Record::Atom(atom) => assert_eq!(atom.serial, 2),
+            _ => panic!("Wrong record type"),
+        }
+        assert!(matches!(report.records[3], Record::Term(_)));
+    }
+
+    #[test]
+    fn test_strict_read() {
+        let error = ReaderOptions::new()
+            .mode(ParseMode::Strict)
+            .read(CONTENTS)
+            .unwrap_err();
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+    }
+
+    #[test]
+    fn test_pdb_reader() {
+        let results: Vec<_> = PdbReader::new(CONTENTS.replace('\n', "\r\n").as_bytes()).collect();
+        assert_eq!(results.len(), 5);
+        assert!(matches!(results[0], Ok(Record::Unknown(_))));
+        assert_eq!(results[2].as_ref().unwrap_err().line, Some(3));
+        match &results[3] {
+            Ok(Record::Atom(atom)) => assert_eq!(atom.serial, 2),
+            _ => panic!("Wrong record type"),
+        }
+        assert!(matches!(results[4], Ok(Record::Term(_))));
+
+        let records: Vec<_> = PdbReader::new(CONTENTS.as_bytes())
+            .filter_map(Result::ok)
+            .collect();
+        let report = ReaderOptions::new().read(CONTENTS).unwrap();
+        assert_eq!(records.len(), report.records.len());
+    }
+
+    #[test]
+    fn test_readers_agree() {
+        let expected: Vec<String> = ReaderOptions::new()
+            .read(CONTENTS)
+            .unwrap()
+            .records
+            .iter()
+            .map(Record::to_string)
+            .collect();
+        let single: Vec<String> = crate::pdbreader_single(CONTENTS)
+            .iter()
+            .map(Record::to_string)
+            .collect();
+        assert_eq!(single, expected);
+        #[cfg(feature = "parallel")]
+        {
+            let parallel: Vec<String> = crate::pdbreader_parallel(CONTENTS)
+                .iter()
+                .map(Record::to_string)
+                .collect();
+            assert_eq!(parallel, expected);
+        }
+    }
+
+    #[test]
+    fn test_filter() {
+        let report = ReaderOptions::new()
+            .filter(|record| matches!(record, Record::Atom(atom) if atom.name == "C"))
+            .spans(true)
+            .read(CONTENTS)
+            .unwrap();
+        assert_eq!(report.records.len(), 1);
+        assert_eq!(report.spans[0].line, 5);
+        match &report.records[0] {
+            Record::Atom(atom) => assert_eq!(atom.serial, 2),
+            _ => panic!("Wrong record type"),
+        }
+
+        let names = ["N".to_string(), "C".to_string()];
+        let options = ReaderOptions::new().filter(
+            move |record| matches!(record, Record::Atom(atom) if names.contains(&atom.name)),
+        );
+        assert_eq!(options.read(CONTENTS).unwrap().records.len(), 2);
+        assert!(format!("{:?}", options).contains("filter: Some(\"..\")"));
+    }
+
+    #[test]
+    fn test_kinds() {
+        let kinds = RecordKinds::ATOM | RecordKinds::UNKNOWN;
+        let report = ReaderOptions::new().kinds(kinds).read(CONTENTS).unwrap();
+        assert_eq!(report.records.len(), 3);
+        assert!(report
+            .records
+            .iter()
+            .all(|record| kinds.contains(record.kind())));
+        assert_eq!(report.diagnostics.len(), 1);
+
+        let report = ReaderOptions::new()
+            .kinds(RecordKinds::TER)
+            .mode(ParseMode::Strict)
+            .read(CONTENTS)
+            .unwrap();
+        assert_eq!(report.records.len(), 1);
+
+        let records: Vec<_> = PdbReader::new(CONTENTS.as_bytes())
+            .kinds(RecordKinds::TER)
+            .collect();
+        assert!(matches!(records[..], [Ok(Record::Term(_))]));
+    }
+
+    #[test]
+    fn test_spans() {
+        assert!(ReaderOptions::new()
+            .read(CONTENTS)
+            .unwrap()
+            .spans
+            .is_empty());
+
+        for contents in [CONTENTS.to_string(), CONTENTS.replace('\n', "\r\n")] {
+            let report = ReaderOptions::new().spans(true).read(&contents).unwrap();
+            assert_eq!(report.spans.len(), report.records.len());
+            let lines: Vec<usize> = report.spans.iter().map(|span| span.line).collect();
+            assert_eq!(lines, vec![1, 2, 5, 6]);
+            assert_eq!(&contents[report.spans[3].bytes.clone()], "TER");
+
+            let mut reader = PdbReader::new(contents.as_bytes());
+            let mut spans = Vec::new();
+            while let Some(result) = reader.next() {
+                if result.is_ok() {
+                    spans.push(reader.span());
+                }
+            }
+            assert_eq!(spans, report.spans);
+        }
+    }
+
+    #[test]
+    fn test_pdb_reader_invalid_utf8() {
+        let mut reader = PdbReader::new(&b"TER\nUSER  \xff\nTER\n"[..]);
+        assert!(matches!(reader.next(), Some(Ok(Record::Term(_)))));
+        match reader.next() {
+            Some(Ok(Record::Unknown(unknown))) => assert_eq!(unknown.line, "USER  \u{fffd}"),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(matches!(reader.next(), Some(Ok(Record::Term(_)))));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_pdb_reader_io_error() {
+        struct Failing;
+        impl std::io::Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("disk on fire"))
+            }
+        }
+        let mut reader = PdbReader::new(std::io::BufReader::new(Failing));
+        let error = reader.next().unwrap().unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::Io);
+        assert_eq!(error.line, Some(1));
+        assert!(reader.next().is_none());
+    }
+}
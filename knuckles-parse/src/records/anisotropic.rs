@@ -1,6 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError};
+use crate::hybrid36::encode_field;
+use crate::records::atom::{format_atom_name, format_charge, parse_charge};
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -17,7 +21,7 @@ pub struct AnisotropicRecord {
     pub alt_loc: Option<char>,
     pub res_name: String,
     pub chain_id: char,
-    pub res_seq: i16,
+    pub res_seq: i32,
     pub i_code: Option<char>,
     pub u00: i32,
     pub u11: i32,
@@ -26,43 +30,64 @@ pub struct AnisotropicRecord {
     pub u02: i32,
     pub u12: i32,
     pub element: Option<String>,
-    pub charge: Option<String>,
+    pub charge: Option<i8>,
 }
 
 impl AnisotropicRecord {
-    pub fn new(str: &str) -> AnisotropicRecord {
-        AnisotropicRecord {
-            serial: str[6..11].trim().parse::<u32>().unwrap_or_default(),
-            name: str[12..16].trim().to_string(),
-            alt_loc: str[16..17].trim().parse::<char>().ok(),
-            res_name: str[17..20].trim().to_string(),
-            chain_id: str[21..22].trim().parse::<char>().unwrap(),
-            res_seq: str[22..26].trim().parse().unwrap(),
-            i_code: str[26..27].trim().parse::<char>().ok(),
-            u00: str[28..35].trim().parse().unwrap(),
-            u11: str[35..42].trim().parse().unwrap(),
-            u22: str[42..49].trim().parse().unwrap(),
-            u01: str[49..56].trim().parse().unwrap(),
-            u02: str[56..63].trim().parse().unwrap(),
-            u12: str[63..70].trim().parse().unwrap(),
-            element: str
-                .get(76..78)
-                .map(|str| str.trim().to_string())
-                .filter(|item| !item.is_empty()),
-            charge: str
-                .get(78..80)
-                .map(|str| str.trim().to_string())
-                .filter(|item| !item.is_empty()),
-        }
+    pub fn new(str: &str) -> Result<AnisotropicRecord, ParseError> {
+        let columns = Columns::new("ANISOU", str);
+        Ok(AnisotropicRecord {
+            serial: columns.hybrid36_or_default(6..11),
+            name: columns.str(12..16)?.to_string(),
+            alt_loc: columns.opt_char(16),
+            res_name: columns.str(17..20)?.to_string(),
+            chain_id: columns.char(21)?,
+            res_seq: columns.hybrid36(22..26)?,
+            i_code: columns.opt_char(26),
+            u00: columns.parse(28..35)?,
+            u11: columns.parse(35..42)?,
+            u22: columns.parse(42..49)?,
+            u01: columns.parse(49..56)?,
+            u02: columns.parse(56..63)?,
+            u12: columns.parse(63..70)?,
+            element: columns.opt_str(76..78),
+            charge: parse_charge(&columns, 78..80)?,
+        })
     }
 }
 
-impl From<&str> for AnisotropicRecord {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for AnisotropicRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         AnisotropicRecord::new(str)
     }
 }
 
+impl std::fmt::Display for AnisotropicRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "ANISOU{} {}{}{:>3} {}{}{} {:>7}{:>7}{:>7}{:>7}{:>7}{:>7}      {:>2}{:<2}",
+            encode_field(5, self.serial),
+            format_atom_name(&self.name, self.element.as_deref()),
+            self.alt_loc.unwrap_or(' '),
+            self.res_name,
+            self.chain_id,
+            encode_field(4, self.res_seq),
+            self.i_code.unwrap_or(' '),
+            self.u00,
+            self.u11,
+            self.u22,
+            self.u01,
+            self.u02,
+            self.u12,
+            self.element.as_deref().unwrap_or_default(),
+            format_charge(self.charge),
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,7 +96,7 @@ mod tests {
     fn parse_anisou_line_test() {
         const LINE: &str =
             "ANISOU    1  N   MET A   1      688   1234    806    -19    -49    178       N  ";
-        let record = AnisotropicRecord::new(LINE);
+        let record = AnisotropicRecord::new(LINE).unwrap();
         assert_eq!(record.serial, 1);
         assert_eq!(record.name, "N");
         assert_eq!(record.res_name, "MET");
@@ -86,7 +111,7 @@ mod tests {
         assert_eq!(record.element, Some("N".to_string()));
         const LINE2: &str =
             "ANISOU    1  N   MET A   1      688   1234    806    -19    -49    178          ";
-        let record = AnisotropicRecord::new(LINE2);
+        let record = AnisotropicRecord::new(LINE2).unwrap();
         assert_eq!(record.serial, 1);
         assert_eq!(record.name, "N");
         assert_eq!(record.res_name, "MET");
@@ -100,4 +125,11 @@ mod tests {
         assert_eq!(record.u12, 178);
         assert_eq!(record.element, None);
     }
+    #[test]
+    fn anisou_record_display_test() {
+        const LINE: &str =
+            "ANISOU    1  N   MET A   1      688   1234    806    -19    -49    178       N  ";
+        let record = AnisotropicRecord::new(LINE).unwrap();
+        assert_eq!(record.to_string(), LINE);
+    }
 }
@@ -1,6 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use std::ops::Range;
+
+use crate::error::{Columns, ParseError, ParseErrorKind};
+use crate::hybrid36::encode_field;
+
 #[cfg(feature = "python")]
 use knuckles_macro::pydefault;
 
@@ -20,7 +25,7 @@ use pyo3::prelude::*;
 ///
 /// # Fields
 ///
-/// - `serial`: Atom serial number (1-99999, or hexadecimal for >99999)
+/// - `serial`: Atom serial number (hybrid-36 encoded above 99999)
 /// - `name`: Atom name (e.g., "CA", "N", "O")
 /// - `alt_loc`: Alternative location indicator
 /// - `res_name`: Residue name (e.g., "ALA", "GLY", "HOH")
@@ -31,8 +36,9 @@ use pyo3::prelude::*;
 /// - `occupancy`: Occupancy value (0.0-1.0)
 /// - `temp_factor`: Temperature factor (B-factor)
 /// - `element`: Element symbol
-/// - `charge`: Formal charge
-/// - `entry`: PDB entry identifier
+/// - `charge`: Formal charge (e.g. `-1` for `1-`)
+/// - `segment_id`: Segment identifier
+/// - `auth_chain`: Full chain identifier when it does not fit in `chain_id`
 ///
 /// # Example
 ///
@@ -40,7 +46,7 @@ use pyo3::prelude::*;
 /// use knuckles_parse::records::atom::AtomRecord;
 ///
 /// let line = "ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N";
-/// let atom = AtomRecord::from(line);
+/// let atom = AtomRecord::try_from(line).unwrap();
 ///
 /// assert_eq!(atom.serial, 1);
 /// assert_eq!(atom.name, "N");
@@ -55,7 +61,7 @@ use pyo3::prelude::*;
 )]
 #[cfg_attr(feature = "python", pydefault)]
 pub struct AtomRecord {
-    /// Atom serial number (1-99999, or hexadecimal for larger values)
+    /// Atom serial number (hybrid-36 encoded above 99999)
     pub serial: u32,
     /// Atom name (e.g., "CA", "N", "O")
     pub name: String,
@@ -66,7 +72,7 @@ pub struct AtomRecord {
     /// Chain identifier
     pub chain_id: Option<char>,
     /// Residue sequence number
-    pub res_seq: i16,
+    pub res_seq: i32,
     /// Insertion code for residues
     pub i_code: Option<char>,
     /// X coordinate in Ångströms
@@ -81,17 +87,22 @@ pub struct AtomRecord {
     pub temp_factor: f32,
     /// Element symbol
     pub element: Option<String>,
-    /// Formal charge
-    pub charge: Option<String>,
-    /// PDB entry identifier
-    pub entry: Option<String>,
+    /// Formal charge, parsed from PDB notation such as `2+` or `1-`
+    pub charge: Option<i8>,
+    /// Segment identifier (columns 73-76)
+    pub segment_id: Option<String>,
+    /// Full chain identifier when it is longer than the one character `chain_id` holds,
+    /// as in mmCIF files of large complexes; PDB files have no column for it
+    pub auth_chain: Option<String>,
 }
 
 impl AtomRecord {
     /// Create a new AtomRecord by parsing a PDB ATOM or HETATM line.
     ///
     /// This method parses fixed-width fields according to the PDB format specification.
-    /// It handles both decimal and hexadecimal serial numbers (for atoms > 99999).
+    /// Serial and residue numbers are read as [hybrid-36](crate::hybrid36), so files with
+    /// more than 99,999 atoms or 9,999 residues are supported. Serials that start with a
+    /// digit but are not decimal are read as hexadecimal, as written by VMD.
     ///
     /// # Arguments
     ///
@@ -101,9 +112,10 @@ impl AtomRecord {
     ///
     /// A new `AtomRecord` with all fields parsed from the input line.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if required numeric fields cannot be parsed (coordinates, occupancy, etc.)
+    /// Returns a [`ParseError`] if the line is too short or a required numeric field
+    /// (residue number, coordinates, occupancy, temperature factor) cannot be parsed.
     ///
     /// # Example
     ///
@@ -111,51 +123,128 @@ impl AtomRecord {
     /// use knuckles_parse::records::atom::AtomRecord;
     ///
     /// let line = "ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N";
-    /// let atom = AtomRecord::new(line);
+    /// let atom = AtomRecord::new(line).unwrap();
     /// assert_eq!(atom.name, "N");
     /// ```
-    pub fn new(str: &str) -> AtomRecord {
-        let mut radix = 10;
-        let serial = str[6..11].trim();
-        if serial.chars().any(|c| c.is_ascii_alphabetic()) {
-            radix = 16;
-        }
-        AtomRecord {
-            // TODO: add support for parsing serial numbers > 99999
-            serial: u32::from_str_radix(serial, radix).unwrap_or_default(),
-            name: str[12..16].trim().to_string(),
-            alt_loc: str[16..17].trim().parse::<char>().ok(),
-            res_name: str[17..20].trim().to_string(),
-            chain_id: str[21..22].trim().parse::<char>().ok(),
-            res_seq: str[22..26].trim().parse().unwrap(),
-            i_code: str[26..27].trim().parse().ok(),
-            x: str[30..38].trim().parse().unwrap(),
-            y: str[38..46].trim().parse().unwrap(),
-            z: str[46..54].trim().parse().unwrap(),
-            occupancy: str[54..60].trim().parse().unwrap(),
-            temp_factor: str[60..66].trim().parse().unwrap(),
-            entry: str
-                .get(72..76)
-                .map(|str| str.trim().to_string())
-                .filter(|item| !item.is_empty()),
-            element: str
-                .get(77..80)
-                .map(|str| str.trim().to_string())
-                .filter(|item| !item.is_empty()),
-            charge: str
-                .get(78..80)
-                .map(|str| str.trim().to_string())
-                .filter(|item| !item.is_empty()),
-        }
+    pub fn new(str: &str) -> Result<AtomRecord, ParseError> {
+        let columns = Columns::new("ATOM", str);
+        let serial = columns.str(6..11)?;
+        Ok(AtomRecord {
+            // Blank or unreadable serials are left as 0 and backfilled by the readers.
+            serial: columns
+                .opt_hybrid36(6..11)
+                .ok()
+                .flatten()
+                .or_else(|| u32::from_str_radix(serial, 16).ok())
+                .unwrap_or_default(),
+            name: columns.str(12..16)?.to_string(),
+            alt_loc: columns.opt_char(16),
+            res_name: columns.str(17..20)?.to_string(),
+            chain_id: columns.opt_char(21),
+            res_seq: columns.hybrid36(22..26)?,
+            i_code: columns.opt_char(26),
+            x: columns.parse(30..38)?,
+            y: columns.parse(38..46)?,
+            z: columns.parse(46..54)?,
+            occupancy: columns.parse(54..60)?,
+            temp_factor: columns.parse(60..66)?,
+            segment_id: columns.opt_str(72..76),
+            element: columns.opt_str(76..78),
+            charge: parse_charge(&columns, 78..80)?,
+            auth_chain: None,
+        })
     }
 }
 
-impl From<&str> for AtomRecord {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for AtomRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         AtomRecord::new(str)
     }
 }
 
+impl AtomRecord {
+    /// Write the record as a fixed-column PDB line using the given record name.
+    ///
+    /// Shared by the ATOM and HETATM representations, which differ only in columns 1-6.
+    pub(crate) fn fmt_record(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        record: &str,
+    ) -> std::fmt::Result {
+        let line = format!(
+            "{:<6}{} {}{}{:>3} {}{:>4}{}   {:8.3}{:8.3}{:8.3}{:6.2}{:6.2}      {:<4}{:>2}{:<2}",
+            record,
+            encode_field(5, self.serial),
+            format_atom_name(&self.name, self.element.as_deref()),
+            self.alt_loc.unwrap_or(' '),
+            self.res_name,
+            self.chain_id.unwrap_or(' '),
+            encode_field(4, self.res_seq),
+            self.i_code.unwrap_or(' '),
+            self.x,
+            self.y,
+            self.z,
+            self.occupancy,
+            self.temp_factor,
+            self.segment_id.as_deref().unwrap_or_default(),
+            self.element.as_deref().unwrap_or_default(),
+            format_charge(self.charge),
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+impl std::fmt::Display for AtomRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_record(f, "ATOM")
+    }
+}
+
+/// Align an atom name within columns 13-16.
+///
+/// Four-character names fill the field. Otherwise names start in column 14 so the
+/// element symbol lines up in columns 13-14, unless the element itself has two letters.
+pub(crate) fn format_atom_name(name: &str, element: Option<&str>) -> String {
+    let two_letter_element =
+        element.is_some_and(|element| element.len() == 2 && name.starts_with(element));
+    if name.len() >= 4 || two_letter_element {
+        format!("{:<4}", name)
+    } else {
+        format!(" {:<3}", name)
+    }
+}
+
+/// Parse a formal charge written as `2+` or `1-`; a leading sign (`-1`) is also accepted.
+pub(crate) fn parse_charge(
+    columns: &Columns,
+    range: Range<usize>,
+) -> Result<Option<i8>, ParseError> {
+    let Some(text) = columns.opt_str(range.clone()) else {
+        return Ok(None);
+    };
+    let charge = match text.strip_suffix('+') {
+        Some(magnitude) => magnitude.parse::<i8>().ok(),
+        None => match text.strip_suffix('-') {
+            Some(magnitude) => magnitude.parse::<i8>().ok().map(|magnitude| -magnitude),
+            None => text.parse::<i8>().ok(),
+        },
+    };
+    charge
+        .map(Some)
+        .ok_or_else(|| columns.error(range, ParseErrorKind::InvalidNumber))
+}
+
+/// Format a formal charge in PDB notation (`2+`, `1-`), leaving zero or no charge blank.
+pub(crate) fn format_charge(charge: Option<i8>) -> String {
+    match charge {
+        Some(charge) if charge > 0 => format!("{}+", charge),
+        Some(charge) if charge < 0 => format!("{}-", -(charge as i16)),
+        _ => String::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,7 +253,7 @@ mod tests {
     fn parse_atom_line_test() {
         const LINE: &str =
             "ATOM     17  NE2 GLN     2      25.562  32.733   1.806  1.00 19.49      1UBQ    ";
-        let record = AtomRecord::new(LINE);
+        let record = AtomRecord::new(LINE).unwrap();
         assert_eq!(record.serial, 17);
         assert_eq!(record.name, "NE2");
         assert_eq!(record.alt_loc, None);
@@ -175,7 +264,7 @@ mod tests {
         assert_eq!(record.z, 1.806);
         assert_eq!(record.occupancy, 1.00);
         assert_eq!(record.temp_factor, 19.49);
-        assert_eq!(record.entry, Some("1UBQ".to_string()));
+        assert_eq!(record.segment_id, Some("1UBQ".to_string()));
         assert_eq!(record.element, None);
         assert_eq!(record.charge, None);
     }
@@ -184,7 +273,7 @@ mod tests {
     fn parse_atom_line_hex_test() {
         const LINE: &str =
             "ATOM  186a0  CA  GLY A  67      26.731  62.085   4.078  0.00  7.83           C  ";
-        let record = AtomRecord::new(LINE);
+        let record = AtomRecord::new(LINE).unwrap();
         assert_eq!(record.serial, 100000);
         assert_eq!(record.name, "CA");
         assert_eq!(record.alt_loc, None);
@@ -195,8 +284,64 @@ mod tests {
         assert_eq!(record.z, 4.078);
         assert_eq!(record.occupancy, 0.00);
         assert_eq!(record.temp_factor, 7.83);
-        assert_eq!(record.entry, None);
+        assert_eq!(record.segment_id, None);
         assert_eq!(record.element, Some("C".to_string()));
         assert_eq!(record.charge, None);
     }
+    #[test]
+    fn parse_atom_line_error_test() {
+        use crate::error::ParseErrorKind;
+
+        const LINE: &str = "ATOM     17  NE2 GLN     2      25.562  32.733";
+        let error = AtomRecord::new(LINE).unwrap_err();
+        assert_eq!(error.record, "ATOM");
+        assert_eq!(error.columns, 46..54);
+        assert_eq!(error.kind, ParseErrorKind::TooShort);
+
+        const BAD: &str =
+            "ATOM     17  NE2 GLN    2a      25.562  32.733   1.806  1.00 19.49      1UBQ    ";
+        let error = AtomRecord::new(BAD).unwrap_err();
+        assert_eq!(error.columns, 22..26);
+        assert_eq!(error.text, "2a");
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+    }
+    #[test]
+    fn parse_atom_line_element_charge_test() {
+        const LINE: &str =
+            "ATOM   1234 FE   HEM A 201      10.000  11.000  12.000  1.00 20.00      HEMEFE2+";
+        let record = AtomRecord::new(LINE).unwrap();
+        assert_eq!(record.segment_id, Some("HEME".to_string()));
+        assert_eq!(record.element, Some("FE".to_string()));
+        assert_eq!(record.charge, Some(2));
+        assert_eq!(record.to_string(), LINE);
+
+        let record = AtomRecord::new(&LINE.replace("2+", "1-")).unwrap();
+        assert_eq!(record.charge, Some(-1));
+
+        let error = AtomRecord::new(&LINE.replace("2+", "x+")).unwrap_err();
+        assert_eq!(error.columns, 78..80);
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+    }
+
+    #[test]
+    fn atom_record_display_test() {
+        const LINE: &str =
+            "ATOM     17  NE2 GLN     2      25.562  32.733   1.806  1.00 19.49      1UBQ    ";
+        let record = AtomRecord::new(LINE).unwrap();
+        assert_eq!(record.to_string(), LINE);
+        const HYBRID36: &str =
+            "ATOM  A0000  CA  GLY AA000      26.731  62.085   4.078  0.00  7.83           C  ";
+        let record = AtomRecord::new(HYBRID36).unwrap();
+        assert_eq!(record.to_string(), HYBRID36);
+    }
+
+    #[test]
+    fn parse_atom_line_hybrid36_test() {
+        const LINE: &str =
+            "ATOM  A0000  CA  GLY Aa000      26.731  62.085   4.078  0.00  7.83           C  ";
+        let record = AtomRecord::new(LINE).unwrap();
+        assert_eq!(record.serial, 100000);
+        assert_eq!(record.res_seq, 1223056);
+        assert_eq!(record.chain_id, Some('A'));
+    }
 }
@@ -0,0 +1,28 @@
+use super::continued::continued_text_record;
+
+continued_text_record! {
+    /// Represents an AUTHOR record from the title section of a PDB file.
+    ///
+    /// The names of the people responsible for the contents of the entry, separated by commas.
+    ///
+    /// # Fields
+    ///
+    /// - `continuation`: Continuation number, absent on the first line
+    /// - `text`: Comma-separated author names on this line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use knuckles_parse::records::author::AuthorRecord;
+    ///
+    /// let record = AuthorRecord::try_from("AUTHOR   2 G.N.PHILLIPS JR.,T.L.ST. STEVENS").unwrap();
+    /// assert_eq!(record.continuation, Some(2));
+    /// assert_eq!(record.text, "G.N.PHILLIPS JR.,T.L.ST. STEVENS");
+    /// ```
+    AuthorRecord {
+        record: "AUTHOR",
+        continuation: 8..10,
+        /// Comma-separated author names on this line
+        text,
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a CAVEAT record, warning of severe errors in an entry.
+///
+/// # Fields
+///
+/// - `continuation`: Continuation number, absent on the first line
+/// - `id_code`: PDB identifier of the entry
+/// - `comment`: Free text describing the problem on this line
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::caveat::CaveatRecord;
+///
+/// let line = "CAVEAT     1ABC    INCORRECT CHIRALITY AT RESIDUE 12";
+/// let caveat = CaveatRecord::try_from(line).unwrap();
+///
+/// assert_eq!(caveat.id_code, "1ABC");
+/// assert_eq!(caveat.comment, "INCORRECT CHIRALITY AT RESIDUE 12");
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct CaveatRecord {
+    /// Continuation number, absent on the first line
+    pub continuation: Option<u32>,
+    /// PDB identifier of the entry
+    pub id_code: String,
+    /// Free text describing the problem on this line
+    pub comment: String,
+}
+
+impl CaveatRecord {
+    /// Create a new CaveatRecord by parsing a CAVEAT line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the continuation number is not a number.
+    pub fn new(str: &str) -> Result<CaveatRecord, ParseError> {
+        let columns = Columns::new("CAVEAT", str);
+        Ok(CaveatRecord {
+            continuation: columns.opt_parse(8..10)?,
+            id_code: columns.opt_str(11..15).unwrap_or_default(),
+            comment: columns.rest(19),
+        })
+    }
+}
+
+impl TryFrom<&str> for CaveatRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        CaveatRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for CaveatRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let continuation = self
+            .continuation
+            .map(|continuation| continuation.to_string())
+            .unwrap_or_default();
+        let line = format!(
+            "CAVEAT  {:>2} {:<4}    {}",
+            continuation, self.id_code, self.comment
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caveat_new() {
+        let line = "CAVEAT   2 1ABC    IS DESCRIBED IN REMARK 5";
+        let record = CaveatRecord::new(line).unwrap();
+        assert_eq!(record.continuation, Some(2));
+        assert_eq!(record.id_code, "1ABC");
+        assert_eq!(record.comment, "IS DESCRIBED IN REMARK 5");
+    }
+
+    #[test]
+    fn test_caveat_display() {
+        for line in [
+            "CAVEAT     1ABC    INCORRECT CHIRALITY AT RESIDUE 12",
+            "CAVEAT   2 1ABC    IS DESCRIBED IN REMARK 5",
+        ] {
+            let record = CaveatRecord::new(line).unwrap();
+            assert_eq!(record.to_string(), format!("{:<80}", line));
+        }
+    }
+}
@@ -0,0 +1,144 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a CISPEP record, a peptide bond in the cis conformation.
+///
+/// # Fields
+///
+/// - `serial`: Serial number of the record
+/// - `res_name1` / `res_name2`: Names of the residues either side of the bond
+/// - `chain_id1` / `chain_id2`: Chain identifiers of the two residues
+/// - `seq_num1` / `seq_num2`: Sequence numbers of the two residues
+/// - `i_code1` / `i_code2`: Insertion codes of the two residues
+/// - `mod_num`: Model number, 0 for single-model entries
+/// - `measure`: Omega angle in degrees
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::cispep::CispepRecord;
+///
+/// let line = "CISPEP   1 SER A   58    GLY A   59          0        20.91";
+/// let cispep = CispepRecord::try_from(line).unwrap();
+///
+/// assert_eq!((cispep.res_name1.as_str(), cispep.res_name2.as_str()), ("SER", "GLY"));
+/// assert_eq!(cispep.measure, Some(20.91));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct CispepRecord {
+    /// Serial number of the record
+    pub serial: u32,
+    /// Name of the first residue
+    pub res_name1: String,
+    /// Chain identifier of the first residue
+    pub chain_id1: char,
+    /// Sequence number of the first residue
+    pub seq_num1: i32,
+    /// Insertion code of the first residue
+    pub i_code1: Option<char>,
+    /// Name of the second residue
+    pub res_name2: String,
+    /// Chain identifier of the second residue
+    pub chain_id2: char,
+    /// Sequence number of the second residue
+    pub seq_num2: i32,
+    /// Insertion code of the second residue
+    pub i_code2: Option<char>,
+    /// Model number, 0 for single-model entries
+    pub mod_num: u32,
+    /// Omega angle in degrees
+    pub measure: Option<f32>,
+}
+
+impl CispepRecord {
+    /// Create a new CispepRecord by parsing a CISPEP line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the line ends before the second residue or a number is
+    /// missing or malformed.
+    pub fn new(str: &str) -> Result<CispepRecord, ParseError> {
+        let columns = Columns::new("CISPEP", str);
+        Ok(CispepRecord {
+            serial: columns.parse(7..10)?,
+            res_name1: columns.str(11..14)?.to_string(),
+            chain_id1: columns.char(15)?,
+            seq_num1: columns.parse(17..21)?,
+            i_code1: columns.opt_char(21),
+            res_name2: columns.str(25..28)?.to_string(),
+            chain_id2: columns.char(29)?,
+            seq_num2: columns.parse(31..35)?,
+            i_code2: columns.opt_char(35),
+            mod_num: columns.opt_parse(43..46)?.unwrap_or_default(),
+            measure: columns.opt_parse(53..59)?,
+        })
+    }
+}
+
+impl TryFrom<&str> for CispepRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        CispepRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for CispepRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "CISPEP {:>3} {:>3} {} {:>4}{}   {:>3} {} {:>4}{}{:>10}       {}",
+            self.serial,
+            self.res_name1,
+            self.chain_id1,
+            self.seq_num1,
+            self.i_code1.unwrap_or(' '),
+            self.res_name2,
+            self.chain_id2,
+            self.seq_num2,
+            self.i_code2.unwrap_or(' '),
+            self.mod_num,
+            self.measure
+                .map(|measure| format!("{:>6.2}", measure))
+                .unwrap_or_default(),
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cispep_new() {
+        let line = "CISPEP   2 PRO B  105A   TYR B  106          3        -6.33";
+        let record = CispepRecord::new(line).unwrap();
+        assert_eq!(record.serial, 2);
+        assert_eq!(record.res_name1, "PRO");
+        assert_eq!(record.chain_id1, 'B');
+        assert_eq!(record.seq_num1, 105);
+        assert_eq!(record.i_code1, Some('A'));
+        assert_eq!(record.res_name2, "TYR");
+        assert_eq!(record.seq_num2, 106);
+        assert_eq!(record.mod_num, 3);
+        assert_eq!(record.measure, Some(-6.33));
+    }
+
+    #[test]
+    fn test_cispep_display() {
+        let line = "CISPEP   1 SER A   58    GLY A   59          0        20.91";
+        let record = CispepRecord::new(line).unwrap();
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
+}
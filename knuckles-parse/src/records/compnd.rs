@@ -0,0 +1,31 @@
+use super::continued::continued_text_record;
+
+continued_text_record! {
+    /// Represents a COMPND record from the title section of a PDB file.
+    ///
+    /// Describes the macromolecular contents of the entry as a list of `TOKEN: value;` pairs,
+    /// starting a new molecule at every `MOL_ID`. See [`crate::title::TitleSection`] for the
+    /// parsed token lists.
+    ///
+    /// # Fields
+    ///
+    /// - `continuation`: Continuation number, absent on the first line
+    /// - `text`: Specification list describing the macromolecular contents on this line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use knuckles_parse::records::compnd::CompndRecord;
+    ///
+    /// let line = "COMPND   2 MOLECULE: HEMOGLOBIN ALPHA CHAIN;";
+    /// let record = CompndRecord::try_from(line).unwrap();
+    /// assert_eq!(record.continuation, Some(2));
+    /// assert_eq!(record.text, "MOLECULE: HEMOGLOBIN ALPHA CHAIN;");
+    /// ```
+    CompndRecord {
+        record: "COMPND",
+        continuation: 7..10,
+        /// Specification list describing the macromolecular contents on this line
+        text,
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError};
+use crate::hybrid36::encode_field;
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -24,7 +27,7 @@ use knuckles_macro::pydefault;
 /// use knuckles_parse::records::connect::ConnectRecord;
 ///
 /// let line = "CONECT  413  412  414                                                           ";
-/// let connect = ConnectRecord::from(line);
+/// let connect = ConnectRecord::try_from(line).unwrap();
 ///
 /// assert_eq!(connect.serial, 413);
 /// assert_eq!(connect.connected[0], Some(412));
@@ -54,35 +57,85 @@ impl ConnectRecord {
     /// # Returns
     ///
     /// A new `ConnectRecord` with parsed connectivity information.
-    pub fn new(str: &str) -> ConnectRecord {
-        ConnectRecord {
-            serial: str[6..11].trim().parse::<u32>().unwrap_or_default(),
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the atom serial number is missing or if it or a bonded
+    /// atom field holds something other than a number.
+    pub fn new(str: &str) -> Result<ConnectRecord, ParseError> {
+        let columns = Columns::new("CONECT", str);
+        Ok(ConnectRecord {
+            serial: columns.hybrid36(6..11)?,
             connected: [
-                str[11..16].trim().parse::<u32>().ok(),
-                str[16..21].trim().parse::<u32>().ok(),
-                str[21..26].trim().parse::<u32>().ok(),
-                str[26..31].trim().parse::<u32>().ok(),
+                columns.opt_hybrid36(11..16)?,
+                columns.opt_hybrid36(16..21)?,
+                columns.opt_hybrid36(21..26)?,
+                columns.opt_hybrid36(26..31)?,
             ],
-        }
+        })
     }
 }
 
-impl From<&str> for ConnectRecord {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for ConnectRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         ConnectRecord::new(str)
     }
 }
 
+impl std::fmt::Display for ConnectRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let connected: String = self
+            .connected
+            .iter()
+            .map(|serial| match serial {
+                Some(serial) => encode_field(5, *serial),
+                None => " ".repeat(5),
+            })
+            .collect();
+        write!(
+            f,
+            "{:<1$}",
+            format!("CONECT{}{}", encode_field(5, self.serial), connected),
+            80
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::ParseErrorKind;
 
     #[test]
     fn parse_connect_line_test() {
         const LINE: &str =
             "CONECT  413  412  414                                                           ";
-        let record = ConnectRecord::new(LINE);
+        let record = ConnectRecord::new(LINE).unwrap();
         assert_eq!(record.serial, 413);
         assert_eq!(record.connected, [Some(412), Some(414), None, None]);
+
+        const HYBRID36: &str = "CONECTA0000A0001";
+        let record = ConnectRecord::new(HYBRID36).unwrap();
+        assert_eq!(record.serial, 100000);
+        assert_eq!(record.connected[0], Some(100001));
+        assert_eq!(record.to_string().trim_end(), HYBRID36);
+    }
+    #[test]
+    fn parse_connect_errors_test() {
+        let error = ConnectRecord::new("CONECT       412  414").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::Missing);
+        assert_eq!(error.columns, 6..11);
+        let error = ConnectRecord::new("CONECT  4x3  412  414").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+        assert!(ConnectRecord::new("CONECT").is_err());
+    }
+    #[test]
+    fn connect_record_display_test() {
+        const LINE: &str =
+            "CONECT  413  412  414                                                           ";
+        let record = ConnectRecord::new(LINE).unwrap();
+        assert_eq!(record.to_string(), LINE);
     }
 }
@@ -0,0 +1,140 @@
+//! Shared definition of the title-section records that hold nothing but free text
+//! continued over several lines, such as TITLE, KEYWDS and AUTHOR.
+
+/// Define a record holding one line of continued free text.
+///
+/// The continuation number, absent on the first line, is read from `continuation`; the
+/// text starts right after it, at the column where `continuation` ends. The doc comments
+/// given are attached to the struct and to its `text` field.
+macro_rules! continued_text_record {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            record: $record:literal,
+            continuation: $columns:expr,
+            $(#[$text_meta:meta])*
+            text $(,)?
+        }
+    ) => {
+        #[cfg(feature = "serde")]
+        use serde::{Deserialize, Serialize};
+
+        use crate::error::{Columns, ParseError};
+
+        #[cfg(feature = "python")]
+        use knuckles_macro::pydefault;
+
+        #[cfg(feature = "python")]
+        use pyo3::prelude::*;
+
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+        #[cfg_attr(feature = "python", pydefault)]
+        pub struct $name {
+            /// Continuation number, absent on the first line
+            pub continuation: Option<u32>,
+            $(#[$text_meta])*
+            pub text: String,
+        }
+
+        impl $name {
+            #[doc = concat!(
+                "Create a new `", stringify!($name), "` by parsing one ", $record, " line."
+            )]
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`ParseError`] if the continuation number is not a number.
+            pub fn new(str: &str) -> Result<$name, ParseError> {
+                let columns = Columns::new($record, str);
+                let continuation: std::ops::Range<usize> = $columns;
+                Ok($name {
+                    continuation: columns.opt_parse(continuation.clone())?,
+                    text: columns.rest(continuation.end),
+                })
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = ParseError;
+
+            fn try_from(str: &str) -> Result<Self, Self::Error> {
+                $name::new(str)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let columns: std::ops::Range<usize> = $columns;
+                let line = match self.continuation {
+                    Some(continuation) => format!(
+                        "{:<start$}{:>width$} {}",
+                        $record,
+                        continuation,
+                        self.text,
+                        start = columns.start,
+                        width = columns.len(),
+                    ),
+                    None => format!("{:<end$}{}", $record, self.text, end = columns.end),
+                };
+                write!(f, "{:<1$}", line, 80)
+            }
+        }
+    };
+}
+
+pub(super) use continued_text_record;
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        author::AuthorRecord, compnd::CompndRecord, expdta::ExpdtaRecord, keywds::KeywdsRecord,
+        mdltyp::MdltypRecord, source::SourceRecord, title::TitleRecord,
+    };
+
+    /// Check that each line parses to the given continuation and text and is written
+    /// back unchanged.
+    macro_rules! check {
+        ($($record:ty: $line:literal => ($continuation:expr, $text:literal),)*) => {
+            $(
+                let record = <$record>::new($line).unwrap();
+                assert_eq!(record.continuation, $continuation, "{}", $line);
+                assert_eq!(record.text, $text, "{}", $line);
+                assert_eq!(record.to_string(), format!("{:<80}", $line));
+            )*
+        };
+    }
+
+    #[test]
+    fn test_continued_text_records() {
+        check! {
+            TitleRecord: "TITLE     STRUCTURE OF THE TRANSFORMED MONOCLINIC LYSOZYME BY"
+                => (None, "STRUCTURE OF THE TRANSFORMED MONOCLINIC LYSOZYME BY"),
+            TitleRecord: "TITLE    2 CONTROLLED DEHYDRATION"
+                => (Some(2), "CONTROLLED DEHYDRATION"),
+            KeywdsRecord: "KEYWDS    LYASE, TRICARBOXYLIC ACID CYCLE, MITOCHONDRION, OXIDATIVE"
+                => (None, "LYASE, TRICARBOXYLIC ACID CYCLE, MITOCHONDRION, OXIDATIVE"),
+            KeywdsRecord: "KEYWDS   2 METABOLISM" => (Some(2), "METABOLISM"),
+            ExpdtaRecord: "EXPDTA    NEUTRON DIFFRACTION; X-RAY DIFFRACTION"
+                => (None, "NEUTRON DIFFRACTION; X-RAY DIFFRACTION"),
+            ExpdtaRecord: "EXPDTA   2 ELECTRON MICROSCOPY"
+                => (Some(2), "ELECTRON MICROSCOPY"),
+            AuthorRecord: "AUTHOR    M.B.BERRY,B.MEADOR,T.BILDERBACK,P.LIANG,M.GLASER,"
+                => (None, "M.B.BERRY,B.MEADOR,T.BILDERBACK,P.LIANG,M.GLASER,"),
+            AuthorRecord: "AUTHOR   2 G.N.PHILLIPS JR.,T.L.ST. STEVENS"
+                => (Some(2), "G.N.PHILLIPS JR.,T.L.ST. STEVENS"),
+            MdltypRecord: "MDLTYP    CA ATOMS ONLY, CHAIN A, B, C, D, E, F, G, H, I, J, K ; P ATOMS ONLY,"
+                => (None, "CA ATOMS ONLY, CHAIN A, B, C, D, E, F, G, H, I, J, K ; P ATOMS ONLY,"),
+            MdltypRecord: "MDLTYP   2 CHAIN X, Y, Z" => (Some(2), "CHAIN X, Y, Z"),
+            CompndRecord: "COMPND    MOL_ID: 1;" => (None, "MOL_ID: 1;"),
+            CompndRecord: "COMPND   2 MOLECULE: HEMOGLOBIN ALPHA CHAIN;"
+                => (Some(2), "MOLECULE: HEMOGLOBIN ALPHA CHAIN;"),
+            CompndRecord: "COMPND  12 CHAIN: A;" => (Some(12), "CHAIN: A;"),
+            SourceRecord: "SOURCE    MOL_ID: 1;" => (None, "MOL_ID: 1;"),
+            SourceRecord: "SOURCE   2 ORGANISM_SCIENTIFIC: HOMO SAPIENS;"
+                => (Some(2), "ORGANISM_SCIENTIFIC: HOMO SAPIENS;"),
+        }
+    }
+}
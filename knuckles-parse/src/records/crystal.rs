@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError};
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -25,7 +27,7 @@ use knuckles_macro::pydefault;
 /// use knuckles_parse::records::crystal::CrystalRecord;
 ///
 /// let line = "CRYST1   52.000   58.600   61.900  90.00  90.00  90.00 P 21 21 21    8";
-/// let crystal = CrystalRecord::from(line);
+/// let crystal = CrystalRecord::try_from(line).unwrap();
 ///
 /// assert_eq!(crystal.a, 52.0);
 /// assert_eq!(crystal.space_group, "P 21 21 21");
@@ -66,26 +68,43 @@ impl CrystalRecord {
     /// # Returns
     ///
     /// A new `CrystalRecord` with parsed crystallographic parameters.
-    pub fn new(str: &str) -> CrystalRecord {
-        CrystalRecord {
-            a: str[6..15].trim().parse().unwrap_or_default(),
-            b: str[15..24].trim().parse().unwrap_or_default(),
-            c: str[24..33].trim().parse().unwrap_or_default(),
-            alpha: str[33..40].trim().parse().unwrap_or_default(),
-            beta: str[40..47].trim().parse().unwrap_or_default(),
-            gamma: str[47..54].trim().parse().unwrap_or_default(),
-            space_group: str[55..66].trim().to_string(),
-            z: str[66..70].trim().parse().unwrap_or_default(),
-        }
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the line ends before the space group field.
+    pub fn new(str: &str) -> Result<CrystalRecord, ParseError> {
+        let columns = Columns::new("CRYST1", str);
+        Ok(CrystalRecord {
+            a: columns.parse_or_default(6..15),
+            b: columns.parse_or_default(15..24),
+            c: columns.parse_or_default(24..33),
+            alpha: columns.parse_or_default(33..40),
+            beta: columns.parse_or_default(40..47),
+            gamma: columns.parse_or_default(47..54),
+            space_group: columns.str(55..66)?.to_string(),
+            z: columns.parse_or_default(66..70),
+        })
     }
 }
 
-impl From<&str> for CrystalRecord {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for CrystalRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         CrystalRecord::new(str)
     }
 }
 
+impl std::fmt::Display for CrystalRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "CRYST1{:9.3}{:9.3}{:9.3}{:7.2}{:7.2}{:7.2} {:<11}{:>4}",
+            self.a, self.b, self.c, self.alpha, self.beta, self.gamma, self.space_group, self.z
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,7 +112,7 @@ mod tests {
     fn parse_crystal_line_test() {
         const LINE: &str =
             "CRYST1   52.000   58.600   61.900  90.00  90.00  90.00 P 21 21 21    8          ";
-        let record = CrystalRecord::new(LINE);
+        let record = CrystalRecord::new(LINE).unwrap();
         assert_eq!(record.a, 52.0);
         assert_eq!(record.b, 58.6);
         assert_eq!(record.c, 61.9);
@@ -103,4 +122,11 @@ mod tests {
         assert_eq!(record.space_group, "P 21 21 21");
         assert_eq!(record.z, 8);
     }
+    #[test]
+    fn crystal_record_display_test() {
+        const LINE: &str =
+            "CRYST1   52.000   58.600   61.900  90.00  90.00  90.00 P 21 21 21    8          ";
+        let record = CrystalRecord::new(LINE).unwrap();
+        assert_eq!(record.to_string(), LINE);
+    }
 }
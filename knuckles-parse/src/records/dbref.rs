@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError, ParseErrorKind};
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -39,50 +41,93 @@ pub enum DBType {
 }
 
 impl DBType {
-    pub fn new(str: &str) -> Self {
+    pub fn new(str: &str) -> Result<Self, ParseError> {
         match str.trim() {
-            "GB" => DBType::GB,
-            "NORINE" | "NOR" => DBType::NORINE,
-            "PDB" => DBType::PDB,
-            "UNP" => DBType::UNP,
-            _ => panic!("Unknown DBType"),
+            "GB" => Ok(DBType::GB),
+            "NORINE" | "NOR" => Ok(DBType::NORINE),
+            "PDB" => Ok(DBType::PDB),
+            "UNP" => Ok(DBType::UNP),
+            item => Err(ParseError::new(
+                "DBREF",
+                0..str.len(),
+                item,
+                ParseErrorKind::InvalidValue,
+            )),
         }
     }
 }
 
 impl DBRefRecord {
-    pub fn new(str: &str) -> Self {
-        let database = DBType::new(&str[26..32]);
+    pub fn new(str: &str) -> Result<Self, ParseError> {
+        let columns = Columns::new("DBREF", str);
+        let database = DBType::new(columns.raw(26..32)?)
+            .map_err(|_| columns.error(26..32, ParseErrorKind::InvalidValue))?;
         let mut i_dbns_beg: Option<char> = None;
         let mut db_ins_end: Option<char> = None;
         if let DBType::PDB = database {
-            i_dbns_beg = Some(str.chars().nth(60).unwrap());
-            db_ins_end = Some(str.chars().nth(67).unwrap());
+            i_dbns_beg = Some(columns.char(60)?);
+            db_ins_end = Some(columns.char(67)?);
         }
-        DBRefRecord {
-            id_code: str[7..11].trim().to_string(),
-            chain_id: str.chars().nth(12).unwrap(),
-            seq_begin: str[14..18].trim().parse().unwrap(),
-            insert_begin: str[18..19].trim().parse().ok(),
-            seq_end: str[20..24].trim().parse().unwrap(),
-            insert_end: str[24..25].trim().parse().ok(),
+        Ok(DBRefRecord {
+            id_code: columns.str(7..11)?.to_string(),
+            chain_id: columns.char(12)?,
+            seq_begin: columns.parse(14..18)?,
+            insert_begin: columns.opt_char(18),
+            seq_end: columns.parse(20..24)?,
+            insert_end: columns.opt_char(24),
             database,
-            db_accession: str[33..41].trim().to_string(),
-            db_id_code: str[42..54].trim().to_string(),
-            db_seq_begin: str[55..60].trim().parse().unwrap(),
+            db_accession: columns.str(33..41)?.to_string(),
+            db_id_code: columns.str(42..54)?.to_string(),
+            db_seq_begin: columns.parse(55..60)?,
             i_dbns_beg,
-            db_seq_end: str[62..67].trim().parse().unwrap(),
+            db_seq_end: columns.parse(62..67)?,
             db_ins_end,
-        }
+        })
     }
 }
 
-impl From<&str> for DBRefRecord {
-    fn from(line: &str) -> Self {
+impl TryFrom<&str> for DBRefRecord {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
         Self::new(line)
     }
 }
 
+impl std::fmt::Display for DBType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code = match self {
+            DBType::GB => "GB",
+            DBType::NORINE => "NORINE",
+            DBType::PDB => "PDB",
+            DBType::UNP => "UNP",
+        };
+        f.pad(code)
+    }
+}
+
+impl std::fmt::Display for DBRefRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "DBREF  {:<4} {} {:>4}{} {:>4}{} {:<6} {:<8} {:<12} {:>5}{} {:>5}{}",
+            self.id_code,
+            self.chain_id,
+            self.seq_begin,
+            self.insert_begin.unwrap_or(' '),
+            self.seq_end,
+            self.insert_end.unwrap_or(' '),
+            self.database,
+            self.db_accession,
+            self.db_id_code,
+            self.db_seq_begin,
+            self.i_dbns_beg.unwrap_or(' '),
+            self.db_seq_end,
+            self.db_ins_end.unwrap_or(' '),
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,7 +135,7 @@ mod tests {
     #[test]
     fn test_new_dbref() {
         let line = "DBREF  2JHQ A    1   226  UNP    Q9KPK8   UNG_VIBCH        1    226";
-        let record = DBRefRecord::new(line);
+        let record = DBRefRecord::new(line).unwrap();
         assert_eq!("2JHQ", record.id_code);
         assert_eq!('A', record.chain_id);
         assert_eq!(1, record.seq_begin);
@@ -105,7 +150,7 @@ mod tests {
         assert_eq!(226, record.db_seq_end);
         assert_eq!(None, record.db_ins_end);
         let line = "DBREF  2JHQ A    1   226  PDB    Q9KPK8   UNG_VIBCH        1A   226B";
-        let record = DBRefRecord::new(line);
+        let record = DBRefRecord::new(line).unwrap();
         assert_eq!("2JHQ", record.id_code);
         assert_eq!('A', record.chain_id);
         assert_eq!(1, record.seq_begin);
@@ -120,4 +165,18 @@ mod tests {
         assert_eq!(226, record.db_seq_end);
         assert_eq!(Some('B'), record.db_ins_end);
     }
+    #[test]
+    fn test_dbref_unknown_database() {
+        let line = "DBREF  2JHQ A    1   226  XYZ    Q9KPK8   UNG_VIBCH        1    226";
+        let error = DBRefRecord::new(line).unwrap_err();
+        assert_eq!(error.columns, 26..32);
+        assert_eq!(error.text, "XYZ");
+        assert_eq!(error.kind, ParseErrorKind::InvalidValue);
+    }
+    #[test]
+    fn test_dbref_display() {
+        let line = "DBREF  2JHQ A    1   226  PDB    Q9KPK8   UNG_VIBCH        1A   226B";
+        let record = DBRefRecord::new(line).unwrap();
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
 }
@@ -0,0 +1,29 @@
+use super::continued::continued_text_record;
+
+continued_text_record! {
+    /// Represents an EXPDTA record from the title section of a PDB file.
+    ///
+    /// The experimental technique(s) used to determine the structure, separated by semicolons
+    /// when more than one was used.
+    ///
+    /// # Fields
+    ///
+    /// - `continuation`: Continuation number, absent on the first line
+    /// - `text`: Semicolon-separated experimental techniques on this line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use knuckles_parse::records::expdta::ExpdtaRecord;
+    ///
+    /// let record = ExpdtaRecord::try_from("EXPDTA   2 ELECTRON MICROSCOPY").unwrap();
+    /// assert_eq!(record.continuation, Some(2));
+    /// assert_eq!(record.text, "ELECTRON MICROSCOPY");
+    /// ```
+    ExpdtaRecord {
+        record: "EXPDTA",
+        continuation: 8..10,
+        /// Semicolon-separated experimental techniques on this line
+        text,
+    }
+}
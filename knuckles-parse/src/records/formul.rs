@@ -0,0 +1,121 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a FORMUL record, giving the chemical formula of a hetero-compound.
+///
+/// # Fields
+///
+/// - `comp_num`: Component number
+/// - `het_id`: Hetero-compound identifier
+/// - `continuation`: Continuation number, absent on the first line
+/// - `water`: Whether the component is water, marked with an asterisk
+/// - `text`: Chemical formula on this line, prefixed by the number of occurrences
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::formul::FormulRecord;
+///
+/// let formul = FormulRecord::try_from("FORMUL   3  HOH   *150(H2 O)").unwrap();
+///
+/// assert_eq!(formul.het_id, "HOH");
+/// assert!(formul.water);
+/// assert_eq!(formul.text, "150(H2 O)");
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct FormulRecord {
+    /// Component number
+    pub comp_num: u32,
+    /// Hetero-compound identifier
+    pub het_id: String,
+    /// Continuation number, absent on the first line
+    pub continuation: Option<u32>,
+    /// Whether the component is water, marked with an asterisk
+    pub water: bool,
+    /// Chemical formula on this line, prefixed by the number of occurrences
+    pub text: String,
+}
+
+impl FormulRecord {
+    /// Create a new FormulRecord by parsing a FORMUL line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the component number is missing or a number is
+    /// malformed.
+    pub fn new(str: &str) -> Result<FormulRecord, ParseError> {
+        let columns = Columns::new("FORMUL", str);
+        Ok(FormulRecord {
+            comp_num: columns.parse(8..10)?,
+            het_id: columns.str(12..15)?.to_string(),
+            continuation: columns.opt_parse(16..18)?,
+            water: columns.opt_char(18) == Some('*'),
+            text: columns.rest(19),
+        })
+    }
+}
+
+impl TryFrom<&str> for FormulRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        FormulRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for FormulRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "FORMUL  {:>2}  {:>3} {:>2}{}{}",
+            self.comp_num,
+            self.het_id,
+            self.continuation
+                .map(|continuation| continuation.to_string())
+                .unwrap_or_default(),
+            if self.water { '*' } else { ' ' },
+            self.text
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formul_new() {
+        let record = FormulRecord::new("FORMUL   2  NAG    2(C8 H15 N O6)").unwrap();
+        assert_eq!(record.comp_num, 2);
+        assert_eq!(record.het_id, "NAG");
+        assert_eq!(record.continuation, None);
+        assert!(!record.water);
+        assert_eq!(record.text, "2(C8 H15 N O6)");
+
+        let record = FormulRecord::new("FORMUL   4  CYC  2 O2").unwrap();
+        assert_eq!(record.continuation, Some(2));
+        assert_eq!(record.text, "O2");
+    }
+
+    #[test]
+    fn test_formul_display() {
+        for line in [
+            "FORMUL   3  HOH   *150(H2 O)",
+            "FORMUL   4  SO4    3(O4 S 2-)",
+        ] {
+            let record = FormulRecord::new(line).unwrap();
+            assert_eq!(record.to_string(), format!("{:<80}", line));
+        }
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents the HEADER record, the first line of a PDB file.
+///
+/// The HEADER record uniquely identifies the entry and gives its classification and
+/// deposition date.
+///
+/// # Fields
+///
+/// - `classification`: Classification of the molecule(s)
+/// - `dep_date`: Deposition date in `DD-MMM-YY` format
+/// - `id_code`: Four-character PDB identifier
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::header::HeaderRecord;
+///
+/// let line = "HEADER    PHOTOSYNTHESIS                          28-MAR-07   2UXK";
+/// let header = HeaderRecord::try_from(line).unwrap();
+///
+/// assert_eq!(header.classification, "PHOTOSYNTHESIS");
+/// assert_eq!(header.dep_date, "28-MAR-07");
+/// assert_eq!(header.id_code, "2UXK");
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct HeaderRecord {
+    /// Classification of the molecule(s)
+    pub classification: String,
+    /// Deposition date in `DD-MMM-YY` format
+    pub dep_date: String,
+    /// Four-character PDB identifier
+    pub id_code: String,
+}
+
+impl HeaderRecord {
+    /// Create a new HeaderRecord by parsing a HEADER line.
+    ///
+    /// Every field is optional, since files produced by modelling programs often leave
+    /// the date and identifier blank.
+    pub fn new(str: &str) -> Result<HeaderRecord, ParseError> {
+        let columns = Columns::new("HEADER", str);
+        Ok(HeaderRecord {
+            classification: columns.trailing_str(10..50).unwrap_or_default(),
+            dep_date: columns.trailing_str(50..59).unwrap_or_default(),
+            id_code: columns.trailing_str(62..66).unwrap_or_default(),
+        })
+    }
+}
+
+impl TryFrom<&str> for HeaderRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        HeaderRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for HeaderRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "HEADER    {:<40}{:<9}   {}",
+            self.classification, self.dep_date, self.id_code
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_new() {
+        let line = "HEADER    PHOTOSYNTHESIS                          28-MAR-07   2UXK";
+        let record = HeaderRecord::new(line).unwrap();
+        assert_eq!(record.classification, "PHOTOSYNTHESIS");
+        assert_eq!(record.dep_date, "28-MAR-07");
+        assert_eq!(record.id_code, "2UXK");
+
+        let record = HeaderRecord::new("HEADER    DE NOVO PROTEIN").unwrap();
+        assert_eq!(record.classification, "DE NOVO PROTEIN");
+        assert_eq!(record.id_code, "");
+    }
+
+    #[test]
+    fn test_header_display() {
+        let line = "HEADER    PHOTOSYNTHESIS                          28-MAR-07   2UXK";
+        let record = HeaderRecord::new(line).unwrap();
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a HELIX record, giving the location and type of a helix.
+///
+/// # Fields
+///
+/// - `serial`: Serial number of the helix, starting at 1
+/// - `helix_id`: Helix identifier
+/// - `init_res_name` / `end_res_name`: Names of the first and last residues
+/// - `init_chain_id` / `end_chain_id`: Chain identifiers of the first and last residues
+/// - `init_seq_num` / `end_seq_num`: Sequence numbers of the first and last residues
+/// - `init_i_code` / `end_i_code`: Insertion codes of the first and last residues
+/// - `helix_class`: Helix class, from 1 (right-handed alpha) to 10 (polyproline)
+/// - `comment`: Comment about the helix
+/// - `length`: Number of residues in the helix
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::helix::HelixRecord;
+///
+/// let line = "HELIX    1  HA GLY A   86  GLY A   94  1                                   9";
+/// let helix = HelixRecord::try_from(line).unwrap();
+///
+/// assert_eq!(helix.helix_id, "HA");
+/// assert_eq!((helix.init_seq_num, helix.end_seq_num), (86, 94));
+/// assert_eq!(helix.length, Some(9));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct HelixRecord {
+    /// Serial number of the helix, starting at 1
+    pub serial: u32,
+    /// Helix identifier
+    pub helix_id: String,
+    /// Name of the first residue
+    pub init_res_name: String,
+    /// Chain identifier of the first residue
+    pub init_chain_id: char,
+    /// Sequence number of the first residue
+    pub init_seq_num: i32,
+    /// Insertion code of the first residue
+    pub init_i_code: Option<char>,
+    /// Name of the last residue
+    pub end_res_name: String,
+    /// Chain identifier of the last residue
+    pub end_chain_id: char,
+    /// Sequence number of the last residue
+    pub end_seq_num: i32,
+    /// Insertion code of the last residue
+    pub end_i_code: Option<char>,
+    /// Helix class, from 1 (right-handed alpha) to 10 (polyproline)
+    pub helix_class: Option<u8>,
+    /// Comment about the helix
+    pub comment: Option<String>,
+    /// Number of residues in the helix
+    pub length: Option<u32>,
+}
+
+impl HelixRecord {
+    /// Create a new HelixRecord by parsing a HELIX line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the line ends before the last residue or a number is
+    /// missing or malformed.
+    pub fn new(str: &str) -> Result<HelixRecord, ParseError> {
+        let columns = Columns::new("HELIX", str);
+        Ok(HelixRecord {
+            serial: columns.parse(7..10)?,
+            helix_id: columns.str(11..14)?.to_string(),
+            init_res_name: columns.str(15..18)?.to_string(),
+            init_chain_id: columns.char(19)?,
+            init_seq_num: columns.parse(21..25)?,
+            init_i_code: columns.opt_char(25),
+            end_res_name: columns.str(27..30)?.to_string(),
+            end_chain_id: columns.char(31)?,
+            end_seq_num: columns.parse(33..37)?,
+            end_i_code: columns.opt_char(37),
+            helix_class: columns.opt_parse(38..40)?,
+            comment: columns.trailing_str(40..70),
+            length: columns.opt_parse(71..76)?,
+        })
+    }
+}
+
+impl TryFrom<&str> for HelixRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        HelixRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for HelixRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "HELIX  {:>3} {:>3} {:>3} {} {:>4}{} {:>3} {} {:>4}{}{:>2}{:<30} {:>5}",
+            self.serial,
+            self.helix_id,
+            self.init_res_name,
+            self.init_chain_id,
+            self.init_seq_num,
+            self.init_i_code.unwrap_or(' '),
+            self.end_res_name,
+            self.end_chain_id,
+            self.end_seq_num,
+            self.end_i_code.unwrap_or(' '),
+            self.helix_class
+                .map(|class| class.to_string())
+                .unwrap_or_default(),
+            self.comment.as_deref().unwrap_or_default(),
+            self.length
+                .map(|length| length.to_string())
+                .unwrap_or_default(),
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_helix_new() {
+        let line = "HELIX    2   2 SER B  105A LEU B  110  5RIGHT-HANDED 3-10                  6";
+        let record = HelixRecord::new(line).unwrap();
+        assert_eq!(record.serial, 2);
+        assert_eq!(record.helix_id, "2");
+        assert_eq!(record.init_res_name, "SER");
+        assert_eq!(record.init_chain_id, 'B');
+        assert_eq!(record.init_seq_num, 105);
+        assert_eq!(record.init_i_code, Some('A'));
+        assert_eq!(record.end_res_name, "LEU");
+        assert_eq!(record.end_seq_num, 110);
+        assert_eq!(record.end_i_code, None);
+        assert_eq!(record.helix_class, Some(5));
+        assert_eq!(record.comment.as_deref(), Some("RIGHT-HANDED 3-10"));
+        assert_eq!(record.length, Some(6));
+
+        let error = HelixRecord::new("HELIX    1  HA GLY A   86  GLY A").unwrap_err();
+        assert_eq!(error.columns, 33..37);
+    }
+
+    #[test]
+    fn test_helix_display() {
+        for line in [
+            "HELIX    1  HA GLY A   86  GLY A   94  1                                   9",
+            "HELIX    2   2 SER B  105A LEU B  110  5RIGHT-HANDED 3-10                  6",
+        ] {
+            let record = HelixRecord::new(line).unwrap();
+            assert_eq!(record.to_string(), format!("{:<80}", line));
+        }
+    }
+}
@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError};
+
 #[cfg(feature = "python")]
 use knuckles_macro::pydefault;
 
@@ -21,27 +23,43 @@ pub struct HetRecord {
 }
 
 impl HetRecord {
-    pub fn new(line: &str) -> Self {
-        HetRecord {
-            het_id: line[7..10].trim().to_string(),
-            chain_id: line.chars().nth(12).unwrap(),
-            seq_num: line[13..17].trim().parse().unwrap(),
-            i_code: line[17..18].trim().parse().ok(),
-            num_het_atoms: line[21..26].trim().parse().unwrap(),
-            text: line
-                .get(31..71)
-                .map(|str| str.trim().to_string())
-                .filter(|item| !item.is_empty()),
-        }
+    pub fn new(line: &str) -> Result<Self, ParseError> {
+        let columns = Columns::new("HET", line);
+        Ok(HetRecord {
+            het_id: columns.str(7..10)?.to_string(),
+            chain_id: columns.char(12)?,
+            seq_num: columns.parse(13..17)?,
+            i_code: columns.opt_char(17),
+            num_het_atoms: columns.parse(21..26)?,
+            // Columns 31-70 in the one-based numbering of the format description.
+            text: columns.opt_str(30..70),
+        })
     }
 }
 
-impl From<&str> for HetRecord {
-    fn from(value: &str) -> Self {
+impl TryFrom<&str> for HetRecord {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         HetRecord::new(value)
     }
 }
 
+impl std::fmt::Display for HetRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "HET    {:>3}  {}{:>4}{}  {:>5}     {}",
+            self.het_id,
+            self.chain_id,
+            self.seq_num,
+            self.i_code.unwrap_or(' '),
+            self.num_het_atoms,
+            self.text.as_deref().unwrap_or_default(),
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,7 +67,7 @@ mod tests {
     #[test]
     fn test_new_het_record() {
         let line = "HET    UDP  A1457      25                     ";
-        let record = HetRecord::new(line);
+        let record = HetRecord::new(line).unwrap();
         assert_eq!(record.het_id, "UDP");
         assert_eq!(record.chain_id, 'A');
         assert_eq!(record.seq_num, 1457);
@@ -58,19 +76,24 @@ mod tests {
         assert_eq!(record.text, None);
 
         let line = "HET    UNK  A 161       1     ";
-        let record = HetRecord::new(line);
+        let record = HetRecord::new(line).unwrap();
         assert_eq!(record.het_id, "UNK");
         assert_eq!(record.chain_id, 'A');
         assert_eq!(record.seq_num, 161);
         assert_eq!(record.i_code, None);
         assert_eq!(record.num_het_atoms, 1);
         assert_eq!(record.text, None);
+
+        // The text starts right at column 31, so its first character must be kept.
+        let line = format!("{:<80}", "HET    NAG  B 501      14     N-ACETYL GROUP");
+        let record = HetRecord::new(&line).unwrap();
+        assert_eq!(record.text, Some("N-ACETYL GROUP".to_string()));
     }
 
     #[test]
-    fn test_from_str() {
+    fn test_try_from_str() {
         let line = "HET    UDP  A1457      25                     ";
-        let record: HetRecord = line.into();
+        let record: HetRecord = line.try_into().unwrap();
         assert_eq!(record.het_id, "UDP");
         assert_eq!(record.chain_id, 'A');
         assert_eq!(record.seq_num, 1457);
@@ -78,4 +101,16 @@ mod tests {
         assert_eq!(record.num_het_atoms, 25);
         assert_eq!(record.text, None);
     }
+    #[test]
+    fn test_het_display() {
+        let line =
+            "HET    UDP  A1457      25                                                       ";
+        let record = HetRecord::new(line).unwrap();
+        assert_eq!(record.to_string(), line);
+        let line =
+            "HET    NAG  B 501      14     N-ACETYL GROUP                                    ";
+        let record = HetRecord::new(line).unwrap();
+        assert_eq!(record.text, Some("N-ACETYL GROUP".to_string()));
+        assert_eq!(record.to_string(), line);
+    }
 }
@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError};
+
 #[cfg(feature = "python")]
 use knuckles_macro::pydefault;
 
@@ -18,26 +20,38 @@ pub struct HetnamRecord {
 }
 
 impl HetnamRecord {
-    pub fn new(line: &str) -> Self {
-        HetnamRecord {
-            continuation: line
-                .get(8..10)
-                .map(|str| str.trim().to_string())
-                .filter(|item| !item.is_empty()),
-            het_id: line[11..15].trim().to_string(),
-            text: line[15..].trim().to_string(), // TODO: This should only parse up to 70 chars but
-                                                 // it may fail if the line is less than 70 chars
-                                                 // using 15..70
-        }
+    pub fn new(line: &str) -> Result<Self, ParseError> {
+        let columns = Columns::new("HETNAM", line);
+        Ok(HetnamRecord {
+            continuation: columns.opt_str(8..10),
+            het_id: columns.str(11..15)?.to_string(),
+            text: columns.rest(15), // TODO: This should only parse up to 70 chars but
+                                    // it may fail if the line is less than 70 chars
+                                    // using 15..70
+        })
     }
 }
 
-impl From<&str> for HetnamRecord {
-    fn from(value: &str) -> Self {
+impl TryFrom<&str> for HetnamRecord {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         HetnamRecord::new(value)
     }
 }
 
+impl std::fmt::Display for HetnamRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "HETNAM  {:>2} {:>3} {}",
+            self.continuation.as_deref().unwrap_or_default(),
+            self.het_id,
+            self.text
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,29 +59,35 @@ mod tests {
     #[test]
     fn test_hetnam_new() {
         let line = "HETNAM     NAG N-ACETYL-D-GLUCOSAMINE";
-        let record = HetnamRecord::new(line);
+        let record = HetnamRecord::new(line).unwrap();
         assert_eq!(record.continuation, None);
         assert_eq!(record.het_id, "NAG");
         assert_eq!(record.text, "N-ACETYL-D-GLUCOSAMINE");
 
         let line = "HETNAM  2  SAD DINUCLEOTIDE";
-        let record = HetnamRecord::new(line);
+        let record = HetnamRecord::new(line).unwrap();
         assert_eq!(record.continuation, Some("2".to_string()));
         assert_eq!(record.het_id, "SAD");
         assert_eq!(record.text, "DINUCLEOTIDE");
     }
 
     #[test]
-    fn test_hetnam_from() {
+    fn test_hetnam_try_from() {
         let line = "HETNAM     NAG N-ACETYL-D-GLUCOSAMINE";
-        let record = HetnamRecord::from(line);
+        let record = HetnamRecord::try_from(line).unwrap();
         assert_eq!(record.continuation, None);
         assert_eq!(record.het_id, "NAG");
         assert_eq!(record.text, "N-ACETYL-D-GLUCOSAMINE");
         let line = "HETNAM  2  SAD DINUCLEOTIDE";
-        let record = HetnamRecord::from(line);
+        let record = HetnamRecord::try_from(line).unwrap();
         assert_eq!(record.continuation, Some("2".to_string()));
         assert_eq!(record.het_id, "SAD");
         assert_eq!(record.text, "DINUCLEOTIDE");
     }
+    #[test]
+    fn test_hetnam_display() {
+        let line = "HETNAM   2 SAD DINUCLEOTIDE";
+        let record = HetnamRecord::new(line).unwrap();
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
 }
@@ -0,0 +1,101 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a HETSYN record, listing synonyms for a hetero-compound.
+///
+/// # Fields
+///
+/// - `continuation`: Continuation number, absent on the first line
+/// - `het_id`: Hetero-compound identifier
+/// - `text`: Semicolon-separated synonyms on this line
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::hetsyn::HetsynRecord;
+///
+/// let line = "HETSYN     HV5 3-METHYL-L-VALINE";
+/// let hetsyn = HetsynRecord::try_from(line).unwrap();
+///
+/// assert_eq!(hetsyn.het_id, "HV5");
+/// assert_eq!(hetsyn.text, "3-METHYL-L-VALINE");
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct HetsynRecord {
+    /// Continuation number, absent on the first line
+    pub continuation: Option<String>,
+    /// Hetero-compound identifier
+    pub het_id: String,
+    /// Semicolon-separated synonyms on this line
+    pub text: String,
+}
+
+impl HetsynRecord {
+    /// Create a new HetsynRecord by parsing a HETSYN line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the line ends before the hetero-compound identifier.
+    pub fn new(line: &str) -> Result<Self, ParseError> {
+        let columns = Columns::new("HETSYN", line);
+        Ok(HetsynRecord {
+            continuation: columns.opt_str(8..10),
+            het_id: columns.str(11..14)?.to_string(),
+            text: columns.rest(15),
+        })
+    }
+}
+
+impl TryFrom<&str> for HetsynRecord {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        HetsynRecord::new(value)
+    }
+}
+
+impl std::fmt::Display for HetsynRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "HETSYN  {:>2} {:>3} {}",
+            self.continuation.as_deref().unwrap_or_default(),
+            self.het_id,
+            self.text
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hetsyn_new() {
+        let line = "HETSYN   2 NAG N-ACETYL-D-GLUCOSAMINE";
+        let record = HetsynRecord::new(line).unwrap();
+        assert_eq!(record.continuation.as_deref(), Some("2"));
+        assert_eq!(record.het_id, "NAG");
+        assert_eq!(record.text, "N-ACETYL-D-GLUCOSAMINE");
+
+        assert!(HetsynRecord::new("HETSYN     N").is_err());
+    }
+
+    #[test]
+    fn test_hetsyn_display() {
+        let line = "HETSYN     NAG N-ACETYL-BETA-D-GLUCOSAMINE; N-ACETYL-D-GLUCOSE";
+        let record = HetsynRecord::new(line).unwrap();
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
+}
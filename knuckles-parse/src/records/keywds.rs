@@ -0,0 +1,28 @@
+use super::continued::continued_text_record;
+
+continued_text_record! {
+    /// Represents a KEYWDS record from the title section of a PDB file.
+    ///
+    /// A comma-separated list of keywords describing the entry.
+    ///
+    /// # Fields
+    ///
+    /// - `continuation`: Continuation number, absent on the first line
+    /// - `text`: Comma-separated keywords on this line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use knuckles_parse::records::keywds::KeywdsRecord;
+    ///
+    /// let record = KeywdsRecord::try_from("KEYWDS   2 METABOLISM").unwrap();
+    /// assert_eq!(record.continuation, Some(2));
+    /// assert_eq!(record.text, "METABOLISM");
+    /// ```
+    KeywdsRecord {
+        record: "KEYWDS",
+        continuation: 8..10,
+        /// Comma-separated keywords on this line
+        text,
+    }
+}
@@ -0,0 +1,182 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+use crate::records::atom::format_atom_name;
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a LINK record, a connection between residues that is not implied by the
+/// primary structure, such as a covalently bound ligand or a metal coordination.
+///
+/// # Fields
+///
+/// - `name1` / `name2`: Names of the two linked atoms
+/// - `alt_loc1` / `alt_loc2`: Alternate location indicators of the two atoms
+/// - `res_name1` / `res_name2`: Residue names of the two atoms
+/// - `chain_id1` / `chain_id2`: Chain identifiers of the two atoms
+/// - `res_seq1` / `res_seq2`: Residue sequence numbers of the two atoms
+/// - `i_code1` / `i_code2`: Insertion codes of the two atoms
+/// - `sym1` / `sym2`: Symmetry operators applied to each atom (e.g., "1555")
+/// - `length`: Link distance in Ångströms
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::link::LinkRecord;
+///
+/// let line = "LINK         SG  CYS A 145                 C18 LIG A 401     1555   1555  1.81";
+/// let link = LinkRecord::try_from(line).unwrap();
+///
+/// assert_eq!(link.name1, "SG");
+/// assert_eq!(link.res_name2, "LIG");
+/// assert_eq!(link.length, Some(1.81));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct LinkRecord {
+    /// Name of the first atom
+    pub name1: String,
+    /// Alternate location indicator of the first atom
+    pub alt_loc1: Option<char>,
+    /// Residue name of the first atom
+    pub res_name1: String,
+    /// Chain identifier of the first atom
+    pub chain_id1: char,
+    /// Residue sequence number of the first atom
+    pub res_seq1: i32,
+    /// Insertion code of the first atom
+    pub i_code1: Option<char>,
+    /// Name of the second atom
+    pub name2: String,
+    /// Alternate location indicator of the second atom
+    pub alt_loc2: Option<char>,
+    /// Residue name of the second atom
+    pub res_name2: String,
+    /// Chain identifier of the second atom
+    pub chain_id2: char,
+    /// Residue sequence number of the second atom
+    pub res_seq2: i32,
+    /// Insertion code of the second atom
+    pub i_code2: Option<char>,
+    /// Symmetry operator applied to the first atom
+    pub sym1: Option<String>,
+    /// Symmetry operator applied to the second atom
+    pub sym2: Option<String>,
+    /// Link distance in Ångströms
+    pub length: Option<f32>,
+}
+
+impl LinkRecord {
+    /// Create a new LinkRecord by parsing a LINK line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the line ends before the second atom or a number is
+    /// missing or malformed.
+    pub fn new(str: &str) -> Result<LinkRecord, ParseError> {
+        let columns = Columns::new("LINK", str);
+        Ok(LinkRecord {
+            name1: columns.str(12..16)?.to_string(),
+            alt_loc1: columns.opt_char(16),
+            res_name1: columns.str(17..20)?.to_string(),
+            chain_id1: columns.char(21)?,
+            res_seq1: columns.parse(22..26)?,
+            i_code1: columns.opt_char(26),
+            name2: columns.str(42..46)?.to_string(),
+            alt_loc2: columns.opt_char(46),
+            res_name2: columns.str(47..50)?.to_string(),
+            chain_id2: columns.char(51)?,
+            res_seq2: columns.parse(52..56)?,
+            i_code2: columns.opt_char(56),
+            sym1: columns.opt_str(59..65),
+            sym2: columns.opt_str(66..72),
+            length: columns.opt_parse(73..78)?,
+        })
+    }
+}
+
+impl TryFrom<&str> for LinkRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        LinkRecord::new(str)
+    }
+}
+
+/// Align a linked atom name; single-atom ions, whose name matches the residue name, are
+/// treated as having a two-letter element.
+fn format_link_name(name: &str, res_name: &str) -> String {
+    format_atom_name(name, (name == res_name).then_some(name))
+}
+
+impl std::fmt::Display for LinkRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "LINK        {}{}{:>3} {}{:>4}{}{:>19}{}{:>3} {}{:>4}{}  {:>6} {:>6} {}",
+            format_link_name(&self.name1, &self.res_name1),
+            self.alt_loc1.unwrap_or(' '),
+            self.res_name1,
+            self.chain_id1,
+            self.res_seq1,
+            self.i_code1.unwrap_or(' '),
+            format_link_name(&self.name2, &self.res_name2),
+            self.alt_loc2.unwrap_or(' '),
+            self.res_name2,
+            self.chain_id2,
+            self.res_seq2,
+            self.i_code2.unwrap_or(' '),
+            self.sym1.as_deref().unwrap_or_default(),
+            self.sym2.as_deref().unwrap_or_default(),
+            self.length
+                .map(|length| format!("{:>5.2}", length))
+                .unwrap_or_default(),
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_new() {
+        let line = "LINK         O   GLY A  49                NA    NA A6001     1555   1555  2.98";
+        let record = LinkRecord::new(line).unwrap();
+        assert_eq!(record.name1, "O");
+        assert_eq!(record.alt_loc1, None);
+        assert_eq!(record.res_name1, "GLY");
+        assert_eq!(record.chain_id1, 'A');
+        assert_eq!(record.res_seq1, 49);
+        assert_eq!(record.name2, "NA");
+        assert_eq!(record.res_name2, "NA");
+        assert_eq!(record.res_seq2, 6001);
+        assert_eq!(record.sym1.as_deref(), Some("1555"));
+        assert_eq!(record.length, Some(2.98));
+
+        let line = "LINK         C  BALA B 100A                N   SER B 101";
+        let record = LinkRecord::new(line).unwrap();
+        assert_eq!(record.alt_loc1, Some('B'));
+        assert_eq!(record.i_code1, Some('A'));
+        assert_eq!(record.sym2, None);
+        assert_eq!(record.length, None);
+    }
+
+    #[test]
+    fn test_link_display() {
+        for line in [
+            "LINK         O   GLY A  49                NA    NA A6001     1555   1555  2.98",
+            "LINK         C  BALA B 100A                N   SER B 101",
+        ] {
+            let record = LinkRecord::new(line).unwrap();
+            assert_eq!(record.to_string(), format!("{:<80}", line));
+        }
+    }
+}
@@ -0,0 +1,194 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+use crate::records::Record;
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a MASTER record, the bookkeeping line counting the records of selected types
+/// in the file.
+///
+/// # Fields
+///
+/// - `num_remark`: Number of REMARK records
+/// - `num_het`: Number of HET records
+/// - `num_helix`: Number of HELIX records
+/// - `num_sheet`: Number of SHEET records
+/// - `num_turn`: Number of TURN records, a deprecated record type
+/// - `num_site`: Number of SITE records
+/// - `num_xform`: Number of coordinate transformation records (ORIGX, SCALE and MTRIX)
+/// - `num_coord`: Number of atomic coordinate records (ATOM and HETATM)
+/// - `num_ter`: Number of TER records
+/// - `num_conect`: Number of CONECT records
+/// - `num_seq`: Number of SEQRES records
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::master::MasterRecord;
+///
+/// let line = "MASTER      300    0    1    5    4    0    2    6 1253    2    8   12";
+/// let master = MasterRecord::try_from(line).unwrap();
+///
+/// assert_eq!(master.num_remark, 300);
+/// assert_eq!(master.num_coord, 1253);
+/// assert_eq!(master.num_seq, 12);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct MasterRecord {
+    /// Number of REMARK records
+    pub num_remark: u32,
+    /// Number of HET records
+    pub num_het: u32,
+    /// Number of HELIX records
+    pub num_helix: u32,
+    /// Number of SHEET records
+    pub num_sheet: u32,
+    /// Number of TURN records, a deprecated record type
+    pub num_turn: u32,
+    /// Number of SITE records
+    pub num_site: u32,
+    /// Number of coordinate transformation records (ORIGX, SCALE and MTRIX)
+    pub num_xform: u32,
+    /// Number of atomic coordinate records (ATOM and HETATM)
+    pub num_coord: u32,
+    /// Number of TER records
+    pub num_ter: u32,
+    /// Number of CONECT records
+    pub num_conect: u32,
+    /// Number of SEQRES records
+    pub num_seq: u32,
+}
+
+impl MasterRecord {
+    /// Create a new MasterRecord by parsing a MASTER line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if a count is missing or malformed.
+    pub fn new(str: &str) -> Result<MasterRecord, ParseError> {
+        let columns = Columns::new("MASTER", str);
+        Ok(MasterRecord {
+            num_remark: columns.parse(10..15)?,
+            num_het: columns.parse(20..25)?,
+            num_helix: columns.parse(25..30)?,
+            num_sheet: columns.parse(30..35)?,
+            num_turn: columns.parse(35..40)?,
+            num_site: columns.parse(40..45)?,
+            num_xform: columns.parse(45..50)?,
+            num_coord: columns.parse(50..55)?,
+            num_ter: columns.parse(55..60)?,
+            num_conect: columns.parse(60..65)?,
+            num_seq: columns.parse(65..70)?,
+        })
+    }
+
+    /// Count the records of each type MASTER keeps track of, giving the MASTER record
+    /// that describes `records`.
+    ///
+    /// Coordinate and TER records are counted across all models.
+    pub fn from_records(records: &[Record]) -> MasterRecord {
+        let mut master = MasterRecord::default();
+        for record in records {
+            let count = match record {
+                Record::Remark(_) => &mut master.num_remark,
+                Record::Het(_) => &mut master.num_het,
+                Record::Helix(_) => &mut master.num_helix,
+                Record::Sheet(_) => &mut master.num_sheet,
+                Record::Site(_) => &mut master.num_site,
+                Record::OrigxN(_) | Record::ScaleN(_) | Record::MtrixN(_) => &mut master.num_xform,
+                Record::Atom(_) | Record::Hetatm(_) => &mut master.num_coord,
+                Record::Term(_) => &mut master.num_ter,
+                Record::Connect(_) => &mut master.num_conect,
+                Record::Seqres(_) => &mut master.num_seq,
+                _ => continue,
+            };
+            *count += 1;
+        }
+        master
+    }
+
+    /// The counts paired with the name of the record type they count, in column order.
+    pub(crate) fn counts(&self) -> [(&'static str, u32); 11] {
+        [
+            ("REMARK", self.num_remark),
+            ("HET", self.num_het),
+            ("HELIX", self.num_helix),
+            ("SHEET", self.num_sheet),
+            ("TURN", self.num_turn),
+            ("SITE", self.num_site),
+            ("ORIGX/SCALE/MTRIX", self.num_xform),
+            ("ATOM/HETATM", self.num_coord),
+            ("TER", self.num_ter),
+            ("CONECT", self.num_conect),
+            ("SEQRES", self.num_seq),
+        ]
+    }
+}
+
+impl TryFrom<&str> for MasterRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        MasterRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for MasterRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "MASTER    {:>5}    0{:>5}{:>5}{:>5}{:>5}{:>5}{:>5}{:>5}{:>5}{:>5}{:>5}",
+            self.num_remark,
+            self.num_het,
+            self.num_helix,
+            self.num_sheet,
+            self.num_turn,
+            self.num_site,
+            self.num_xform,
+            self.num_coord,
+            self.num_ter,
+            self.num_conect,
+            self.num_seq
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_master_new() {
+        let line = "MASTER      300    0    1    5    4    0    2    6 1253    2    8   12";
+        let record = MasterRecord::new(line).unwrap();
+        assert_eq!(record.num_remark, 300);
+        assert_eq!(record.num_het, 1);
+        assert_eq!(record.num_helix, 5);
+        assert_eq!(record.num_sheet, 4);
+        assert_eq!(record.num_site, 2);
+        assert_eq!(record.num_xform, 6);
+        assert_eq!(record.num_coord, 1253);
+        assert_eq!(record.num_ter, 2);
+        assert_eq!(record.num_conect, 8);
+        assert_eq!(record.num_seq, 12);
+
+        let error = MasterRecord::new("MASTER      300    0    1    5").unwrap_err();
+        assert_eq!(error.columns, 30..35);
+    }
+
+    #[test]
+    fn test_master_display() {
+        let line = "MASTER      300    0    1    5    4    0    2    6 1253    2    8   12";
+        let record = MasterRecord::new(line).unwrap();
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
+}
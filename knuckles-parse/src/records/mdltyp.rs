@@ -0,0 +1,29 @@
+use super::continued::continued_text_record;
+
+continued_text_record! {
+    /// Represents an MDLTYP record from the title section of a PDB file.
+    ///
+    /// Additional annotation about the coordinates, such as `CA ATOMS ONLY`, separated by
+    /// semicolons.
+    ///
+    /// # Fields
+    ///
+    /// - `continuation`: Continuation number, absent on the first line
+    /// - `text`: Semicolon-separated model annotations on this line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use knuckles_parse::records::mdltyp::MdltypRecord;
+    ///
+    /// let record = MdltypRecord::try_from("MDLTYP   2 CHAIN X, Y, Z").unwrap();
+    /// assert_eq!(record.continuation, Some(2));
+    /// assert_eq!(record.text, "CHAIN X, Y, Z");
+    /// ```
+    MdltypRecord {
+        record: "MDLTYP",
+        continuation: 8..10,
+        /// Semicolon-separated model annotations on this line
+        text,
+    }
+}
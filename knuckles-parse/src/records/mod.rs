@@ -7,16 +7,43 @@
 pub mod anisotropic;
 /// Atom coordinate records (ATOM/HETATM)
 pub mod atom;
+/// Author list records (AUTHOR)
+pub mod author;
+/// Entry error warning records (CAVEAT)
+pub mod caveat;
+/// Cis peptide records (CISPEP)
+pub mod cispep;
+/// Macromolecule description records (COMPND)
+pub mod compnd;
 /// Connectivity records (CONECT)
 pub mod connect;
+mod continued;
 /// Crystal structure parameters (CRYST1)
 pub mod crystal;
 /// Database reference records (DBREF)
 pub mod dbref;
+/// Experimental technique records (EXPDTA)
+pub mod expdta;
+/// Chemical formula records (FORMUL)
+pub mod formul;
+/// Entry header records (HEADER)
+pub mod header;
+/// Helix records (HELIX)
+pub mod helix;
 /// Hetero-compound records (HET)
 pub mod het;
 /// Hetero-compound name records (HETNAM)
 pub mod hetnam;
+/// Hetero-compound synonym records (HETSYN)
+pub mod hetsyn;
+/// Keyword records (KEYWDS)
+pub mod keywds;
+/// Inter-residue link records (LINK)
+pub mod link;
+/// Record count bookkeeping records (MASTER)
+pub mod master;
+/// Model type annotation records (MDLTYP)
+pub mod mdltyp;
 /// Model records (MODEL)
 pub mod model;
 /// Modified residue records (MODRES)
@@ -25,20 +52,44 @@ pub mod modres;
 pub mod mtrixn;
 /// Number of models records (NUMMDL)
 pub mod nummdl;
+/// Obsolete entry records (OBSLTE)
+pub mod obslte;
 /// Original coordinate system transformation records (ORIGX1/2/3)
 pub mod origxn;
+/// Remark records (REMARK)
+pub mod remark;
+/// Revision history records (REVDAT)
+pub mod revdat;
 /// Scale matrix records (SCALE1/2/3)
 pub mod scalen;
 /// Sequence differences records (SEQADV)
 pub mod seqadv;
 /// Residue sequence records (SEQRES)
 pub mod seqres;
+/// Beta sheet strand records (SHEET)
+pub mod sheet;
+/// Site records (SITE)
+pub mod site;
+/// Biological source records (SOURCE)
+pub mod source;
+/// Split entry records (SPLIT)
+pub mod split;
+/// Superseded entry records (SPRSDE)
+pub mod sprsde;
+/// Disulfide bond records (SSBOND)
+pub mod ssbond;
 /// Chain termination records (TER)
 pub mod term;
+/// Entry title records (TITLE)
+pub mod title;
+/// Unrecognized records kept verbatim
+pub mod unknown;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{ParseError, ParseErrorKind};
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -51,22 +102,47 @@ use pyo3::prelude::*;
 ///
 /// - `Anisou` - Anisotropic temperature factor records
 /// - `Atom` - Standard atom coordinate records
+/// - `Author` - Author list
+/// - `Caveat` - Warnings about severe errors in the entry
+/// - `Cispep` - Cis peptides
+/// - `Compnd` - Macromolecule descriptions
 /// - `Connect` - Connectivity records showing bonds between atoms
 /// - `Crystal` - Crystallographic unit cell parameters
 /// - `DBRef` - Database reference records
+/// - `Expdta` - Experimental techniques
+/// - `Formul` - Chemical formulas of hetero-compounds
+/// - `Header` - Entry classification, deposition date and identifier
+/// - `Helix` - Helices
 /// - `Het` - Hetero-compound records
 /// - `Hetatm` - Hetero-atom coordinate records (uses same structure as `Atom`)
 /// - `Hetnam` - Hetero-compound name records
+/// - `Hetsyn` - Hetero-compound synonym records
+/// - `Keywds` - Keywords
+/// - `Link` - Links between residues not implied by the sequence
+/// - `Master` - Counts of selected record types, for checking completeness
+/// - `Mdltyp` - Model type annotations
 /// - `Nummdl` - Number of models in the file
 /// - `MtrixN` - Transformation matrix records (N = 1, 2, or 3)
 /// - `Model` - Model records for multi-model structures
 /// - `Modres` - Modified residue records
+/// - `Obslte` - Replacements of a withdrawn entry
 /// - `OrigxN` - Original coordinate system transformation (N = 1, 2, or 3)
+/// - `Remark` - Numbered annotations
+/// - `Revdat` - Revision history
 /// - `ScaleN` - Scale matrix records (N = 1, 2, or 3)
 /// - `Seqres` - Residue sequence records
 /// - `Seqadv` - Sequence differences from database
+/// - `Sheet` - Beta sheet strands
+/// - `Site` - Residues making up a site
+/// - `Source` - Biological sources of the macromolecules
+/// - `Split` - Entries making up a large structure
+/// - `Sprsde` - Entries superseded by this one
+/// - `Ssbond` - Disulfide bonds
 /// - `Term` - Chain termination records
+/// - `Title` - Entry title
+/// - `Unknown` - Records with an unrecognized name, kept verbatim
 /// - `Endmdl` - End of model marker (no associated data)
+/// - `End` - End of file marker (no associated data)
 ///
 /// # Example
 ///
@@ -90,18 +166,44 @@ pub enum Record {
     Anisou(anisotropic::AnisotropicRecord),
     /// Standard atom coordinate record
     Atom(atom::AtomRecord),
+    /// Author list record
+    Author(author::AuthorRecord),
+    /// Warning about severe errors in the entry
+    Caveat(caveat::CaveatRecord),
+    /// Cis peptide record
+    Cispep(cispep::CispepRecord),
+    /// Macromolecule description record
+    Compnd(compnd::CompndRecord),
     /// Connectivity record showing bonds between atoms
     Connect(connect::ConnectRecord),
     /// Crystallographic unit cell parameters
     Crystal(crystal::CrystalRecord),
     /// Database reference record
     DBRef(dbref::DBRefRecord),
+    /// Experimental technique record
+    Expdta(expdta::ExpdtaRecord),
+    /// Chemical formula record
+    Formul(formul::FormulRecord),
+    /// Entry header record
+    Header(header::HeaderRecord),
+    /// Helix record
+    Helix(helix::HelixRecord),
     /// Hetero-compound record
     Het(het::HetRecord),
     /// Hetero-atom coordinate record (same structure as Atom)
     Hetatm(atom::AtomRecord),
     /// Hetero-compound name record
     Hetnam(hetnam::HetnamRecord),
+    /// Hetero-compound synonym record
+    Hetsyn(hetsyn::HetsynRecord),
+    /// Keyword record
+    Keywds(keywds::KeywdsRecord),
+    /// Link between residues not implied by the sequence
+    Link(link::LinkRecord),
+    /// Record count bookkeeping record
+    Master(master::MasterRecord),
+    /// Model type annotation record
+    Mdltyp(mdltyp::MdltypRecord),
     /// Number of models record
     Nummdl(nummdl::NummdlRecord),
     /// Transformation matrix record (MTRIX1, MTRIX2, or MTRIX3)
@@ -110,18 +212,42 @@ pub enum Record {
     Model(model::ModelRecord),
     /// Modified residue record
     Modres(modres::ModresRecord),
+    /// Replacements of a withdrawn entry
+    Obslte(obslte::ObslteRecord),
     /// Original coordinate system transformation (ORIGX1, ORIGX2, or ORIGX3)
     OrigxN(origxn::OrigxN),
+    /// Numbered annotation record
+    Remark(remark::RemarkRecord),
+    /// Revision history record
+    Revdat(revdat::RevdatRecord),
     /// Scale matrix record (SCALE1, SCALE2, or SCALE3)
     ScaleN(scalen::ScaleN),
     /// Residue sequence record
     Seqres(seqres::SeqresRecord),
     /// Sequence differences from database
     Seqadv(seqadv::SeqAdvRecord),
+    /// Beta sheet strand record
+    Sheet(sheet::SheetRecord),
+    /// Residues making up a site
+    Site(site::SiteRecord),
+    /// Biological source record
+    Source(source::SourceRecord),
+    /// Entries making up a large structure
+    Split(split::SplitRecord),
+    /// Entries superseded by this one
+    Sprsde(sprsde::SprsdeRecord),
+    /// Disulfide bond record
+    Ssbond(ssbond::SsbondRecord),
     /// Chain termination record
     Term(term::TermRecord),
+    /// Entry title record
+    Title(title::TitleRecord),
+    /// Record with an unrecognized record name, kept verbatim
+    Unknown(unknown::UnknownRecord),
     /// End of model marker
     Endmdl(),
+    /// End of file marker
+    End(),
 }
 #[cfg(feature = "python")]
 macro_rules! debug_match {
@@ -129,6 +255,7 @@ macro_rules! debug_match {
         match $self {
             $(Self::$variant($value) => format!("{:?}", $value),)*
             Self::Endmdl() => "ENDMDL".to_string(),
+            Self::End() => "END".to_string(),
         }
     };
 }
@@ -137,57 +264,106 @@ macro_rules! debug_match {
 #[pymethods]
 impl Record {
     #[new]
-    fn python_new(str: &str) -> Self {
-        match Self::try_from(str) {
-            Ok(item) => item,
-            Err(_) => panic!("Unable to parse line"),
-        }
+    fn python_new(str: &str) -> PyResult<Self> {
+        Ok(Self::try_from(str)?)
     }
     #[getter]
     fn record(&self, py: Python) -> Py<PyAny> {
         match self {
             Self::Atom(atom) => atom.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Anisou(anisou) => anisou.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Author(author) => author.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Caveat(caveat) => caveat.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Cispep(cispep) => cispep.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Compnd(compnd) => compnd.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Connect(connect) => connect.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Crystal(crystal) => crystal.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::DBRef(dbref) => dbref.clone().into_pyobject(py).unwrap().into_any().into(),
-            Self::Endmdl() => py.None(),
+            Self::End() | Self::Endmdl() => py.None(),
+            Self::Expdta(expdta) => expdta.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Formul(formul) => formul.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Header(header) => header.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Helix(helix) => helix.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Hetatm(atom) => atom.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Het(het) => het.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Hetnam(hetnam) => hetnam.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Hetsyn(hetsyn) => hetsyn.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Keywds(keywds) => keywds.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Link(link) => link.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Master(master) => master.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Mdltyp(mdltyp) => mdltyp.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Nummdl(nummdl) => nummdl.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Model(model) => model.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Modres(modres) => modres.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::MtrixN(mtrix) => mtrix.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Obslte(obslte) => obslte.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::OrigxN(origxn) => origxn.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Remark(remark) => remark.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Revdat(revdat) => revdat.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::ScaleN(scalen) => scalen.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Seqadv(seqadv) => seqadv.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Seqres(seqres) => seqres.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Sheet(sheet) => sheet.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Site(site) => site.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Source(source) => source.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Split(split) => split.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Sprsde(sprsde) => sprsde.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Ssbond(ssbond) => ssbond.clone().into_pyobject(py).unwrap().into_any().into(),
             Self::Term(term) => term.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Title(title) => title.clone().into_pyobject(py).unwrap().into_any().into(),
+            Self::Unknown(unknown) => unknown.clone().into_pyobject(py).unwrap().into_any().into(),
         }
     }
 
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
     fn __repr__(&self) -> String {
         debug_match!(
             self,
             {
                 Anisou(anisou),
                 Atom(atom),
+                Author(author),
+                Caveat(caveat),
+                Cispep(cispep),
+                Compnd(compnd),
                 Connect(connect),
                 Crystal(crystal),
                 DBRef(dbref),
+                Expdta(expdta),
+                Formul(formul),
+                Header(header),
+                Helix(helix),
                 Het(het),
                 Hetatm(atom),
                 Hetnam(hetnam),
+                Hetsyn(hetsyn),
+                Keywds(keywds),
+                Link(link),
+                Master(master),
+                Mdltyp(mdltyp),
                 Model(model),
                 Modres(modres),
                 MtrixN(mtrix),
                 Nummdl(nummdl),
+                Obslte(obslte),
                 OrigxN(origxn),
+                Remark(remark),
+                Revdat(revdat),
                 ScaleN(scalen),
                 Seqadv(seqadv),
                 Seqres(seqres),
-                Term(term)
+                Sheet(sheet),
+                Site(site),
+                Source(source),
+                Split(split),
+                Sprsde(sprsde),
+                Ssbond(ssbond),
+                Term(term),
+                Title(title),
+                Unknown(unknown)
             }
 
         )
@@ -195,7 +371,7 @@ impl Record {
 }
 
 impl TryFrom<&str> for Record {
-    type Error = &'static str;
+    type Error = ParseError;
 
     /// Parse a PDB line into the appropriate Record variant.
     ///
@@ -209,15 +385,17 @@ impl TryFrom<&str> for Record {
     /// # Returns
     ///
     /// - `Ok(Record)` - Successfully parsed record
-    /// - `Err(&'static str)` - Error message if parsing failed
+    /// - `Err(ParseError)` - Where and why parsing failed
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The line is shorter than 6 characters
-    /// - The record type is not recognized
+    /// - The line is blank
     /// - The line format is invalid for the detected record type
     ///
+    /// Lines with a record name this crate does not recognize are not an error; they are
+    /// kept verbatim as [`Record::Unknown`].
+    ///
     /// # Example
     ///
     /// ```rust
@@ -227,65 +405,359 @@ impl TryFrom<&str> for Record {
     /// let record = Record::try_from(line).unwrap();
     /// ```
     fn try_from(line: &str) -> Result<Self, Self::Error> {
-        match &line.get(0..6) {
-            Some(item) => match *item {
-                "ANISOU" => Ok(Record::Anisou(anisotropic::AnisotropicRecord::from(line))),
-                "ATOM  " => Ok(Record::Atom(atom::AtomRecord::from(line))),
-                "CONECT" => Ok(Record::Connect(connect::ConnectRecord::from(line))),
-                "CRYST1" => Ok(Record::Crystal(crystal::CrystalRecord::from(line))),
-                "DBREF " => Ok(Record::DBRef(dbref::DBRefRecord::from(line))),
+        // Records such as a bare "TER" may be shorter than the six-character record name.
+        let padded;
+        let tag = match line.get(0..6) {
+            Some(tag) => Some(tag),
+            // No record name has multi-byte characters, so a line whose sixth column ends
+            // inside one is of an unknown type.
+            None if line.len() >= 6 => {
+                return Ok(Record::Unknown(unknown::UnknownRecord::try_from(line)?));
+            }
+            None if !line.trim().is_empty() => {
+                padded = format!("{:<6}", line);
+                Some(padded.as_str())
+            }
+            None => None,
+        };
+        match tag {
+            Some(item) => match item {
+                "ANISOU" => Ok(Record::Anisou(anisotropic::AnisotropicRecord::try_from(
+                    line,
+                )?)),
+                "ATOM  " => Ok(Record::Atom(atom::AtomRecord::try_from(line)?)),
+                "AUTHOR" => Ok(Record::Author(author::AuthorRecord::try_from(line)?)),
+                "CAVEAT" => Ok(Record::Caveat(caveat::CaveatRecord::try_from(line)?)),
+                "CISPEP" => Ok(Record::Cispep(cispep::CispepRecord::try_from(line)?)),
+                "COMPND" => Ok(Record::Compnd(compnd::CompndRecord::try_from(line)?)),
+                "CONECT" => Ok(Record::Connect(connect::ConnectRecord::try_from(line)?)),
+                "CRYST1" => Ok(Record::Crystal(crystal::CrystalRecord::try_from(line)?)),
+                "DBREF " => Ok(Record::DBRef(dbref::DBRefRecord::try_from(line)?)),
+                "END   " => Ok(Record::End()),
                 "ENDMDL" => Ok(Record::Endmdl()),
-                "HETATM" => Ok(Record::Hetatm(atom::AtomRecord::from(line))),
-                "HET   " => Ok(Record::Het(het::HetRecord::from(line))),
-                "HETNAM" => Ok(Record::Hetnam(hetnam::HetnamRecord::from(line))),
-                "MTRIX1" | "MTRIX2" | "MTRIX3" => Ok(Record::MtrixN(mtrixn::MtrixN::from(line))),
-                "MODEL " => Ok(Record::Model(model::ModelRecord::from(line))),
-                "MODRES" => Ok(Record::Modres(modres::ModresRecord::from(line))),
-                "NUMMDL" => Ok(Record::Nummdl(nummdl::NummdlRecord::from(line))),
-                "ORIGX1" | "ORIGX2" | "ORIGX3" => Ok(Record::OrigxN(origxn::OrigxN::from(line))),
-                "SCALE1" | "SCALE2" | "SCALE3" => Ok(Record::ScaleN(scalen::ScaleN::from(line))),
-                "SEQRES" => Ok(Record::Seqres(seqres::SeqresRecord::from(line))),
-                "TER   " => Ok(Record::Term(term::TermRecord::from(line))),
-                _ => Err("Unknown record type"),
+                "EXPDTA" => Ok(Record::Expdta(expdta::ExpdtaRecord::try_from(line)?)),
+                "FORMUL" => Ok(Record::Formul(formul::FormulRecord::try_from(line)?)),
+                "HEADER" => Ok(Record::Header(header::HeaderRecord::try_from(line)?)),
+                "HELIX " => Ok(Record::Helix(helix::HelixRecord::try_from(line)?)),
+                "HETATM" => Ok(Record::Hetatm(atom::AtomRecord::try_from(line)?)),
+                "HET   " => Ok(Record::Het(het::HetRecord::try_from(line)?)),
+                "HETNAM" => Ok(Record::Hetnam(hetnam::HetnamRecord::try_from(line)?)),
+                "HETSYN" => Ok(Record::Hetsyn(hetsyn::HetsynRecord::try_from(line)?)),
+                "KEYWDS" => Ok(Record::Keywds(keywds::KeywdsRecord::try_from(line)?)),
+                "LINK  " => Ok(Record::Link(link::LinkRecord::try_from(line)?)),
+                "MASTER" => Ok(Record::Master(master::MasterRecord::try_from(line)?)),
+                "MDLTYP" => Ok(Record::Mdltyp(mdltyp::MdltypRecord::try_from(line)?)),
+                "MTRIX1" | "MTRIX2" | "MTRIX3" => {
+                    Ok(Record::MtrixN(mtrixn::MtrixN::try_from(line)?))
+                }
+                "MODEL " => Ok(Record::Model(model::ModelRecord::try_from(line)?)),
+                "MODRES" => Ok(Record::Modres(modres::ModresRecord::try_from(line)?)),
+                "NUMMDL" => Ok(Record::Nummdl(nummdl::NummdlRecord::try_from(line)?)),
+                "OBSLTE" => Ok(Record::Obslte(obslte::ObslteRecord::try_from(line)?)),
+                "ORIGX1" | "ORIGX2" | "ORIGX3" => {
+                    Ok(Record::OrigxN(origxn::OrigxN::try_from(line)?))
+                }
+                "REMARK" => Ok(Record::Remark(remark::RemarkRecord::try_from(line)?)),
+                "REVDAT" => Ok(Record::Revdat(revdat::RevdatRecord::try_from(line)?)),
+                "SCALE1" | "SCALE2" | "SCALE3" => {
+                    Ok(Record::ScaleN(scalen::ScaleN::try_from(line)?))
+                }
+                "SEQRES" => Ok(Record::Seqres(seqres::SeqresRecord::try_from(line)?)),
+                "SHEET " => Ok(Record::Sheet(sheet::SheetRecord::try_from(line)?)),
+                "SITE  " => Ok(Record::Site(site::SiteRecord::try_from(line)?)),
+                "SOURCE" => Ok(Record::Source(source::SourceRecord::try_from(line)?)),
+                "SPLIT " => Ok(Record::Split(split::SplitRecord::try_from(line)?)),
+                "SPRSDE" => Ok(Record::Sprsde(sprsde::SprsdeRecord::try_from(line)?)),
+                "SSBOND" => Ok(Record::Ssbond(ssbond::SsbondRecord::try_from(line)?)),
+                "TER   " => Ok(Record::Term(term::TermRecord::try_from(line)?)),
+                "TITLE " => Ok(Record::Title(title::TitleRecord::try_from(line)?)),
+                _ => Ok(Record::Unknown(unknown::UnknownRecord::try_from(line)?)),
             },
-            None => Err("Unable to parse line"),
+            None => Err(ParseError::new(
+                "UNKNOWN",
+                0..6,
+                line.trim(),
+                ParseErrorKind::TooShort,
+            )),
         }
     }
 }
 
 impl std::fmt::Display for Record {
+    /// Write the record as a fixed-column PDB line, padded to 80 characters.
+    ///
+    /// This is the same with or without the `serde` feature; serialize the record, for
+    /// example with `serde_json::to_string`, for JSON.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        #[cfg(feature = "serde")]
-        match serde_json::to_string(&self) {
-            Ok(item) => write!(f, "{}", item),
-            Err(_) => Err(std::fmt::Error),
-        }
-        #[cfg(not(feature = "serde"))]
         match self {
-            Record::Anisou(anisotropic) => write!(f, "{:?}", anisotropic),
-            Record::Atom(atom) => write!(f, "{:?}", atom),
-            Record::Connect(connect) => write!(f, "{:?}", connect),
-            Record::Crystal(crystal) => write!(f, "{:?}", crystal),
-            Record::DBRef(dbref) => write!(f, "{:?}", dbref),
-            Record::Endmdl() => write!(f, "ENDMDL"),
-            Record::Hetatm(atom) => write!(f, "{:?}", atom),
-            Record::Hetnam(hetnam) => write!(f, "{:?}", hetnam),
-            Record::Het(het) => write!(f, "{:?}", het),
-            Record::MtrixN(mtrix) => write!(f, "{:?}", mtrix),
-            Record::Model(model) => write!(f, "{:?}", model),
-            Record::Modres(modres) => write!(f, "{:?}", modres),
-            Record::Nummdl(nummdl) => write!(f, "{:?}", nummdl),
-            Record::OrigxN(origxn) => write!(f, "{:?}", origxn),
-            Record::ScaleN(scalen) => write!(f, "{:?}", scalen),
-            Record::Seqres(seqres) => write!(f, "{:?}", seqres),
-            Record::Seqadv(seqadv) => write!(f, "{:?}", seqadv),
-            Record::Term(term) => write!(f, "{:?}", term),
+            Record::Anisou(anisotropic) => write!(f, "{}", anisotropic),
+            Record::Atom(atom) => write!(f, "{}", atom),
+            Record::Author(author) => write!(f, "{}", author),
+            Record::Caveat(caveat) => write!(f, "{}", caveat),
+            Record::Cispep(cispep) => write!(f, "{}", cispep),
+            Record::Compnd(compnd) => write!(f, "{}", compnd),
+            Record::Connect(connect) => write!(f, "{}", connect),
+            Record::Crystal(crystal) => write!(f, "{}", crystal),
+            Record::DBRef(dbref) => write!(f, "{}", dbref),
+            Record::End() => write!(f, "{:<80}", "END"),
+            Record::Endmdl() => write!(f, "{:<80}", "ENDMDL"),
+            Record::Expdta(expdta) => write!(f, "{}", expdta),
+            Record::Formul(formul) => write!(f, "{}", formul),
+            Record::Header(header) => write!(f, "{}", header),
+            Record::Helix(helix) => write!(f, "{}", helix),
+            Record::Hetatm(atom) => atom.fmt_record(f, "HETATM"),
+            Record::Hetnam(hetnam) => write!(f, "{}", hetnam),
+            Record::Het(het) => write!(f, "{}", het),
+            Record::Hetsyn(hetsyn) => write!(f, "{}", hetsyn),
+            Record::Keywds(keywds) => write!(f, "{}", keywds),
+            Record::Link(link) => write!(f, "{}", link),
+            Record::Master(master) => write!(f, "{}", master),
+            Record::Mdltyp(mdltyp) => write!(f, "{}", mdltyp),
+            Record::MtrixN(mtrix) => write!(f, "{}", mtrix),
+            Record::Model(model) => write!(f, "{}", model),
+            Record::Modres(modres) => write!(f, "{}", modres),
+            Record::Nummdl(nummdl) => write!(f, "{}", nummdl),
+            Record::Obslte(obslte) => write!(f, "{}", obslte),
+            Record::OrigxN(origxn) => write!(f, "{}", origxn),
+            Record::Remark(remark) => write!(f, "{}", remark),
+            Record::Revdat(revdat) => write!(f, "{}", revdat),
+            Record::ScaleN(scalen) => write!(f, "{}", scalen),
+            Record::Seqres(seqres) => write!(f, "{}", seqres),
+            Record::Seqadv(seqadv) => write!(f, "{}", seqadv),
+            Record::Sheet(sheet) => write!(f, "{}", sheet),
+            Record::Site(site) => write!(f, "{}", site),
+            Record::Source(source) => write!(f, "{}", source),
+            Record::Split(split) => write!(f, "{}", split),
+            Record::Sprsde(sprsde) => write!(f, "{}", sprsde),
+            Record::Ssbond(ssbond) => write!(f, "{}", ssbond),
+            Record::Term(term) => write!(f, "{}", term),
+            Record::Title(title) => write!(f, "{}", title),
+            Record::Unknown(unknown) => write!(f, "{}", unknown),
         }
     }
 }
 
+/// A set of record types, used to select which lines a reader parses.
+///
+/// Each record type has a constant named after its record name (`MTRIX`, `ORIGX` and
+/// `SCALE` cover all three rows), and [`RecordKinds::UNKNOWN`] covers every line kept as
+/// [`Record::Unknown`]. Sets are combined with `|`.
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::{Record, RecordKinds};
+///
+/// let coordinates = RecordKinds::ATOM | RecordKinds::HETATM;
+/// let line = "ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N";
+///
+/// assert!(coordinates.contains(RecordKinds::of_line(line)));
+/// assert!(!coordinates.contains(RecordKinds::of_line("SEQRES   1 A    2  MET ALA")));
+/// assert_eq!(Record::try_from(line).unwrap().kind(), RecordKinds::ATOM);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RecordKinds(u64);
+
+/// Define the [`RecordKinds`] constants along with the mappings from record names and
+/// [`Record`] variants to them.
+macro_rules! record_kinds {
+    ($($kind:ident = $bit:expr, $variant:ident, [$($tag:literal)|+];)*) => {
+        impl RecordKinds {
+            $(
+                #[doc = concat!("Records parsed as [`Record::", stringify!($variant), "`]")]
+                pub const $kind: RecordKinds = RecordKinds(1 << $bit);
+            )*
+
+            /// The record type of a line, judged only by its record name in columns 1-6.
+            ///
+            /// Lines with an unrecognized record name are [`RecordKinds::UNKNOWN`].
+            pub fn of_line(line: &str) -> RecordKinds {
+                let tag = match line.get(0..6) {
+                    Some(tag) => std::borrow::Cow::Borrowed(tag),
+                    None => std::borrow::Cow::Owned(format!("{:<6}", line)),
+                };
+                match tag.as_ref() {
+                    $($($tag)|+ => RecordKinds::$kind,)*
+                    _ => RecordKinds::UNKNOWN,
+                }
+            }
+        }
+
+        impl Record {
+            /// The record type of this record.
+            pub fn kind(&self) -> RecordKinds {
+                match self {
+                    $(Record::$variant(..) => RecordKinds::$kind,)*
+                    Record::Unknown(_) => RecordKinds::UNKNOWN,
+                }
+            }
+        }
+    };
+}
+
+record_kinds! {
+    ANISOU = 0, Anisou, ["ANISOU"];
+    ATOM = 1, Atom, ["ATOM  "];
+    AUTHOR = 2, Author, ["AUTHOR"];
+    CAVEAT = 3, Caveat, ["CAVEAT"];
+    CISPEP = 4, Cispep, ["CISPEP"];
+    COMPND = 5, Compnd, ["COMPND"];
+    CONECT = 6, Connect, ["CONECT"];
+    CRYST1 = 7, Crystal, ["CRYST1"];
+    DBREF = 8, DBRef, ["DBREF "];
+    END = 9, End, ["END   "];
+    ENDMDL = 10, Endmdl, ["ENDMDL"];
+    EXPDTA = 11, Expdta, ["EXPDTA"];
+    FORMUL = 12, Formul, ["FORMUL"];
+    HEADER = 13, Header, ["HEADER"];
+    HELIX = 14, Helix, ["HELIX "];
+    HET = 15, Het, ["HET   "];
+    HETATM = 16, Hetatm, ["HETATM"];
+    HETNAM = 17, Hetnam, ["HETNAM"];
+    HETSYN = 18, Hetsyn, ["HETSYN"];
+    KEYWDS = 19, Keywds, ["KEYWDS"];
+    LINK = 20, Link, ["LINK  "];
+    MASTER = 21, Master, ["MASTER"];
+    MDLTYP = 22, Mdltyp, ["MDLTYP"];
+    MODEL = 23, Model, ["MODEL "];
+    MODRES = 24, Modres, ["MODRES"];
+    MTRIX = 25, MtrixN, ["MTRIX1" | "MTRIX2" | "MTRIX3"];
+    NUMMDL = 26, Nummdl, ["NUMMDL"];
+    OBSLTE = 27, Obslte, ["OBSLTE"];
+    ORIGX = 28, OrigxN, ["ORIGX1" | "ORIGX2" | "ORIGX3"];
+    REMARK = 29, Remark, ["REMARK"];
+    REVDAT = 30, Revdat, ["REVDAT"];
+    SCALE = 31, ScaleN, ["SCALE1" | "SCALE2" | "SCALE3"];
+    SEQADV = 32, Seqadv, ["SEQADV"];
+    SEQRES = 33, Seqres, ["SEQRES"];
+    SHEET = 34, Sheet, ["SHEET "];
+    SITE = 35, Site, ["SITE  "];
+    SOURCE = 36, Source, ["SOURCE"];
+    SPLIT = 37, Split, ["SPLIT "];
+    SPRSDE = 38, Sprsde, ["SPRSDE"];
+    SSBOND = 39, Ssbond, ["SSBOND"];
+    TER = 40, Term, ["TER   "];
+    TITLE = 41, Title, ["TITLE "];
+}
+
+impl RecordKinds {
+    /// Lines kept as [`Record::Unknown`]
+    pub const UNKNOWN: RecordKinds = RecordKinds(1 << 63);
+
+    /// The set containing no record types.
+    pub const fn empty() -> RecordKinds {
+        RecordKinds(0)
+    }
+
+    /// The set containing every record type.
+    pub const fn all() -> RecordKinds {
+        RecordKinds(u64::MAX)
+    }
+
+    /// Whether the set contains no record types.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether every record type in `other` is also in this set.
+    pub const fn contains(self, other: RecordKinds) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for RecordKinds {
+    type Output = RecordKinds;
+
+    fn bitor(self, other: RecordKinds) -> RecordKinds {
+        RecordKinds(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for RecordKinds {
+    fn bitor_assign(&mut self, other: RecordKinds) {
+        self.0 |= other.0;
+    }
+}
+
+impl std::ops::BitAnd for RecordKinds {
+    type Output = RecordKinds;
+
+    fn bitand(self, other: RecordKinds) -> RecordKinds {
+        RecordKinds(self.0 & other.0)
+    }
+}
+
+impl std::ops::Not for RecordKinds {
+    type Output = RecordKinds;
+
+    fn not(self) -> RecordKinds {
+        RecordKinds(!self.0)
+    }
+}
+
 // impl From<&str> for Record {
 //     fn from(line: &str) -> Self {
 //         Self::try_from(line).unwrap()
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_unknown() {
+        let line = "JRNL        AUTH   A.UTHOR";
+        match Record::try_from(line) {
+            Ok(Record::Unknown(unknown)) => {
+                assert_eq!(unknown.tag, "JRNL");
+                assert_eq!(unknown.line, line);
+            }
+            other => panic!("Wrong record type: {:?}", other),
+        }
+        assert!(matches!(Record::try_from("USER"), Ok(Record::Unknown(_))));
+    }
+
+    #[test]
+    fn test_record_kinds() {
+        for line in [
+            "HETATM    4  O   HOH A 101      15.123  12.456  30.789  1.00 25.50           O",
+            "MTRIX2   1  0.000000  1.000000  0.000000        0.00000    1",
+            "TER",
+            "END",
+            "JRNL        AUTH   A.UTHOR",
+        ] {
+            let record = Record::try_from(line).unwrap();
+            assert_eq!(RecordKinds::of_line(line), record.kind());
+        }
+        assert_eq!(RecordKinds::of_line("MTRIX3"), RecordKinds::MTRIX);
+
+        let coordinates = RecordKinds::ATOM | RecordKinds::HETATM;
+        assert!(coordinates.contains(RecordKinds::HETATM));
+        assert!(!coordinates.contains(RecordKinds::ATOM | RecordKinds::ANISOU));
+        assert!((coordinates & RecordKinds::ANISOU).is_empty());
+        assert!(RecordKinds::all().contains(RecordKinds::UNKNOWN));
+        assert!(!(!coordinates).contains(RecordKinds::ATOM));
+    }
+
+    #[test]
+    fn test_record_try_from_errors() {
+        let error = Record::try_from("   ").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::TooShort);
+        assert!(matches!(Record::try_from("TER"), Ok(Record::Term(_))));
+        assert!(matches!(Record::try_from("END"), Ok(Record::End())));
+        match Record::try_from("USER \u{e9}t\u{e9}") {
+            Ok(Record::Unknown(unknown)) => assert_eq!(unknown.line, "USER \u{e9}t\u{e9}"),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(
+            RecordKinds::of_line("USER \u{e9}t\u{e9}"),
+            RecordKinds::UNKNOWN
+        );
+
+        let error = Record::try_from("SEQRES   1 A  1x7  THR SER").unwrap_err();
+        assert_eq!(error.record, "SEQRES");
+        assert_eq!(error.columns, 13..17);
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+    }
+}
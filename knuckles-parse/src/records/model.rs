@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError};
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -23,7 +25,7 @@ use knuckles_macro::pydefault;
 /// use knuckles_parse::records::model::ModelRecord;
 ///
 /// let line = "MODEL        1                                                                  ";
-/// let model = ModelRecord::from(line);
+/// let model = ModelRecord::try_from(line).unwrap();
 /// assert_eq!(model.serial, 1);
 /// ```
 #[derive(Debug, Clone)]
@@ -37,15 +39,18 @@ pub struct ModelRecord {
 
 impl ModelRecord {
     /// Create a new ModelRecord by parsing a MODEL line.
-    pub fn new(str: &str) -> ModelRecord {
-        ModelRecord {
-            serial: str[10..14].trim().parse::<u32>().unwrap_or_default(),
-        }
+    pub fn new(str: &str) -> Result<ModelRecord, ParseError> {
+        let columns = Columns::new("MODEL", str);
+        Ok(ModelRecord {
+            serial: columns.parse_or_default(10..14),
+        })
     }
 }
 
-impl From<&str> for ModelRecord {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for ModelRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         ModelRecord::new(str)
     }
 }
@@ -62,13 +67,13 @@ mod tests {
     #[test]
     fn test_model_record_new() {
         let line = "MODEL        1";
-        let model = ModelRecord::new(line);
+        let model = ModelRecord::new(line).unwrap();
         assert_eq!(model.serial, 1);
     }
     #[test]
-    fn test_model_record_from() {
+    fn test_model_record_try_from() {
         let line = "MODEL        1";
-        let model = ModelRecord::from(line);
+        let model = ModelRecord::try_from(line).unwrap();
         assert_eq!(model.serial, 1);
     }
     #[test]
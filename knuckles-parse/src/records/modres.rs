@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError};
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -22,25 +24,56 @@ pub struct ModresRecord {
 }
 
 impl ModresRecord {
-    pub fn new(str: &str) -> ModresRecord {
-        ModresRecord::from(str)
+    pub fn new(line: &str) -> Result<ModresRecord, ParseError> {
+        let columns = Columns::new("MODRES", line);
+        Ok(Self {
+            id_code: columns.str(7..11)?.to_string(),
+            res_name: columns.str(12..15)?.to_string(),
+            chain_id: columns.char(16)?,
+            seq_num: columns.parse(18..22)?,
+            i_code: columns.opt_char(22),
+            std_res_name: columns.str(24..27)?.to_string(),
+            comment: columns.rest(29),
+        })
     }
+}
 
-    pub fn from(line: &str) -> Self {
-        Self {
-            id_code: line[7..11].trim().to_string(),
-            res_name: line[12..15].trim().to_string(),
-            chain_id: line.chars().nth(16).unwrap(),
-            seq_num: line[18..22].trim().parse().unwrap(),
-            i_code: line[22..23].trim().parse().ok(),
-            std_res_name: line[24..27].trim().to_string(),
-            comment: line[29..].trim().to_string(),
-        }
+impl TryFrom<&str> for ModresRecord {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        Self::new(line)
     }
 }
 
-impl From<&str> for ModresRecord {
-    fn from(line: &str) -> Self {
-        Self::from(line)
+impl std::fmt::Display for ModresRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "MODRES {:<4} {:>3} {} {:>4}{} {:>3}  {}",
+            self.id_code,
+            self.res_name,
+            self.chain_id,
+            self.seq_num,
+            self.i_code.unwrap_or(' '),
+            self.std_res_name,
+            self.comment
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modres_display() {
+        let line = "MODRES 2R0L ASN A   74  ASN  GLYCOSYLATION SITE";
+        let record = ModresRecord::new(line).unwrap();
+        assert_eq!(record.res_name, "ASN");
+        assert_eq!(record.chain_id, 'A');
+        assert_eq!(record.seq_num, 74);
+        assert_eq!(record.comment, "GLYCOSYLATION SITE");
+        assert_eq!(record.to_string(), format!("{:<80}", line));
     }
 }
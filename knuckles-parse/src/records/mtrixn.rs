@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError, ParseErrorKind};
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -18,23 +20,35 @@ pub enum MtrixN {
 }
 
 impl MtrixN {
-    pub fn new(str: &str) -> Self {
-        let record = MtrixnRecord::from(str);
+    pub fn new(str: &str) -> Result<Self, ParseError> {
+        let record = MtrixnRecord::new(str)?;
         match record.n {
-            1 => MtrixN::Mtrix1(record),
-            2 => MtrixN::Mtrix2(record),
-            3 => MtrixN::Mtrix3(record),
-            _ => panic!("Invalid MTRIXn record"),
+            1 => Ok(MtrixN::Mtrix1(record)),
+            2 => Ok(MtrixN::Mtrix2(record)),
+            3 => Ok(MtrixN::Mtrix3(record)),
+            _ => Err(Columns::new("MTRIXn", str).error(5..6, ParseErrorKind::InvalidValue)),
         }
     }
 }
 
-impl From<&str> for MtrixN {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for MtrixN {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         MtrixN::new(str)
     }
 }
 
+impl std::fmt::Display for MtrixN {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MtrixN::Mtrix1(record) | MtrixN::Mtrix2(record) | MtrixN::Mtrix3(record) => {
+                write!(f, "{}", record)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "python", pyclass(get_all, set_all))]
@@ -48,28 +62,27 @@ pub struct MtrixnRecord {
 }
 
 impl MtrixnRecord {
-    pub fn new(str: &str) -> Self {
-        Self::from(str)
-    }
-
-    pub fn from(str: &str) -> Self {
-        Self {
-            n: str.chars().nth(5).unwrap() as u32 - 48,
-            serial_number: str[7..10].trim().parse().unwrap(),
+    pub fn new(str: &str) -> Result<Self, ParseError> {
+        let columns = Columns::new("MTRIXn", str);
+        Ok(Self {
+            n: columns.parse(5..6)?,
+            serial_number: columns.parse(7..10)?,
             matrix: [
-                str[10..20].trim().parse().unwrap(),
-                str[20..30].trim().parse().unwrap(),
-                str[30..40].trim().parse().unwrap(),
+                columns.parse(10..20)?,
+                columns.parse(20..30)?,
+                columns.parse(30..40)?,
             ],
-            vn: str[45..55].trim().parse().unwrap(),
-            i_given: str.chars().nth(59).unwrap() == '1',
-        }
+            vn: columns.parse(45..55)?,
+            i_given: columns.opt_char(59) == Some('1'),
+        })
     }
 }
 
-impl From<&str> for MtrixnRecord {
-    fn from(line: &str) -> Self {
-        Self::from(line)
+impl TryFrom<&str> for MtrixnRecord {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        Self::new(line)
     }
 }
 
@@ -100,14 +113,14 @@ mod tests {
     fn test_mtrixn_record() {
         let line =
             "MTRIX1   1 -1.000000  0.000000  0.000000        0.00000    1                   ";
-        let record = MtrixnRecord::from(line);
+        let record = MtrixnRecord::try_from(line).unwrap();
         assert_eq!(record.n, 1);
         assert_eq!(record.serial_number, 1);
         assert_eq!(record.matrix, [-1., 0., 0.]);
         assert_eq!(record.vn, 0.);
         assert!(record.i_given);
         let line2 = "MTRIX2   1  0.000000  1.000000  0.000000        0.00000    1";
-        let record = MtrixnRecord::from(line2);
+        let record = MtrixnRecord::try_from(line2).unwrap();
 
         assert_eq!(record.n, 2);
         assert_eq!(record.serial_number, 1);
@@ -119,7 +132,7 @@ mod tests {
     #[test]
     fn test_mtrixn() {
         let line = "MTRIX1   1 -1.000000  0.000000  0.000000        0.00000    1";
-        let record = MtrixN::from(line);
+        let record = MtrixN::try_from(line).unwrap();
         match record {
             MtrixN::Mtrix1(record) => {
                 assert_eq!(record.n, 1);
@@ -136,7 +149,14 @@ mod tests {
     fn test_mtrixn_display() {
         let line =
             "MTRIX1   1 -1.000000  0.000000  0.000000        0.00000    1                    ";
-        let record = MtrixnRecord::from(line);
+        let record = MtrixnRecord::try_from(line).unwrap();
         assert_eq!(format!("{}", record), line);
     }
+    #[test]
+    fn test_mtrixn_invalid_n() {
+        let line = "MTRIX4   1 -1.000000  0.000000  0.000000        0.00000    1";
+        let error = MtrixN::try_from(line).unwrap_err();
+        assert_eq!(error.columns, 5..6);
+        assert_eq!(error.kind, ParseErrorKind::InvalidValue);
+    }
 }
@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError};
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -26,15 +28,18 @@ pub struct NummdlRecord {
 
 impl NummdlRecord {
     /// Create a new NummdlRecord by parsing a NUMMDL line.
-    pub fn new(str: &str) -> NummdlRecord {
-        NummdlRecord {
-            count: str[10..14].trim().parse::<u32>().unwrap_or_default(),
-        }
+    pub fn new(str: &str) -> Result<NummdlRecord, ParseError> {
+        let columns = Columns::new("NUMMDL", str);
+        Ok(NummdlRecord {
+            count: columns.parse_or_default(10..14),
+        })
     }
 }
 
-impl From<&str> for NummdlRecord {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for NummdlRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         NummdlRecord::new(str)
     }
 }
@@ -51,13 +56,13 @@ mod tests {
     #[test]
     fn test_model_record_new() {
         let line = "NUMMDL       1";
-        let model = NummdlRecord::new(line);
+        let model = NummdlRecord::new(line).unwrap();
         assert_eq!(model.count, 1);
     }
     #[test]
-    fn test_model_record_from() {
+    fn test_model_record_try_from() {
         let line = "NUMMDL       1";
-        let model = NummdlRecord::from(line);
+        let model = NummdlRecord::try_from(line).unwrap();
         assert_eq!(model.count, 1);
     }
     #[test]
@@ -0,0 +1,110 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents an OBSLTE record, marking an entry as withdrawn and naming its replacements.
+///
+/// # Fields
+///
+/// - `continuation`: Continuation number, absent on the first line
+/// - `date`: Date this entry was replaced, in `DD-MMM-YY` format
+/// - `id_code`: PDB identifier of this entry
+/// - `replaced_by`: Up to nine PDB identifiers of the entries that replace this one on this line
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::obslte::ObslteRecord;
+///
+/// let record = ObslteRecord::try_from("OBSLTE     31-JAN-94 1MBP      2MBP").unwrap();
+/// assert_eq!(record.replaced_by, vec!["2MBP"]);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct ObslteRecord {
+    /// Continuation number, absent on the first line
+    pub continuation: Option<u32>,
+    /// Date this entry was replaced, in `DD-MMM-YY` format
+    pub date: String,
+    /// PDB identifier of this entry
+    pub id_code: String,
+    /// PDB identifiers of the entries that replace this one on this line
+    pub replaced_by: Vec<String>,
+}
+
+impl ObslteRecord {
+    /// Create a new ObslteRecord by parsing an OBSLTE line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the continuation number is not a number.
+    pub fn new(str: &str) -> Result<ObslteRecord, ParseError> {
+        let columns = Columns::new("OBSLTE", str);
+        Ok(ObslteRecord {
+            continuation: columns.opt_parse(8..10)?,
+            date: columns.opt_str(11..20).unwrap_or_default(),
+            id_code: columns.opt_str(21..25).unwrap_or_default(),
+            replaced_by: (0..9)
+                .filter_map(|i| columns.opt_str(31 + 5 * i..35 + 5 * i))
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<&str> for ObslteRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        ObslteRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for ObslteRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let continuation = self
+            .continuation
+            .map(|continuation| continuation.to_string())
+            .unwrap_or_default();
+        let line = format!(
+            "OBSLTE  {:>2} {:<9} {:<4}      {}",
+            continuation,
+            self.date,
+            self.id_code,
+            self.replaced_by.join(" ")
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_obslte_new() {
+        let record = ObslteRecord::new(
+            "OBSLTE     02-APR-08 1BOV      3BOV 3BOW 3BOX 3BOY 3BOZ 3BP0 3BP1 3BP2 3BP3",
+        )
+        .unwrap();
+        assert_eq!(record.continuation, None);
+        assert_eq!(record.id_code, "1BOV");
+        assert_eq!(record.date, "02-APR-08");
+        assert_eq!(record.replaced_by.len(), 9);
+    }
+
+    #[test]
+    fn test_obslte_display() {
+        let line = "OBSLTE     02-APR-08 1BOV      3BOV 3BOW 3BOX 3BOY 3BOZ 3BP0 3BP1 3BP2 3BP3";
+        let record = ObslteRecord::new(line).unwrap();
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
+}
@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError, ParseErrorKind};
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -18,23 +20,35 @@ pub enum OrigxN {
 }
 
 impl OrigxN {
-    pub fn new(str: &str) -> OrigxN {
-        let record = OrigxnRecord::from(str);
+    pub fn new(str: &str) -> Result<OrigxN, ParseError> {
+        let record = OrigxnRecord::new(str)?;
         match record.n {
-            1 => OrigxN::Origx1(record),
-            2 => OrigxN::Origx2(record),
-            3 => OrigxN::Origx3(record),
-            _ => panic!("Invalid ORIGXN record"),
+            1 => Ok(OrigxN::Origx1(record)),
+            2 => Ok(OrigxN::Origx2(record)),
+            3 => Ok(OrigxN::Origx3(record)),
+            _ => Err(Columns::new("ORIGXn", str).error(5..6, ParseErrorKind::InvalidValue)),
         }
     }
 }
 
-impl From<&str> for OrigxN {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for OrigxN {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         OrigxN::new(str)
     }
 }
 
+impl std::fmt::Display for OrigxN {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrigxN::Origx1(record) | OrigxN::Origx2(record) | OrigxN::Origx3(record) => {
+                write!(f, "{}", record)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "python", pyclass(get_all, set_all))]
@@ -46,21 +60,24 @@ pub struct OrigxnRecord {
 }
 
 impl OrigxnRecord {
-    pub fn new(str: &str) -> OrigxnRecord {
-        OrigxnRecord {
-            n: str.chars().nth(5).unwrap() as u16 - 48,
+    pub fn new(str: &str) -> Result<OrigxnRecord, ParseError> {
+        let columns = Columns::new("ORIGXn", str);
+        Ok(OrigxnRecord {
+            n: columns.parse(5..6)?,
             origxn: [
-                str[10..20].trim().parse().unwrap_or_default(),
-                str[20..30].trim().parse().unwrap_or_default(),
-                str[30..40].trim().parse().unwrap_or_default(),
+                columns.parse_or_default(10..20),
+                columns.parse_or_default(20..30),
+                columns.parse_or_default(30..40),
             ],
-            tn: str[45..55].trim().parse().unwrap_or_default(),
-        }
+            tn: columns.parse_or_default(45..55),
+        })
     }
 }
 
-impl From<&str> for OrigxnRecord {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for OrigxnRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         OrigxnRecord::new(str)
     }
 }
@@ -85,7 +102,7 @@ mod tests {
     fn parse_origxn_line_test() {
         const LINE: &str =
             "ORIGX1      0.963457  0.136613  0.230424       16.61000                         ";
-        let record = OrigxnRecord::new(LINE);
+        let record = OrigxnRecord::new(LINE).unwrap();
         assert_eq!(record.n, 1);
         assert_eq!(record.origxn, [0.963457, 0.136613, 0.230424]);
         assert_eq!(record.tn, 16.61);
@@ -95,7 +112,7 @@ mod tests {
     fn parse_origxn_test() {
         const LINE: &str =
             "ORIGX1      0.963457  0.136613  0.230424       16.61000                         ";
-        let record = OrigxN::new(LINE);
+        let record = OrigxN::new(LINE).unwrap();
         match record {
             OrigxN::Origx1(record) => {
                 assert_eq!(record.n, 1);
@@ -110,7 +127,7 @@ mod tests {
     fn origxn_record_display_test() {
         const LINE: &str =
             "ORIGX1      0.963457  0.136613  0.230424       16.61000                         ";
-        let record = OrigxnRecord::new(LINE);
+        let record = OrigxnRecord::new(LINE).unwrap();
         assert_eq!(format!("{}", record), LINE);
     }
 }
@@ -0,0 +1,102 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a REMARK record, one line of the numbered annotations in a PDB file.
+///
+/// Many REMARKs hold fixed-column tables, so the text keeps its leading whitespace and
+/// only trailing whitespace is removed. See [`crate::remarks::Remarks`] for the typed
+/// contents of REMARK 2, 3, 350, 465 and 470.
+///
+/// # Fields
+///
+/// - `number`: REMARK number identifying the kind of annotation
+/// - `text`: Text of the line from column 12, with leading whitespace preserved
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::remark::RemarkRecord;
+///
+/// let line = "REMARK   2 RESOLUTION.    1.74 ANGSTROMS.";
+/// let remark = RemarkRecord::try_from(line).unwrap();
+///
+/// assert_eq!(remark.number, 2);
+/// assert_eq!(remark.text, "RESOLUTION.    1.74 ANGSTROMS.");
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct RemarkRecord {
+    /// REMARK number identifying the kind of annotation
+    pub number: u32,
+    /// Text of the line from column 12, with leading whitespace preserved
+    pub text: String,
+}
+
+impl RemarkRecord {
+    /// Create a new RemarkRecord by parsing a REMARK line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the REMARK number is missing or not a number.
+    pub fn new(str: &str) -> Result<RemarkRecord, ParseError> {
+        let columns = Columns::new("REMARK", str);
+        Ok(RemarkRecord {
+            number: columns.parse(7..10)?,
+            text: str.get(11..).unwrap_or_default().trim_end().to_string(),
+        })
+    }
+}
+
+impl TryFrom<&str> for RemarkRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        RemarkRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for RemarkRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!("REMARK {:>3} {}", self.number, self.text);
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remark_new() {
+        let record = RemarkRecord::new("REMARK 465     MET A     1").unwrap();
+        assert_eq!(record.number, 465);
+        assert_eq!(record.text, "    MET A     1");
+
+        let record = RemarkRecord::new("REMARK   2").unwrap();
+        assert_eq!(record.number, 2);
+        assert_eq!(record.text, "");
+
+        assert!(RemarkRecord::new("REMARK  x2").is_err());
+    }
+
+    #[test]
+    fn test_remark_display() {
+        for line in [
+            "REMARK   2 RESOLUTION.    1.74 ANGSTROMS.",
+            "REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        0.00000",
+        ] {
+            let record = RemarkRecord::new(line).unwrap();
+            assert_eq!(record.to_string(), format!("{:<80}", line));
+        }
+    }
+}
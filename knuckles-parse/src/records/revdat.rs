@@ -0,0 +1,133 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a REVDAT record, describing one modification made to an entry.
+///
+/// # Fields
+///
+/// - `mod_num`: Modification number, increasing with each revision
+/// - `continuation`: Continuation number, absent on the first line of a modification
+/// - `mod_date`: Date of the modification in `DD-MMM-YY` format
+/// - `mod_id`: Identifier of the modification
+/// - `mod_type`: 0 for the initial release, 1 for later modifications
+/// - `records`: Names of up to four records changed by the modification on this line
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::revdat::RevdatRecord;
+///
+/// let line = "REVDAT   2   24-FEB-09 1ABC    1       VERSN";
+/// let revdat = RevdatRecord::try_from(line).unwrap();
+///
+/// assert_eq!(revdat.mod_num, 2);
+/// assert_eq!(revdat.records, vec!["VERSN"]);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct RevdatRecord {
+    /// Modification number, increasing with each revision
+    pub mod_num: u32,
+    /// Continuation number, absent on the first line of a modification
+    pub continuation: Option<u32>,
+    /// Date of the modification in `DD-MMM-YY` format
+    pub mod_date: String,
+    /// Identifier of the modification
+    pub mod_id: String,
+    /// 0 for the initial release, 1 for later modifications
+    pub mod_type: u8,
+    /// Names of the records changed by the modification on this line
+    pub records: Vec<String>,
+}
+
+impl RevdatRecord {
+    /// Create a new RevdatRecord by parsing a REVDAT line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the modification number is missing or either it or
+    /// the continuation number is not a number.
+    pub fn new(str: &str) -> Result<RevdatRecord, ParseError> {
+        let columns = Columns::new("REVDAT", str);
+        Ok(RevdatRecord {
+            mod_num: columns.parse(7..10)?,
+            continuation: columns.opt_parse(10..12)?,
+            mod_date: columns.opt_str(13..22).unwrap_or_default(),
+            mod_id: columns.opt_str(23..27).unwrap_or_default(),
+            mod_type: columns.parse_or_default(31..32),
+            records: (0..4)
+                .filter_map(|i| columns.trailing_str(39 + 7 * i..45 + 7 * i))
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<&str> for RevdatRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        RevdatRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for RevdatRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let continuation = self
+            .continuation
+            .map(|continuation| continuation.to_string())
+            .unwrap_or_default();
+        let records: Vec<String> = self
+            .records
+            .iter()
+            .map(|record| format!("{:<6}", record))
+            .collect();
+        let line = format!(
+            "REVDAT {:>3}{:>2} {:<9} {:<4}    {}       {}",
+            self.mod_num,
+            continuation,
+            self.mod_date,
+            self.mod_id,
+            self.mod_type,
+            records.join(" ")
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revdat_new() {
+        let line = "REVDAT   3 1 13-JUL-11 1ABC    1       JRNL   REMARK SOURCE HETATM";
+        let record = RevdatRecord::new(line).unwrap();
+        assert_eq!(record.mod_num, 3);
+        assert_eq!(record.continuation, Some(1));
+        assert_eq!(record.mod_date, "13-JUL-11");
+        assert_eq!(record.mod_id, "1ABC");
+        assert_eq!(record.mod_type, 1);
+        assert_eq!(record.records, vec!["JRNL", "REMARK", "SOURCE", "HETATM"]);
+    }
+
+    #[test]
+    fn test_revdat_display() {
+        for line in [
+            "REVDAT   1   08-OCT-02 1ABC    0",
+            "REVDAT   3 1 13-JUL-11 1ABC    1       JRNL   REMARK SOURCE HETATM",
+        ] {
+            let record = RevdatRecord::new(line).unwrap();
+            assert_eq!(record.to_string(), format!("{:<80}", line));
+        }
+    }
+}
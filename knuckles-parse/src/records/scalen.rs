@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError, ParseErrorKind};
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -18,23 +20,35 @@ pub enum ScaleN {
 }
 
 impl ScaleN {
-    pub fn new(str: &str) -> Self {
-        let record = ScalenRecord::from(str);
+    pub fn new(str: &str) -> Result<ScaleN, ParseError> {
+        let record = ScalenRecord::new(str)?;
         match record.n {
-            1 => ScaleN::Scale1(record),
-            2 => ScaleN::Scale2(record),
-            3 => ScaleN::Scale3(record),
-            _ => panic!("Invalid SCALEn record"),
+            1 => Ok(ScaleN::Scale1(record)),
+            2 => Ok(ScaleN::Scale2(record)),
+            3 => Ok(ScaleN::Scale3(record)),
+            _ => Err(Columns::new("SCALEn", str).error(5..6, ParseErrorKind::InvalidValue)),
         }
     }
 }
 
-impl From<&str> for ScaleN {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for ScaleN {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         ScaleN::new(str)
     }
 }
 
+impl std::fmt::Display for ScaleN {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaleN::Scale1(record) | ScaleN::Scale2(record) | ScaleN::Scale3(record) => {
+                write!(f, "{}", record)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "python", pyclass(get_all, set_all))]
@@ -46,25 +60,40 @@ pub struct ScalenRecord {
 }
 
 impl ScalenRecord {
-    pub fn new(str: &str) -> ScalenRecord {
-        ScalenRecord {
-            n: str.chars().nth(5).unwrap() as u16 - 48,
+    pub fn new(str: &str) -> Result<ScalenRecord, ParseError> {
+        let columns = Columns::new("SCALEn", str);
+        Ok(ScalenRecord {
+            n: columns.parse(5..6)?,
             scalen: [
-                str[10..20].trim().parse().unwrap_or_default(),
-                str[20..30].trim().parse().unwrap_or_default(),
-                str[30..40].trim().parse().unwrap_or_default(),
+                columns.parse_or_default(10..20),
+                columns.parse_or_default(20..30),
+                columns.parse_or_default(30..40),
             ],
-            un: str[45..55].trim().parse().unwrap_or_default(),
-        }
+            un: columns.parse_or_default(45..55),
+        })
     }
 }
 
-impl From<&str> for ScalenRecord {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for ScalenRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         ScalenRecord::new(str)
     }
 }
 
+impl std::fmt::Display for ScalenRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let n = format!("{:1}", self.n);
+        let scalen = format!(
+            "{:10.6}{:10.6}{:10.6}",
+            self.scalen[0], self.scalen[1], self.scalen[2]
+        );
+        let un = format!("     {:10.5}", self.un);
+        write!(f, "{:<1$}", format!("SCALE{}    {}{}", n, scalen, un), 80)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,7 +101,7 @@ mod tests {
     fn parse_scalen_line_test() {
         const LINE: &str =
             "SCALE1      0.019231  0.000000  0.000000        0.00000                         ";
-        let record = ScalenRecord::new(LINE);
+        let record = ScalenRecord::new(LINE).unwrap();
         assert_eq!(record.n, 1);
         assert_eq!(record.scalen, [0.019231, 0.0, 0.0]);
         assert_eq!(record.un, 0.0);
@@ -82,7 +111,7 @@ mod tests {
     fn parse_scalen_test() {
         const LINE: &str =
             "SCALE1      0.019231  0.000000  0.000000        0.00000                         ";
-        let record = ScaleN::new(LINE);
+        let record = ScaleN::new(LINE).unwrap();
         match record {
             ScaleN::Scale1(record) => {
                 assert_eq!(record.n, 1);
@@ -92,4 +121,11 @@ mod tests {
             _ => panic!("Wrong record type"),
         }
     }
+    #[test]
+    fn scalen_record_display_test() {
+        const LINE: &str =
+            "SCALE1      0.019231  0.000000  0.000000        0.00000                         ";
+        let record = ScaleN::new(LINE).unwrap();
+        assert_eq!(format!("{}", record), LINE);
+    }
 }
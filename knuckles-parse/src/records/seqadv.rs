@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError, ParseErrorKind};
+
 use super::dbref::DBType;
 
 #[cfg(feature = "python")]
@@ -27,33 +29,61 @@ pub struct SeqAdvRecord {
 }
 
 impl SeqAdvRecord {
-    pub fn new(line: &str) -> Self {
-        // let db_res = line[39..42].trim().parse::<i32>().ok();
-        let database = DBType::new(&line[24..28]);
-        SeqAdvRecord {
-            id_code: line[7..11].trim().to_string(),
-            res_name: line[12..15].trim().to_string(),
-            chain_id: line.chars().nth(16).unwrap(),
-            seq_num: line[18..22].trim().parse().unwrap(),
-            i_code: line[22..23].trim().parse().ok(),
+    pub fn new(line: &str) -> Result<Self, ParseError> {
+        let columns = Columns::new("SEQADV", line);
+        let database = DBType::new(columns.raw(24..28)?)
+            .map_err(|_| columns.error(24..28, ParseErrorKind::InvalidValue))?;
+        Ok(SeqAdvRecord {
+            id_code: columns.str(7..11)?.to_string(),
+            res_name: columns.str(12..15)?.to_string(),
+            chain_id: columns.char(16)?,
+            seq_num: columns.parse(18..22)?,
+            i_code: columns.opt_char(22),
             database,
-            db_accession: line[29..38].trim().to_string(),
-            db_res: line
-                .get(39..42)
-                .map(|str| str.trim().to_string())
-                .filter(|item| !item.is_empty()),
-            db_seq: line[43..48].trim().parse().ok(),
-            conflict: line[49..].trim().to_string(),
-        }
+            db_accession: columns.str(29..38)?.to_string(),
+            db_res: columns.opt_str(39..42),
+            db_seq: columns.opt_parse(43..48)?,
+            conflict: columns.rest(49),
+        })
     }
 }
 
-impl From<&str> for SeqAdvRecord {
-    fn from(value: &str) -> Self {
+impl TryFrom<&str> for SeqAdvRecord {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         SeqAdvRecord::new(value)
     }
 }
 
+impl std::fmt::Display for SeqAdvRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The SEQADV database field is only four columns wide.
+        let database = match self.database {
+            DBType::NORINE => "NOR".to_string(),
+            ref database => database.to_string(),
+        };
+        let db_seq = match self.db_seq {
+            Some(db_seq) => format!("{:>5}", db_seq),
+            None => " ".repeat(5),
+        };
+        let line = format!(
+            "SEQADV {:<4} {:>3} {} {:>4}{} {:<4} {:<9} {:>3} {} {}",
+            self.id_code,
+            self.res_name,
+            self.chain_id,
+            self.seq_num,
+            self.i_code.unwrap_or(' '),
+            database,
+            self.db_accession,
+            self.db_res.as_deref().unwrap_or_default(),
+            db_seq,
+            self.conflict
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,7 +91,7 @@ mod tests {
     #[test]
     fn test_new_seqdv_record() {
         let line = "SEQADV 3ABC MET A   -1  UNP  P10725              EXPRESSION TAG";
-        let record = SeqAdvRecord::new(line);
+        let record = SeqAdvRecord::new(line).unwrap();
         assert_eq!("3ABC", record.id_code);
         assert_eq!("MET", record.res_name);
         assert_eq!('A', record.chain_id);
@@ -74,7 +104,7 @@ mod tests {
         assert_eq!("EXPRESSION TAG", record.conflict);
 
         let line = "SEQADV 3ABC GLY A   50  UNP  P10725    VAL    50 ENGINEERED";
-        let record = SeqAdvRecord::new(line);
+        let record = SeqAdvRecord::new(line).unwrap();
         assert_eq!("3ABC", record.id_code);
         assert_eq!("GLY", record.res_name);
         assert_eq!('A', record.chain_id);
@@ -87,7 +117,7 @@ mod tests {
         assert_eq!("ENGINEERED", record.conflict);
 
         let line = "SEQADV 2OKW LEU A   64  NOR  NOR00669  PHE    14 SEE REMARK 999";
-        let record = SeqAdvRecord::new(line);
+        let record = SeqAdvRecord::new(line).unwrap();
         assert_eq!("2OKW", record.id_code);
         assert_eq!("LEU", record.res_name);
         assert_eq!('A', record.chain_id);
@@ -99,4 +129,15 @@ mod tests {
         assert_eq!(Some(14), record.db_seq);
         assert_eq!("SEE REMARK 999", record.conflict);
     }
+    #[test]
+    fn test_seqadv_display() {
+        for line in [
+            "SEQADV 3ABC MET A   -1  UNP  P10725              EXPRESSION TAG",
+            "SEQADV 3ABC GLY A   50  UNP  P10725    VAL    50 ENGINEERED",
+            "SEQADV 2OKW LEU A   64  NOR  NOR00669  PHE    14 SEE REMARK 999",
+        ] {
+            let record = SeqAdvRecord::new(line).unwrap();
+            assert_eq!(record.to_string(), format!("{:<80}", line));
+        }
+    }
 }
@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError};
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -26,7 +28,7 @@ use knuckles_macro::pydefault;
 /// use knuckles_parse::records::seqres::SeqresRecord;
 ///
 /// let line = "SEQRES   1 A  147  THR SER ASN PHE ALA ASP GLY LYS ASP ALA ILE LEU GLU";
-/// let seqres = SeqresRecord::from(line);
+/// let seqres = SeqresRecord::try_from(line).unwrap();
 /// assert_eq!(seqres.chain_id, 'A');
 /// assert_eq!(seqres.num_res, 147);
 /// ```
@@ -47,21 +49,57 @@ pub struct SeqresRecord {
 
 impl SeqresRecord {
     /// Create a new SeqresRecord by parsing a SEQRES line.
-    pub fn new(str: &str) -> Self {
-        Self {
-            ser_num: str[7..10].trim().parse().unwrap(),
-            chain_id: str.chars().nth(11).unwrap(),
-            num_res: str[13..17].trim().parse().unwrap(),
-            res_names: str[19..]
+    pub fn new(str: &str) -> Result<Self, ParseError> {
+        let columns = Columns::new("SEQRES", str);
+        Ok(Self {
+            ser_num: columns.parse(7..10)?,
+            chain_id: columns.char(11)?,
+            num_res: columns.parse(13..17)?,
+            res_names: columns
+                .rest(19)
                 .split_whitespace()
                 .map(|s| s.to_string())
                 .collect(),
-        }
+        })
     }
 }
 
-impl From<&str> for SeqresRecord {
-    fn from(line: &str) -> Self {
+impl TryFrom<&str> for SeqresRecord {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
         Self::new(line)
     }
 }
+
+impl std::fmt::Display for SeqresRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let res_names: Vec<String> = self
+            .res_names
+            .iter()
+            .map(|res_name| format!("{:>3}", res_name))
+            .collect();
+        let line = format!(
+            "SEQRES {:>3} {} {:>4}  {}",
+            self.ser_num,
+            self.chain_id,
+            self.num_res,
+            res_names.join(" ")
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seqres_display() {
+        let line = "SEQRES   1 A  147  THR SER ASN PHE ALA ASP GLY LYS ASP ALA ILE LEU GLU";
+        let record = SeqresRecord::new(line).unwrap();
+        assert_eq!(record.ser_num, 1);
+        assert_eq!(record.res_names.len(), 13);
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
+}
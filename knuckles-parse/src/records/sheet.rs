@@ -0,0 +1,241 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+use crate::records::atom::format_atom_name;
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a SHEET record, describing one strand of a beta sheet.
+///
+/// Every strand after the first gives its registration: a pair of hydrogen-bonded atoms
+/// in the current strand and the previous one. The registration fields are `None` for
+/// the first strand.
+///
+/// # Fields
+///
+/// - `strand`: Strand number, starting at 1 for each sheet
+/// - `sheet_id`: Sheet identifier
+/// - `num_strands`: Number of strands in the sheet
+/// - `init_*` / `end_*`: Residue name, chain, sequence number and insertion code of the
+///   first and last residues of the strand
+/// - `sense`: Sense of the strand relative to the previous one: 0 for the first strand,
+///   1 for parallel and -1 for anti-parallel
+/// - `cur_*`: Registration atom in the current strand
+/// - `prev_*`: Registration atom in the previous strand
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::sheet::SheetRecord;
+///
+/// let line = "SHEET    2   A 5 ILE A  96  THR A  99 -1  N  LYS A  98   O  THR A 108";
+/// let sheet = SheetRecord::try_from(line).unwrap();
+///
+/// assert_eq!(sheet.sheet_id, "A");
+/// assert_eq!(sheet.sense, -1);
+/// assert_eq!(sheet.cur_atom.as_deref(), Some("N"));
+/// assert_eq!(sheet.prev_res_seq, Some(108));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct SheetRecord {
+    /// Strand number, starting at 1 for each sheet
+    pub strand: u32,
+    /// Sheet identifier
+    pub sheet_id: String,
+    /// Number of strands in the sheet
+    pub num_strands: u32,
+    /// Name of the first residue
+    pub init_res_name: String,
+    /// Chain identifier of the first residue
+    pub init_chain_id: char,
+    /// Sequence number of the first residue
+    pub init_seq_num: i32,
+    /// Insertion code of the first residue
+    pub init_i_code: Option<char>,
+    /// Name of the last residue
+    pub end_res_name: String,
+    /// Chain identifier of the last residue
+    pub end_chain_id: char,
+    /// Sequence number of the last residue
+    pub end_seq_num: i32,
+    /// Insertion code of the last residue
+    pub end_i_code: Option<char>,
+    /// 0 for the first strand, 1 for parallel and -1 for anti-parallel
+    pub sense: i8,
+    /// Name of the registration atom in the current strand
+    pub cur_atom: Option<String>,
+    /// Residue name of the registration atom in the current strand
+    pub cur_res_name: Option<String>,
+    /// Chain identifier of the registration atom in the current strand
+    pub cur_chain_id: Option<char>,
+    /// Residue sequence number of the registration atom in the current strand
+    pub cur_res_seq: Option<i32>,
+    /// Insertion code of the registration atom in the current strand
+    pub cur_i_code: Option<char>,
+    /// Name of the registration atom in the previous strand
+    pub prev_atom: Option<String>,
+    /// Residue name of the registration atom in the previous strand
+    pub prev_res_name: Option<String>,
+    /// Chain identifier of the registration atom in the previous strand
+    pub prev_chain_id: Option<char>,
+    /// Residue sequence number of the registration atom in the previous strand
+    pub prev_res_seq: Option<i32>,
+    /// Insertion code of the registration atom in the previous strand
+    pub prev_i_code: Option<char>,
+}
+
+impl SheetRecord {
+    /// Create a new SheetRecord by parsing a SHEET line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the line ends before the last residue or a number is
+    /// missing or malformed.
+    pub fn new(str: &str) -> Result<SheetRecord, ParseError> {
+        let columns = Columns::new("SHEET", str);
+        Ok(SheetRecord {
+            strand: columns.parse(7..10)?,
+            sheet_id: columns.str(11..14)?.to_string(),
+            num_strands: columns.parse(14..16)?,
+            init_res_name: columns.str(17..20)?.to_string(),
+            init_chain_id: columns.char(21)?,
+            init_seq_num: columns.parse(22..26)?,
+            init_i_code: columns.opt_char(26),
+            end_res_name: columns.str(28..31)?.to_string(),
+            end_chain_id: columns.char(32)?,
+            end_seq_num: columns.parse(33..37)?,
+            end_i_code: columns.opt_char(37),
+            sense: columns.opt_parse(38..40)?.unwrap_or_default(),
+            cur_atom: columns.opt_str(41..45),
+            cur_res_name: columns.opt_str(45..48),
+            cur_chain_id: columns.opt_char(49),
+            cur_res_seq: columns.opt_parse(50..54)?,
+            cur_i_code: columns.opt_char(54),
+            prev_atom: columns.opt_str(56..60),
+            prev_res_name: columns.opt_str(60..63),
+            prev_chain_id: columns.opt_char(64),
+            prev_res_seq: columns.opt_parse(65..69)?,
+            prev_i_code: columns.opt_char(69),
+        })
+    }
+}
+
+impl TryFrom<&str> for SheetRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        SheetRecord::new(str)
+    }
+}
+
+/// Format a registration atom as its name, residue name, chain, sequence number and
+/// insertion code.
+fn format_registration(
+    atom: &Option<String>,
+    res_name: &Option<String>,
+    chain_id: Option<char>,
+    res_seq: Option<i32>,
+    i_code: Option<char>,
+) -> String {
+    format!(
+        "{}{:>3} {}{:>4}{}",
+        format_atom_name(atom.as_deref().unwrap_or_default(), None),
+        res_name.as_deref().unwrap_or_default(),
+        chain_id.unwrap_or(' '),
+        res_seq.map(|seq| seq.to_string()).unwrap_or_default(),
+        i_code.unwrap_or(' '),
+    )
+}
+
+impl std::fmt::Display for SheetRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut line = format!(
+            "SHEET  {:>3} {:>3}{:>2} {:>3} {}{:>4}{} {:>3} {}{:>4}{}{:>2}",
+            self.strand,
+            self.sheet_id,
+            self.num_strands,
+            self.init_res_name,
+            self.init_chain_id,
+            self.init_seq_num,
+            self.init_i_code.unwrap_or(' '),
+            self.end_res_name,
+            self.end_chain_id,
+            self.end_seq_num,
+            self.end_i_code.unwrap_or(' '),
+            self.sense,
+        );
+        if self.cur_atom.is_some() || self.prev_atom.is_some() {
+            line.push(' ');
+            line.push_str(&format_registration(
+                &self.cur_atom,
+                &self.cur_res_name,
+                self.cur_chain_id,
+                self.cur_res_seq,
+                self.cur_i_code,
+            ));
+            line.push(' ');
+            line.push_str(&format_registration(
+                &self.prev_atom,
+                &self.prev_res_name,
+                self.prev_chain_id,
+                self.prev_res_seq,
+                self.prev_i_code,
+            ));
+        }
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sheet_new() {
+        let record = SheetRecord::new("SHEET    1   A 5 THR A 107  ARG A 110  0").unwrap();
+        assert_eq!(record.strand, 1);
+        assert_eq!(record.num_strands, 5);
+        assert_eq!(record.init_res_name, "THR");
+        assert_eq!(record.init_chain_id, 'A');
+        assert_eq!(record.init_seq_num, 107);
+        assert_eq!(record.end_res_name, "ARG");
+        assert_eq!(record.end_seq_num, 110);
+        assert_eq!(record.sense, 0);
+        assert_eq!(record.cur_atom, None);
+        assert_eq!(record.prev_res_seq, None);
+
+        let line = "SHEET    3 BB110 GLY B1002A LEU B1010  1  OG1THR B1005   N  VAL B1021B";
+        let record = SheetRecord::new(line).unwrap();
+        assert_eq!(record.sheet_id, "BB1");
+        assert_eq!(record.num_strands, 10);
+        assert_eq!(record.init_i_code, Some('A'));
+        assert_eq!(record.sense, 1);
+        assert_eq!(record.cur_atom.as_deref(), Some("OG1"));
+        assert_eq!(record.cur_res_name.as_deref(), Some("THR"));
+        assert_eq!(record.cur_chain_id, Some('B'));
+        assert_eq!(record.cur_res_seq, Some(1005));
+        assert_eq!(record.prev_atom.as_deref(), Some("N"));
+        assert_eq!(record.prev_res_seq, Some(1021));
+        assert_eq!(record.prev_i_code, Some('B'));
+    }
+
+    #[test]
+    fn test_sheet_display() {
+        for line in [
+            "SHEET    1   A 5 THR A 107  ARG A 110  0",
+            "SHEET    2   A 5 ILE A  96  THR A  99 -1  N  LYS A  98   O  THR A 108",
+            "SHEET    3 BB110 GLY B1002A LEU B1010  1  OG1THR B1005   N  VAL B1021B",
+        ] {
+            let record = SheetRecord::new(line).unwrap();
+            assert_eq!(record.to_string(), format!("{:<80}", line));
+        }
+    }
+}
@@ -0,0 +1,171 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a SITE record, listing residues that make up a site such as a binding
+/// pocket or active site.
+///
+/// A site with more than four residues continues over several lines with the same
+/// `site_id`.
+///
+/// # Fields
+///
+/// - `seq_num`: Line number within the site, starting at 1
+/// - `site_id`: Site identifier
+/// - `num_res`: Total number of residues in the site
+/// - `residues`: Up to four residues of the site on this line
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::site::SiteRecord;
+///
+/// let line = "SITE     1 AC1  3 HIS A  94  HIS A  96  HIS A 119";
+/// let site = SiteRecord::try_from(line).unwrap();
+///
+/// assert_eq!(site.site_id, "AC1");
+/// assert_eq!(site.residues.len(), 3);
+/// assert_eq!(site.residues[2].seq_num, 119);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct SiteRecord {
+    /// Line number within the site, starting at 1
+    pub seq_num: u32,
+    /// Site identifier
+    pub site_id: String,
+    /// Total number of residues in the site
+    pub num_res: u32,
+    /// Up to four residues of the site on this line
+    pub residues: Vec<SiteResidue>,
+}
+
+/// A residue belonging to a site.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+pub struct SiteResidue {
+    /// Residue name
+    pub res_name: String,
+    /// Chain identifier
+    pub chain_id: char,
+    /// Residue sequence number
+    pub seq_num: i32,
+    /// Insertion code
+    pub i_code: Option<char>,
+}
+
+impl SiteRecord {
+    /// Create a new SiteRecord by parsing a SITE line.
+    ///
+    /// Residue slots left blank are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if a number is missing or malformed.
+    pub fn new(str: &str) -> Result<SiteRecord, ParseError> {
+        let columns = Columns::new("SITE", str);
+        let seq_num = columns.parse(7..10)?;
+        let site_id = columns.str(11..14)?.to_string();
+        let num_res = columns.parse(15..17)?;
+        let mut residues = Vec::new();
+        for start in (0..4).map(|i| 18 + 11 * i) {
+            let Some(res_name) = columns.opt_str(start..start + 3) else {
+                continue;
+            };
+            residues.push(SiteResidue {
+                res_name,
+                chain_id: columns.char(start + 4)?,
+                seq_num: columns.parse(start + 5..start + 9)?,
+                i_code: columns.opt_char(start + 9),
+            });
+        }
+        Ok(SiteRecord {
+            seq_num,
+            site_id,
+            num_res,
+            residues,
+        })
+    }
+}
+
+impl TryFrom<&str> for SiteRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        SiteRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for SiteRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let residues: Vec<String> = self
+            .residues
+            .iter()
+            .map(|residue| {
+                format!(
+                    "{:>3} {}{:>4}{}",
+                    residue.res_name,
+                    residue.chain_id,
+                    residue.seq_num,
+                    residue.i_code.unwrap_or(' ')
+                )
+            })
+            .collect();
+        let line = format!(
+            "SITE   {:>3} {:>3} {:>2} {}",
+            self.seq_num,
+            self.site_id,
+            self.num_res,
+            residues.join(" ")
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_site_new() {
+        let line = "SITE     2 AC2 11 GLU B 166  MET B 165A HOH B 501                      ";
+        let record = SiteRecord::new(line).unwrap();
+        assert_eq!(record.seq_num, 2);
+        assert_eq!(record.site_id, "AC2");
+        assert_eq!(record.num_res, 11);
+        assert_eq!(record.residues.len(), 3);
+        assert_eq!(
+            record.residues[1],
+            SiteResidue {
+                res_name: "MET".to_string(),
+                chain_id: 'B',
+                seq_num: 165,
+                i_code: Some('A'),
+            }
+        );
+
+        let error = SiteRecord::new("SITE     1 AC1  3 HIS A  9x").unwrap_err();
+        assert_eq!(error.columns, 23..27);
+    }
+
+    #[test]
+    fn test_site_display() {
+        for line in [
+            "SITE     1 AC1  3 HIS A  94  HIS A  96  HIS A 119",
+            "SITE     1 AC2 11 GLU B 166  MET B 165A HOH B 501  HOH B 502",
+        ] {
+            let record = SiteRecord::new(line).unwrap();
+            assert_eq!(record.to_string(), format!("{:<80}", line));
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use super::continued::continued_text_record;
+
+continued_text_record! {
+    /// Represents a SOURCE record from the title section of a PDB file.
+    ///
+    /// Describes the biological and/or chemical source of each molecule in the entry as a list
+    /// of `TOKEN: value;` pairs keyed by `MOL_ID`, mirroring [`super::compnd::CompndRecord`].
+    ///
+    /// # Fields
+    ///
+    /// - `continuation`: Continuation number, absent on the first line
+    /// - `text`: Specification list describing the biological source on this line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use knuckles_parse::records::source::SourceRecord;
+    ///
+    /// let line = "SOURCE   2 ORGANISM_SCIENTIFIC: HOMO SAPIENS;";
+    /// let record = SourceRecord::try_from(line).unwrap();
+    /// assert_eq!(record.continuation, Some(2));
+    /// assert_eq!(record.text, "ORGANISM_SCIENTIFIC: HOMO SAPIENS;");
+    /// ```
+    SourceRecord {
+        record: "SOURCE",
+        continuation: 7..10,
+        /// Specification list describing the biological source on this line
+        text,
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a SPLIT record, listing the entries that make up a large structure.
+///
+/// # Fields
+///
+/// - `continuation`: Continuation number, absent on the first line
+/// - `id_codes`: Up to 14 PDB identifiers of the related entries on this line
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::split::SplitRecord;
+///
+/// let split = SplitRecord::try_from("SPLIT      1VOQ 1VOR 1VOS").unwrap();
+/// assert_eq!(split.id_codes, vec!["1VOQ", "1VOR", "1VOS"]);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct SplitRecord {
+    /// Continuation number, absent on the first line
+    pub continuation: Option<u32>,
+    /// PDB identifiers of the related entries on this line
+    pub id_codes: Vec<String>,
+}
+
+impl SplitRecord {
+    /// Create a new SplitRecord by parsing a SPLIT line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the continuation number is not a number.
+    pub fn new(str: &str) -> Result<SplitRecord, ParseError> {
+        let columns = Columns::new("SPLIT", str);
+        Ok(SplitRecord {
+            continuation: columns.opt_parse(8..10)?,
+            id_codes: (0..14)
+                .filter_map(|i| columns.opt_str(11 + 5 * i..15 + 5 * i))
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<&str> for SplitRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        SplitRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for SplitRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let continuation = self
+            .continuation
+            .map(|continuation| continuation.to_string())
+            .unwrap_or_default();
+        let line = format!("SPLIT   {:>2} {}", continuation, self.id_codes.join(" "));
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_new() {
+        let line = "SPLIT    2 1VP0 1VP1";
+        let record = SplitRecord::new(line).unwrap();
+        assert_eq!(record.continuation, Some(2));
+        assert_eq!(record.id_codes, vec!["1VP0", "1VP1"]);
+    }
+
+    #[test]
+    fn test_split_display() {
+        let line =
+            "SPLIT      1VOQ 1VOR 1VOS 1VOU 1VOV 1VOW 1VOX 1VOY 1VP0 1VP1 1VP2 1VP3 1VP4 1VP5";
+        let record = SplitRecord::new(line).unwrap();
+        assert_eq!(record.id_codes.len(), 14);
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents an SPRSDE record, listing the entries this entry supersedes.
+///
+/// # Fields
+///
+/// - `continuation`: Continuation number, absent on the first line
+/// - `date`: Date this entry superseded the others, in `DD-MMM-YY` format
+/// - `id_code`: PDB identifier of this entry
+/// - `superseded`: Up to nine PDB identifiers of the entries superseded by this one on this line
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::sprsde::SprsdeRecord;
+///
+/// let record = SprsdeRecord::try_from("SPRSDE     17-JUL-84 4HHB      1HHB").unwrap();
+/// assert_eq!(record.superseded, vec!["1HHB"]);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct SprsdeRecord {
+    /// Continuation number, absent on the first line
+    pub continuation: Option<u32>,
+    /// Date this entry superseded the others, in `DD-MMM-YY` format
+    pub date: String,
+    /// PDB identifier of this entry
+    pub id_code: String,
+    /// PDB identifiers of the entries superseded by this one on this line
+    pub superseded: Vec<String>,
+}
+
+impl SprsdeRecord {
+    /// Create a new SprsdeRecord by parsing an SPRSDE line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the continuation number is not a number.
+    pub fn new(str: &str) -> Result<SprsdeRecord, ParseError> {
+        let columns = Columns::new("SPRSDE", str);
+        Ok(SprsdeRecord {
+            continuation: columns.opt_parse(8..10)?,
+            date: columns.opt_str(11..20).unwrap_or_default(),
+            id_code: columns.opt_str(21..25).unwrap_or_default(),
+            superseded: (0..9)
+                .filter_map(|i| columns.opt_str(31 + 5 * i..35 + 5 * i))
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<&str> for SprsdeRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        SprsdeRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for SprsdeRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let continuation = self
+            .continuation
+            .map(|continuation| continuation.to_string())
+            .unwrap_or_default();
+        let line = format!(
+            "SPRSDE  {:>2} {:<9} {:<4}      {}",
+            continuation,
+            self.date,
+            self.id_code,
+            self.superseded.join(" ")
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sprsde_new() {
+        let record = SprsdeRecord::new("SPRSDE     27-FEB-95 1GDJ      1LH4 2LH4").unwrap();
+        assert_eq!(record.continuation, None);
+        assert_eq!(record.id_code, "1GDJ");
+        assert_eq!(record.date, "27-FEB-95");
+        assert_eq!(record.superseded.len(), 2);
+    }
+
+    #[test]
+    fn test_sprsde_display() {
+        let line = "SPRSDE     27-FEB-95 1GDJ      1LH4 2LH4";
+        let record = SprsdeRecord::new(line).unwrap();
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
+}
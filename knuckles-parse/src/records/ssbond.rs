@@ -0,0 +1,155 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Columns, ParseError};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents an SSBOND record, a disulfide bond between two cysteines.
+///
+/// # Fields
+///
+/// - `serial`: Serial number of the bond
+/// - `res_name1` / `res_name2`: Residue names, normally "CYS"
+/// - `chain_id1` / `chain_id2`: Chain identifiers of the two residues
+/// - `seq_num1` / `seq_num2`: Sequence numbers of the two residues
+/// - `i_code1` / `i_code2`: Insertion codes of the two residues
+/// - `sym1` / `sym2`: Symmetry operators applied to each residue (e.g., "1555")
+/// - `length`: Bond distance in Ångströms
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::ssbond::SsbondRecord;
+///
+/// let line = "SSBOND   1 CYS A    6    CYS A  127                          1555   1555  2.03";
+/// let ssbond = SsbondRecord::try_from(line).unwrap();
+///
+/// assert_eq!((ssbond.seq_num1, ssbond.seq_num2), (6, 127));
+/// assert_eq!(ssbond.sym2.as_deref(), Some("1555"));
+/// assert_eq!(ssbond.length, Some(2.03));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct SsbondRecord {
+    /// Serial number of the bond
+    pub serial: u32,
+    /// Name of the first residue
+    pub res_name1: String,
+    /// Chain identifier of the first residue
+    pub chain_id1: char,
+    /// Sequence number of the first residue
+    pub seq_num1: i32,
+    /// Insertion code of the first residue
+    pub i_code1: Option<char>,
+    /// Name of the second residue
+    pub res_name2: String,
+    /// Chain identifier of the second residue
+    pub chain_id2: char,
+    /// Sequence number of the second residue
+    pub seq_num2: i32,
+    /// Insertion code of the second residue
+    pub i_code2: Option<char>,
+    /// Symmetry operator applied to the first residue
+    pub sym1: Option<String>,
+    /// Symmetry operator applied to the second residue
+    pub sym2: Option<String>,
+    /// Bond distance in Ångströms
+    pub length: Option<f32>,
+}
+
+impl SsbondRecord {
+    /// Create a new SsbondRecord by parsing an SSBOND line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the line ends before the second residue or a number is
+    /// missing or malformed.
+    pub fn new(str: &str) -> Result<SsbondRecord, ParseError> {
+        let columns = Columns::new("SSBOND", str);
+        Ok(SsbondRecord {
+            serial: columns.parse(7..10)?,
+            res_name1: columns.str(11..14)?.to_string(),
+            chain_id1: columns.char(15)?,
+            seq_num1: columns.parse(17..21)?,
+            i_code1: columns.opt_char(21),
+            res_name2: columns.str(25..28)?.to_string(),
+            chain_id2: columns.char(29)?,
+            seq_num2: columns.parse(31..35)?,
+            i_code2: columns.opt_char(35),
+            sym1: columns.opt_str(59..65),
+            sym2: columns.opt_str(66..72),
+            length: columns.opt_parse(73..78)?,
+        })
+    }
+}
+
+impl TryFrom<&str> for SsbondRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        SsbondRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for SsbondRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = format!(
+            "SSBOND {:>3} {:>3} {} {:>4}{}   {:>3} {} {:>4}{}{:>29} {:>6} {}",
+            self.serial,
+            self.res_name1,
+            self.chain_id1,
+            self.seq_num1,
+            self.i_code1.unwrap_or(' '),
+            self.res_name2,
+            self.chain_id2,
+            self.seq_num2,
+            self.i_code2.unwrap_or(' '),
+            self.sym1.as_deref().unwrap_or_default(),
+            self.sym2.as_deref().unwrap_or_default(),
+            self.length
+                .map(|length| format!("{:>5.2}", length))
+                .unwrap_or_default(),
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssbond_new() {
+        let line = "SSBOND   2 CYS B   30A   CYS C  115                          1555   3654  2.06";
+        let record = SsbondRecord::new(line).unwrap();
+        assert_eq!(record.serial, 2);
+        assert_eq!(record.res_name1, "CYS");
+        assert_eq!(record.chain_id1, 'B');
+        assert_eq!(record.seq_num1, 30);
+        assert_eq!(record.i_code1, Some('A'));
+        assert_eq!(record.chain_id2, 'C');
+        assert_eq!(record.seq_num2, 115);
+        assert_eq!(record.i_code2, None);
+        assert_eq!(record.sym1.as_deref(), Some("1555"));
+        assert_eq!(record.sym2.as_deref(), Some("3654"));
+        assert_eq!(record.length, Some(2.06));
+
+        let record = SsbondRecord::new("SSBOND   1 CYS A    6    CYS A  127").unwrap();
+        assert_eq!(record.sym1, None);
+        assert_eq!(record.length, None);
+    }
+
+    #[test]
+    fn test_ssbond_display() {
+        let line = "SSBOND   1 CYS A    6    CYS A  127                          1555   1555  2.03";
+        let record = SsbondRecord::new(line).unwrap();
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Columns, ParseError};
+use crate::hybrid36::encode_field;
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -30,33 +33,57 @@ pub struct TermRecord {
     /// Chain identifier
     pub chain_id: char,
     /// Residue sequence number
-    pub res_seq: i16,
+    pub res_seq: i32,
     /// Insertion code
     pub i_code: Option<char>,
 }
 
 impl TermRecord {
     /// Create a new TermRecord by parsing a TER line.
-    pub fn new(str: &str) -> TermRecord {
-        TermRecord {
-            serial: str[6..11].trim().parse().unwrap_or_default(),
-            res_name: str[17..20].trim().to_string(),
-            chain_id: str[21..22].parse().unwrap_or_default(),
-            res_seq: str[22..26].trim().parse().unwrap_or_default(),
-            i_code: str
-                .get(26..27)
-                .map(|s| s.parse().unwrap_or_default())
-                .filter(|item| *item != ' '),
-        }
+    ///
+    /// Bare `TER` lines without any of the optional fields are accepted.
+    pub fn new(str: &str) -> Result<TermRecord, ParseError> {
+        let columns = Columns::new("TER", str);
+        Ok(TermRecord {
+            serial: columns.hybrid36_or_default(6..11),
+            res_name: columns.opt_str(17..20).unwrap_or_default(),
+            chain_id: columns.char(21).unwrap_or_default(),
+            res_seq: columns.hybrid36_or_default(22..26),
+            i_code: columns.opt_char(26),
+        })
     }
 }
 
-impl From<&str> for TermRecord {
-    fn from(str: &str) -> Self {
+impl TryFrom<&str> for TermRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
         TermRecord::new(str)
     }
 }
 
+impl std::fmt::Display for TermRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Bare TER lines carry no fields, so write them back the same way.
+        if self.serial == 0 && self.res_name.is_empty() {
+            return write!(f, "{:<80}", "TER");
+        }
+        let chain_id = match self.chain_id {
+            '\0' => ' ',
+            chain_id => chain_id,
+        };
+        let line = format!(
+            "TER   {}      {:>3} {}{}{}",
+            encode_field(5, self.serial),
+            self.res_name,
+            chain_id,
+            encode_field(4, self.res_seq),
+            self.i_code.unwrap_or(' ')
+        );
+        write!(f, "{:<1$}", line, 80)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,7 +92,7 @@ mod tests {
     fn test_new() {
         let str =
             "TER     297      ALA A  18                                                      ";
-        let record = TermRecord::new(str);
+        let record = TermRecord::new(str).unwrap();
         assert_eq!(record.serial, 297);
         assert_eq!(record.res_name, "ALA");
         assert_eq!(record.chain_id, 'A');
@@ -74,14 +101,31 @@ mod tests {
     }
 
     #[test]
-    fn test_from() {
+    fn test_try_from() {
         let str =
             "TER     297      ALA A  18                                                      ";
-        let record = TermRecord::from(str);
+        let record = TermRecord::try_from(str).unwrap();
         assert_eq!(record.serial, 297);
         assert_eq!(record.res_name, "ALA");
         assert_eq!(record.chain_id, 'A');
         assert_eq!(record.res_seq, 18);
         assert_eq!(record.i_code, None);
     }
+    #[test]
+    fn test_bare_ter() {
+        let record = TermRecord::new("TER   ").unwrap();
+        assert_eq!(record.serial, 0);
+        assert_eq!(record.res_name, "");
+        assert_eq!(record.chain_id, char::default());
+        assert_eq!(record.i_code, None);
+    }
+    #[test]
+    fn test_display() {
+        let str =
+            "TER     297      ALA A  18                                                      ";
+        let record = TermRecord::new(str).unwrap();
+        assert_eq!(record.to_string(), str);
+        let record = TermRecord::new("TER").unwrap();
+        assert_eq!(record.to_string(), format!("{:<80}", "TER"));
+    }
 }
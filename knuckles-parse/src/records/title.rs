@@ -0,0 +1,28 @@
+use super::continued::continued_text_record;
+
+continued_text_record! {
+    /// Represents a TITLE record from the title section of a PDB file.
+    ///
+    /// The title of the entry, spread over as many continuation lines as needed.
+    ///
+    /// # Fields
+    ///
+    /// - `continuation`: Continuation number, absent on the first line
+    /// - `text`: Title of the experiment or analysis on this line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use knuckles_parse::records::title::TitleRecord;
+    ///
+    /// let record = TitleRecord::try_from("TITLE    2 CONTROLLED DEHYDRATION").unwrap();
+    /// assert_eq!(record.continuation, Some(2));
+    /// assert_eq!(record.text, "CONTROLLED DEHYDRATION");
+    /// ```
+    TitleRecord {
+        record: "TITLE",
+        continuation: 8..10,
+        /// Title of the experiment or analysis on this line
+        text,
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, ParseErrorKind};
+
+#[cfg(feature = "python")]
+use knuckles_macro::pydefault;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Represents a line whose record name this crate does not recognize.
+///
+/// The line is kept verbatim so that custom or unsupported records survive a
+/// read-modify-write cycle.
+///
+/// # Fields
+///
+/// - `tag`: Record name from columns 1-6, without surrounding whitespace
+/// - `line`: The complete line as read
+///
+/// # Example
+///
+/// ```rust
+/// use knuckles_parse::records::Record;
+///
+/// let line = "JRNL        AUTH   A.UTHOR";
+/// let Record::Unknown(unknown) = Record::try_from(line).unwrap() else {
+///     panic!("JRNL is not supported");
+/// };
+///
+/// assert_eq!(unknown.tag, "JRNL");
+/// assert_eq!(unknown.line, line);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[cfg_attr(feature = "python", pydefault)]
+pub struct UnknownRecord {
+    /// Record name from columns 1-6, without surrounding whitespace
+    pub tag: String,
+    /// The complete line as read
+    pub line: String,
+}
+
+impl UnknownRecord {
+    /// Create a new UnknownRecord holding `str` verbatim.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the record name in columns 1-6 is blank.
+    pub fn new(str: &str) -> Result<UnknownRecord, ParseError> {
+        let tag: String = str.chars().take(6).collect();
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return Err(ParseError::new(
+                "UNKNOWN",
+                0..6,
+                "",
+                ParseErrorKind::Missing,
+            ));
+        }
+        Ok(UnknownRecord {
+            tag: tag.to_string(),
+            line: str.to_string(),
+        })
+    }
+}
+
+impl TryFrom<&str> for UnknownRecord {
+    type Error = ParseError;
+
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        UnknownRecord::new(str)
+    }
+}
+
+impl std::fmt::Display for UnknownRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:<1$}", self.line, 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_new() {
+        let record = UnknownRecord::new("USER  MOD reduce.3.24.130724 H: found=0").unwrap();
+        assert_eq!(record.tag, "USER");
+        assert_eq!(record.line, "USER  MOD reduce.3.24.130724 H: found=0");
+
+        let error = UnknownRecord::new("      orphan text").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::Missing);
+    }
+
+    #[test]
+    fn test_unknown_display() {
+        let line = "JRNL        TITL   A TITLE";
+        let record = UnknownRecord::new(line).unwrap();
+        assert_eq!(record.to_string(), format!("{:<80}", line));
+    }
+}
@@ -0,0 +1,499 @@
+//! Typed contents of the commonly used REMARKs.
+//!
+//! Every REMARK line is parsed into a generic [`RemarkRecord`] holding its number and
+//! text. [`Remarks`] interprets the ones with a well-defined layout:
+//!
+//! | REMARK | Contents |
+//! |---|---|
+//! | 2 | Resolution |
+//! | 3 | Refinement program, resolution range and R-factors |
+//! | 350 | Biological assemblies and their BIOMT operators |
+//! | 465 | Missing residues |
+//! | 470 | Missing atoms |
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::{reader::ReaderOptions, remarks::Remarks};
+//!
+//! let contents = "\
+//! REMARK   2 RESOLUTION.    1.74 ANGSTROMS.
+//! REMARK   3   FREE R VALUE                     : 0.231
+//! REMARK 465   M RES C SSSEQI
+//! REMARK 465     MET A     1
+//! ";
+//! let report = ReaderOptions::new().read(contents).unwrap();
+//! let remarks = Remarks::new(&report.records);
+//!
+//! assert_eq!(remarks.resolution, Some(1.74));
+//! assert_eq!(remarks.refinement.unwrap().r_free, Some(0.231));
+//! assert_eq!(remarks.missing_residues[0].res_name, "MET");
+//! ```
+
+use crate::error::{Columns, ParseError, ParseErrorKind};
+use crate::records::{remark::RemarkRecord, Record};
+use crate::transform::Transform;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The typed contents of REMARK 2, 3, 350, 465 and 470.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Remarks {
+    /// Resolution in Ångströms from REMARK 2, absent when not applicable
+    pub resolution: Option<f32>,
+    /// Refinement statistics from REMARK 3
+    pub refinement: Option<Refinement>,
+    /// Biological assemblies from REMARK 350
+    pub assemblies: Vec<BiologicalAssembly>,
+    /// Residues that were not located in the experiment, from REMARK 465
+    pub missing_residues: Vec<MissingResidue>,
+    /// Residues with atoms that were not located in the experiment, from REMARK 470
+    pub missing_atoms: Vec<MissingAtoms>,
+    /// Malformed rows of the fixed-column tables in REMARK 350, 465 and 470, which were
+    /// skipped
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub diagnostics: Vec<ParseError>,
+}
+
+/// Refinement statistics from REMARK 3.
+///
+/// Only the first value of each item is kept when an entry lists several refinements.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Refinement {
+    /// Refinement program
+    pub program: Option<String>,
+    /// High resolution limit in Ångströms
+    pub resolution_high: Option<f32>,
+    /// Low resolution limit in Ångströms
+    pub resolution_low: Option<f32>,
+    /// R-factor of the working set
+    pub r_work: Option<f32>,
+    /// R-factor of the working and test sets together
+    pub r_all: Option<f32>,
+    /// R-factor of the test set
+    pub r_free: Option<f32>,
+}
+
+/// A biological assembly from REMARK 350.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BiologicalAssembly {
+    /// Assembly number
+    pub id: u32,
+    /// Biological unit determined by the authors (e.g., "DIMERIC")
+    pub author_unit: Option<String>,
+    /// Quaternary structure determined by software
+    pub software_unit: Option<String>,
+    /// Groups of chains with the operators applied to them
+    pub transforms: Vec<AssemblyTransform>,
+}
+
+/// A set of BIOMT operators and the chains they apply to.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AssemblyTransform {
+    /// Chain identifiers the operators apply to
+    pub chains: Vec<String>,
+    /// Operators generating the assembly from these chains
+    pub operators: Vec<BiomtOperator>,
+}
+
+/// A rotation and translation from the three BIOMT lines sharing a serial number.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BiomtOperator {
+    /// Operator serial number
+    pub serial: u32,
+    /// Rotation matrix, one row per BIOMT line
+    pub matrix: [[f32; 3]; 3],
+    /// Translation vector
+    pub vector: [f32; 3],
+}
+
+/// A residue listed in REMARK 465.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MissingResidue {
+    /// Model number, given for multi-model entries
+    pub model: Option<u32>,
+    /// Residue name
+    pub res_name: String,
+    /// Chain identifier
+    pub chain_id: Option<char>,
+    /// Residue sequence number
+    pub res_seq: i32,
+    /// Insertion code
+    pub i_code: Option<char>,
+}
+
+/// A residue listed in REMARK 470 with the names of its missing atoms.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MissingAtoms {
+    /// Model number, given for multi-model entries
+    pub model: Option<u32>,
+    /// Residue name
+    pub res_name: String,
+    /// Chain identifier
+    pub chain_id: Option<char>,
+    /// Residue sequence number
+    pub res_seq: i32,
+    /// Insertion code
+    pub i_code: Option<char>,
+    /// Names of the missing atoms
+    pub atoms: Vec<String>,
+}
+
+impl Remarks {
+    /// Interpret the REMARK records among `records`.
+    ///
+    /// Free-text values that are not numbers, such as `NULL` in REMARK 3, are `None`.
+    /// A malformed row of the fixed-column tables in REMARK 350, 465 and 470 is skipped
+    /// and reported in [`Remarks::diagnostics`], with the columns of the offending field;
+    /// the other rows are still read.
+    pub fn new(records: &[Record]) -> Remarks {
+        let mut remarks = Remarks::default();
+        let mut missing_residues = false;
+        let mut missing_atoms = false;
+        for record in records {
+            let Record::Remark(remark) = record else {
+                continue;
+            };
+            match remark.number {
+                2 => {
+                    if let Some(resolution) = remark.text.trim().strip_prefix("RESOLUTION.") {
+                        remarks.resolution = leading_number(resolution);
+                    }
+                }
+                3 => remarks
+                    .refinement
+                    .get_or_insert_with(Refinement::default)
+                    .push(&remark.text),
+                350 => {
+                    if let Err(error) = remarks.push_assembly_line(remark) {
+                        remarks.diagnostics.push(error);
+                    }
+                }
+                465 | 470 if remark.text.trim().is_empty() => {}
+                465 if missing_residues => match MissingResidue::new(remark) {
+                    Ok(residue) => remarks.missing_residues.push(residue),
+                    Err(error) => remarks.diagnostics.push(error),
+                },
+                465 => missing_residues = remark.text.trim() == "M RES C SSSEQI",
+                470 if missing_atoms => match MissingAtoms::new(remark) {
+                    Ok(atoms) => remarks.missing_atoms.push(atoms),
+                    Err(error) => remarks.diagnostics.push(error),
+                },
+                470 => missing_atoms = remark.text.trim() == "M RES CSSEQI  ATOMS",
+                _ => {}
+            }
+        }
+        remarks
+    }
+
+    fn push_assembly_line(&mut self, remark: &RemarkRecord) -> Result<(), ParseError> {
+        let text = remark.text.trim();
+        if text.starts_with("BIOMOLECULE:") {
+            let line = remark.to_string();
+            let start = line.find(':').unwrap_or_default() + 1;
+            let columns = Columns::new("REMARK 350", &line);
+            self.assemblies.push(BiologicalAssembly {
+                id: columns.parse(start..line.trim_end().len())?,
+                ..Default::default()
+            });
+            return Ok(());
+        }
+        let Some(assembly) = self.assemblies.last_mut() else {
+            return Ok(());
+        };
+        if let Some(unit) = text.strip_prefix("AUTHOR DETERMINED BIOLOGICAL UNIT:") {
+            assembly.author_unit = Some(unit.trim().to_string());
+        } else if let Some(unit) = text.strip_prefix("SOFTWARE DETERMINED QUATERNARY STRUCTURE:") {
+            assembly.software_unit = Some(unit.trim().to_string());
+        } else if let Some(chains) = text.strip_prefix("APPLY THE FOLLOWING TO CHAINS:") {
+            assembly.transforms.push(AssemblyTransform {
+                chains: split_chains(chains),
+                operators: Vec::new(),
+            });
+        } else if let Some(chains) = text.strip_prefix("AND CHAINS:") {
+            if let Some(transform) = assembly.transforms.last_mut() {
+                transform.chains.extend(split_chains(chains));
+            }
+        } else if text.starts_with("BIOMT") {
+            if assembly.transforms.is_empty() {
+                assembly.transforms.push(AssemblyTransform::default());
+            }
+            if let Some(transform) = assembly.transforms.last_mut() {
+                transform.push_biomt(remark)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AssemblyTransform {
+    /// Fill in one row of an operator from a `BIOMTn` line, starting a new operator when
+    /// the serial number changes.
+    fn push_biomt(&mut self, remark: &RemarkRecord) -> Result<(), ParseError> {
+        let line = remark.to_string();
+        let columns = Columns::new("REMARK 350", &line);
+        let row = columns.parse::<usize>(18..19)?;
+        if !(1..=3).contains(&row) {
+            return Err(columns.error(18..19, ParseErrorKind::InvalidValue));
+        }
+        let serial = columns.parse(19..23)?;
+        let matrix_row = [
+            columns.parse(23..33)?,
+            columns.parse(33..43)?,
+            columns.parse(43..53)?,
+        ];
+        let translation = columns.parse(58..68)?;
+        if self
+            .operators
+            .last()
+            .is_none_or(|operator| operator.serial != serial)
+        {
+            self.operators.push(BiomtOperator {
+                serial,
+                ..Default::default()
+            });
+        }
+        if let Some(operator) = self.operators.last_mut() {
+            operator.matrix[row - 1] = matrix_row;
+            operator.vector[row - 1] = translation;
+        }
+        Ok(())
+    }
+}
+
+impl BiomtOperator {
+    /// The operator as a coordinate transform.
+    pub fn transform(&self) -> Transform {
+        Transform::from_rows(std::array::from_fn(|i| (self.matrix[i], self.vector[i])))
+    }
+}
+
+impl Refinement {
+    fn push(&mut self, text: &str) {
+        let Some((key, value)) = text.split_once(':') else {
+            return;
+        };
+        let key = key.split_whitespace().collect::<Vec<_>>().join(" ");
+        let value = value.trim();
+        let number = value.parse().ok();
+        match key.as_str() {
+            "PROGRAM" if self.program.is_none() => {
+                self.program = Some(value.to_string()).filter(|value| value != "NULL")
+            }
+            "RESOLUTION RANGE HIGH (ANGSTROMS)" if self.resolution_high.is_none() => {
+                self.resolution_high = number
+            }
+            "RESOLUTION RANGE LOW (ANGSTROMS)" if self.resolution_low.is_none() => {
+                self.resolution_low = number
+            }
+            "R VALUE (WORKING SET)" if self.r_work.is_none() => self.r_work = number,
+            "R VALUE (WORKING + TEST SET)" if self.r_all.is_none() => self.r_all = number,
+            "FREE R VALUE" if self.r_free.is_none() => self.r_free = number,
+            _ => {}
+        }
+    }
+}
+
+impl MissingResidue {
+    fn new(remark: &RemarkRecord) -> Result<MissingResidue, ParseError> {
+        let line = remark.to_string();
+        let columns = Columns::new("REMARK 465", &line);
+        Ok(MissingResidue {
+            model: columns.opt_parse(11..14)?,
+            res_name: columns.str(15..18)?.to_string(),
+            chain_id: columns.opt_char(19),
+            res_seq: columns.parse(21..26)?,
+            i_code: columns.opt_char(26),
+        })
+    }
+}
+
+impl MissingAtoms {
+    fn new(remark: &RemarkRecord) -> Result<MissingAtoms, ParseError> {
+        let line = remark.to_string();
+        let columns = Columns::new("REMARK 470", &line);
+        Ok(MissingAtoms {
+            model: columns.opt_parse(11..14)?,
+            res_name: columns.str(15..18)?.to_string(),
+            chain_id: columns.opt_char(19),
+            res_seq: columns.parse(20..24)?,
+            i_code: columns.opt_char(24),
+            atoms: columns
+                .rest(25)
+                .split_whitespace()
+                .map(String::from)
+                .collect(),
+        })
+    }
+}
+
+/// The number at the start of `text`, if there is one.
+fn leading_number(text: &str) -> Option<f32> {
+    text.split_whitespace().next()?.parse().ok()
+}
+
+/// Split a comma-separated list of chain identifiers.
+fn split_chains(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|chain| !chain.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(contents: &str) -> Remarks {
+        let records: Vec<Record> = contents
+            .lines()
+            .map(|line| Record::try_from(line).unwrap())
+            .collect();
+        Remarks::new(&records)
+    }
+
+    #[test]
+    fn test_resolution_and_refinement() {
+        let remarks = parse(
+            "\
+REMARK   2
+REMARK   2 RESOLUTION.    2.10 ANGSTROMS.
+REMARK   3 REFINEMENT.
+REMARK   3   PROGRAM     : REFMAC 5.2.0019
+REMARK   3   RESOLUTION RANGE HIGH (ANGSTROMS) : 2.10
+REMARK   3   RESOLUTION RANGE LOW  (ANGSTROMS) : 40.00
+REMARK   3   R VALUE     (WORKING + TEST SET) : 0.19500
+REMARK   3   R VALUE            (WORKING SET) : 0.19200
+REMARK   3   FREE R VALUE                     : 0.25400
+REMARK   3   FREE R VALUE TEST SET SIZE   (%) : 5.000
+REMARK   3   BIN R VALUE           (WORKING SET) : NULL",
+        );
+        assert_eq!(remarks.resolution, Some(2.1));
+        let refinement = remarks.refinement.unwrap();
+        assert_eq!(refinement.program.as_deref(), Some("REFMAC 5.2.0019"));
+        assert_eq!(refinement.resolution_high, Some(2.1));
+        assert_eq!(refinement.resolution_low, Some(40.0));
+        assert_eq!(refinement.r_work, Some(0.192));
+        assert_eq!(refinement.r_all, Some(0.195));
+        assert_eq!(refinement.r_free, Some(0.254));
+
+        let remarks = parse("REMARK   2 RESOLUTION. NOT APPLICABLE.");
+        assert_eq!(remarks.resolution, None);
+        assert_eq!(remarks.refinement, None);
+    }
+
+    #[test]
+    fn test_assemblies() {
+        let remarks = parse(
+            "\
+REMARK 350 BIOMOLECULE: 1
+REMARK 350 AUTHOR DETERMINED BIOLOGICAL UNIT: DIMERIC
+REMARK 350 APPLY THE FOLLOWING TO CHAINS: A, B,
+REMARK 350                    AND CHAINS: C
+REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        0.00000
+REMARK 350   BIOMT2   1  0.000000  1.000000  0.000000        0.00000
+REMARK 350   BIOMT3   1  0.000000  0.000000  1.000000        0.00000
+REMARK 350   BIOMT1   2 -1.000000  0.000000  0.000000       45.20000
+REMARK 350   BIOMT2   2  0.000000 -1.000000  0.000000        0.00000
+REMARK 350   BIOMT3   2  0.000000  0.000000  1.000000      -12.50000
+REMARK 350 BIOMOLECULE: 2
+REMARK 350 APPLY THE FOLLOWING TO CHAINS: D
+REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        0.00000
+REMARK 350   BIOMT2   1  0.000000  1.000000  0.000000        0.00000
+REMARK 350   BIOMT3   1  0.000000  0.000000  1.000000        0.00000",
+        );
+        assert_eq!(remarks.assemblies.len(), 2);
+        let assembly = &remarks.assemblies[0];
+        assert_eq!(assembly.id, 1);
+        assert_eq!(assembly.author_unit.as_deref(), Some("DIMERIC"));
+        assert_eq!(assembly.transforms[0].chains, vec!["A", "B", "C"]);
+        let operators = &assembly.transforms[0].operators;
+        assert_eq!(operators.len(), 2);
+        assert_eq!(operators[0].matrix[1], [0.0, 1.0, 0.0]);
+        assert_eq!(operators[1].serial, 2);
+        assert_eq!(operators[1].vector, [45.2, 0.0, -12.5]);
+        assert!(operators[0].transform().is_identity(0.0));
+        let [x, _, z] = operators[1].transform().apply([1.0, 2.0, 3.0]);
+        assert!((x - 44.2).abs() < 1e-5 && (z + 9.5).abs() < 1e-5);
+        assert_eq!(remarks.assemblies[1].transforms[0].chains, vec!["D"]);
+
+        let remarks = parse(
+            "\
+REMARK   2 RESOLUTION.    2.10 ANGSTROMS.
+REMARK 350 BIOMOLECULE: 1
+REMARK 350   BIOMT1   1  1.0000x0  0.000000  0.000000        0.00000
+REMARK 350   BIOMT1   2  1.000000  0.000000  0.000000        0.00000",
+        );
+        assert_eq!(remarks.resolution, Some(2.1));
+        let operators = &remarks.assemblies[0].transforms[0].operators;
+        assert_eq!(operators.len(), 1);
+        assert_eq!(operators[0].serial, 2);
+        assert_eq!(remarks.diagnostics.len(), 1);
+        let error = &remarks.diagnostics[0];
+        assert_eq!(error.record, "REMARK 350");
+        assert_eq!(error.columns, 23..33);
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+    }
+
+    #[test]
+    fn test_missing_residues_and_atoms() {
+        let remarks = parse(
+            "\
+REMARK 465 MISSING RESIDUES
+REMARK 465 THE FOLLOWING RESIDUES WERE NOT LOCATED IN THE
+REMARK 465 EXPERIMENT. (M=MODEL NUMBER; RES=RESIDUE NAME; C=CHAIN
+REMARK 465 IDENTIFIER; SSSEQ=SEQUENCE NUMBER; I=INSERTION CODE.)
+REMARK 465
+REMARK 465   M RES C SSSEQI
+REMARK 465     MET A     1
+REMARK 465     GLY A    -2A
+REMARK 465   2 SER B  1001
+REMARK 470
+REMARK 470 MISSING ATOM
+REMARK 470   M RES CSSEQI  ATOMS
+REMARK 470     LYS A  32    CG   CD   CE   NZ
+REMARK 470     GLU B 104A   OE1",
+        );
+        assert!(remarks.diagnostics.is_empty());
+        assert_eq!(remarks.missing_residues.len(), 3);
+        assert_eq!(
+            remarks.missing_residues[0],
+            MissingResidue {
+                model: None,
+                res_name: "MET".to_string(),
+                chain_id: Some('A'),
+                res_seq: 1,
+                i_code: None,
+            }
+        );
+        assert_eq!(remarks.missing_residues[1].res_seq, -2);
+        assert_eq!(remarks.missing_residues[1].i_code, Some('A'));
+        assert_eq!(remarks.missing_residues[2].model, Some(2));
+        assert_eq!(remarks.missing_residues[2].res_seq, 1001);
+
+        assert_eq!(remarks.missing_atoms.len(), 2);
+        assert_eq!(remarks.missing_atoms[0].res_seq, 32);
+        assert_eq!(remarks.missing_atoms[0].atoms, vec!["CG", "CD", "CE", "NZ"]);
+        assert_eq!(remarks.missing_atoms[1].i_code, Some('A'));
+        assert_eq!(remarks.missing_atoms[1].atoms, vec!["OE1"]);
+
+        let remarks = parse(
+            "\
+REMARK 465   M RES C SSSEQI
+REMARK 465     MET A    1x
+REMARK 465     GLY A     2",
+        );
+        assert_eq!(remarks.missing_residues.len(), 1);
+        assert_eq!(remarks.missing_residues[0].res_seq, 2);
+        assert_eq!(remarks.diagnostics[0].record, "REMARK 465");
+    }
+}
@@ -0,0 +1,466 @@
+//! Hierarchical view of the atoms in a PDB file.
+//!
+//! A [`Structure`] groups ATOM and HETATM records into [`Model`]s, [`Chain`]s and
+//! [`Residue`]s, following MODEL/ENDMDL boundaries, chain identifiers, residue numbers
+//! and insertion codes. Alternate locations of an atom stay together in their residue.
+//! Chains are told apart by their full label, so chains whose identifiers are too long
+//! for the chain column and only kept in [`AtomRecord::auth_chain`] stay separate.
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::{pdbreader_single, structure::Structure};
+//!
+//! let contents = "ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N\n\
+//!                 ATOM      2  CA  ALA A   1      20.987  18.149  27.890  1.00 11.85           C\n\
+//!                 ATOM      3  N   GLY A   2      22.154  16.967  27.462  1.00 11.18           N";
+//! let records = pdbreader_single(contents);
+//! let structure = Structure::new(&records);
+//!
+//! let chain = structure.models()[0].chain('A').unwrap();
+//! assert_eq!(chain.residues().len(), 2);
+//! assert_eq!(chain.residue(1, None).unwrap().atom("CA").unwrap().serial, 2);
+//! ```
+
+use std::collections::HashMap;
+
+use crate::records::{atom::AtomRecord, model::ModelRecord, term::TermRecord, Record};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// All models in a PDB file.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Structure {
+    /// Models in file order
+    pub models: Vec<Model>,
+}
+
+/// A single model, delimited by MODEL/ENDMDL records or implied for single-model files.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Model {
+    /// Model serial number (1 for files without MODEL records)
+    pub serial: u32,
+    /// Chains in order of first appearance
+    pub chains: Vec<Chain>,
+}
+
+/// The residues of a model sharing a chain identifier.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Chain {
+    /// Chain identifier
+    pub id: Option<char>,
+    /// Full chain identifier when it does not fit in `id`, as in [`AtomRecord::auth_chain`]
+    pub auth_chain: Option<String>,
+    /// Residues in order of first appearance
+    pub residues: Vec<Residue>,
+}
+
+/// The atoms sharing a residue number and insertion code within a chain.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Residue {
+    /// Residue name (e.g., "ALA", "HOH")
+    pub name: String,
+    /// Residue sequence number
+    pub seq: i32,
+    /// Insertion code
+    pub i_code: Option<char>,
+    /// Whether the residue was read from HETATM records
+    pub hetero: bool,
+    /// Atoms in file order, including every alternate location
+    pub atoms: Vec<AtomRecord>,
+}
+
+impl Structure {
+    /// Build a structure from parsed records.
+    ///
+    /// Only ATOM, HETATM, MODEL and ENDMDL records are used; everything else is ignored.
+    /// Atoms outside of any MODEL/ENDMDL pair are placed in an implicit model numbered
+    /// one past the previous model (1 for the first).
+    pub fn new(records: &[Record]) -> Structure {
+        let mut builder = Builder::default();
+        for record in records {
+            match record {
+                Record::Model(model) => builder.start_model(model.serial),
+                Record::Endmdl() => builder.current = None,
+                Record::Atom(atom) => builder.push(atom, false),
+                Record::Hetatm(atom) => builder.push(atom, true),
+                _ => {}
+            }
+        }
+        builder.structure
+    }
+
+    /// The models of the structure.
+    pub fn models(&self) -> &[Model] {
+        &self.models
+    }
+
+    /// Look up a model by its serial number.
+    pub fn model(&self, serial: u32) -> Option<&Model> {
+        self.models.iter().find(|model| model.serial == serial)
+    }
+
+    /// Iterate over every atom in every model.
+    pub fn atoms(&self) -> impl Iterator<Item = &AtomRecord> {
+        self.models.iter().flat_map(Model::atoms)
+    }
+
+    /// Mutably iterate over every atom in every model.
+    pub fn atoms_mut(&mut self) -> impl Iterator<Item = &mut AtomRecord> {
+        self.models.iter_mut().flat_map(Model::atoms_mut)
+    }
+
+    /// Flatten the structure back into ATOM, HETATM and TER records, for use with
+    /// [`crate::writer::write_pdb`] or [`crate::mmcif::write_mmcif`].
+    ///
+    /// A TER record follows the last ATOM residue of each chain. Since a TER record takes
+    /// a serial number of its own, the atoms and TER records of each model are renumbered
+    /// in one running sequence from 1, leaving every serial number unique. Each model is
+    /// wrapped in MODEL/ENDMDL records when there is more than one, or when the serial
+    /// number of the only model is not 1, so that reading the records back gives the same
+    /// structure apart from the atom serial numbers.
+    pub fn to_records(&self) -> Vec<Record> {
+        let wrap = match &self.models[..] {
+            [model] => model.serial != 1,
+            models => !models.is_empty(),
+        };
+        let mut records = Vec::new();
+        for model in &self.models {
+            if wrap {
+                records.push(Record::Model(ModelRecord {
+                    serial: model.serial,
+                }));
+            }
+            let mut serial = 0;
+            for chain in &model.chains {
+                let last_polymer = chain.residues.iter().rposition(|residue| !residue.hetero);
+                for (index, residue) in chain.residues.iter().enumerate() {
+                    for atom in &residue.atoms {
+                        serial += 1;
+                        let atom = AtomRecord {
+                            serial,
+                            ..atom.clone()
+                        };
+                        records.push(match residue.hetero {
+                            true => Record::Hetatm(atom),
+                            false => Record::Atom(atom),
+                        });
+                    }
+                    if Some(index) == last_polymer {
+                        serial += 1;
+                        records.push(Record::Term(TermRecord {
+                            serial,
+                            res_name: residue.name.clone(),
+                            chain_id: chain.id.unwrap_or_default(),
+                            res_seq: residue.seq,
+                            i_code: residue.i_code,
+                        }));
+                    }
+                }
+            }
+            if wrap {
+                records.push(Record::Endmdl());
+            }
+        }
+        records
+    }
+}
+
+impl From<&[Record]> for Structure {
+    fn from(records: &[Record]) -> Self {
+        Structure::new(records)
+    }
+}
+
+impl Model {
+    /// The chains of the model.
+    pub fn chains(&self) -> &[Chain] {
+        &self.chains
+    }
+
+    /// Look up a chain by identifier; pass `None` for a blank chain identifier.
+    pub fn chain(&self, id: impl Into<Option<char>>) -> Option<&Chain> {
+        let id = id.into();
+        self.chains
+            .iter()
+            .find(|chain| chain.id == id && chain.auth_chain.is_none())
+    }
+
+    /// Look up a chain by its full label, which may be longer than one character.
+    pub fn chain_by_label(&self, label: &str) -> Option<&Chain> {
+        self.chains.iter().find(|chain| chain.label() == label)
+    }
+
+    /// Iterate over every residue in the model.
+    pub fn residues(&self) -> impl Iterator<Item = &Residue> {
+        self.chains.iter().flat_map(|chain| chain.residues.iter())
+    }
+
+    /// Iterate over every atom in the model.
+    pub fn atoms(&self) -> impl Iterator<Item = &AtomRecord> {
+        self.chains.iter().flat_map(Chain::atoms)
+    }
+
+    /// Mutably iterate over every atom in the model.
+    pub fn atoms_mut(&mut self) -> impl Iterator<Item = &mut AtomRecord> {
+        self.chains.iter_mut().flat_map(Chain::atoms_mut)
+    }
+}
+
+impl Chain {
+    /// The full chain label: `auth_chain` when set, otherwise the chain identifier, or
+    /// an empty string for a blank one.
+    pub fn label(&self) -> String {
+        match (&self.auth_chain, self.id) {
+            (Some(label), _) => label.clone(),
+            (None, Some(id)) => id.to_string(),
+            (None, None) => String::new(),
+        }
+    }
+
+    /// The residues of the chain.
+    pub fn residues(&self) -> &[Residue] {
+        &self.residues
+    }
+
+    /// Look up a residue by sequence number and insertion code.
+    pub fn residue(&self, seq: i32, i_code: impl Into<Option<char>>) -> Option<&Residue> {
+        let i_code = i_code.into();
+        self.residues
+            .iter()
+            .find(|residue| residue.seq == seq && residue.i_code == i_code)
+    }
+
+    /// Iterate over every atom in the chain.
+    pub fn atoms(&self) -> impl Iterator<Item = &AtomRecord> {
+        self.residues
+            .iter()
+            .flat_map(|residue| residue.atoms.iter())
+    }
+
+    /// Mutably iterate over every atom in the chain.
+    pub fn atoms_mut(&mut self) -> impl Iterator<Item = &mut AtomRecord> {
+        self.residues
+            .iter_mut()
+            .flat_map(|residue| residue.atoms.iter_mut())
+    }
+}
+
+impl Residue {
+    /// The atoms of the residue, including every alternate location.
+    pub fn atoms(&self) -> &[AtomRecord] {
+        &self.atoms
+    }
+
+    /// Look up an atom by name.
+    ///
+    /// When the atom has alternate locations, the one with the highest occupancy is
+    /// returned, preferring the first in file order on ties.
+    pub fn atom(&self, name: &str) -> Option<&AtomRecord> {
+        self.atoms.iter().filter(|atom| atom.name == name).fold(
+            None,
+            |best: Option<&AtomRecord>, atom| match best {
+                Some(best) if best.occupancy >= atom.occupancy => Some(best),
+                _ => Some(atom),
+            },
+        )
+    }
+
+    /// The distinct alternate location indicators used in the residue, in file order.
+    pub fn alt_locs(&self) -> Vec<char> {
+        let mut alt_locs = Vec::new();
+        for alt_loc in self.atoms.iter().filter_map(|atom| atom.alt_loc) {
+            if !alt_locs.contains(&alt_loc) {
+                alt_locs.push(alt_loc);
+            }
+        }
+        alt_locs
+    }
+
+    /// Iterate over the atoms of a single conformer: atoms without an alternate location
+    /// plus those at `alt_loc`.
+    pub fn conformer(&self, alt_loc: char) -> impl Iterator<Item = &AtomRecord> {
+        self.atoms
+            .iter()
+            .filter(move |atom| atom.alt_loc.is_none() || atom.alt_loc == Some(alt_loc))
+    }
+}
+
+/// Incrementally groups atoms, keeping indices of chains and residues already seen.
+#[derive(Default)]
+struct Builder {
+    structure: Structure,
+    current: Option<usize>,
+    chains: HashMap<(usize, Option<char>, Option<String>), usize>,
+    residues: HashMap<(usize, usize, i32, Option<char>), usize>,
+}
+
+impl Builder {
+    fn start_model(&mut self, serial: u32) {
+        self.structure.models.push(Model {
+            serial,
+            chains: Vec::new(),
+        });
+        self.current = Some(self.structure.models.len() - 1);
+    }
+
+    fn push(&mut self, atom: &AtomRecord, hetero: bool) {
+        let model_index = match self.current {
+            Some(index) => index,
+            None => {
+                let serial = self
+                    .structure
+                    .models
+                    .last()
+                    .map_or(1, |model| model.serial + 1);
+                self.start_model(serial);
+                self.structure.models.len() - 1
+            }
+        };
+        let model = &mut self.structure.models[model_index];
+        let chain_index = *self
+            .chains
+            .entry((model_index, atom.chain_id, atom.auth_chain.clone()))
+            .or_insert_with(|| {
+                model.chains.push(Chain {
+                    id: atom.chain_id,
+                    auth_chain: atom.auth_chain.clone(),
+                    residues: Vec::new(),
+                });
+                model.chains.len() - 1
+            });
+        let chain = &mut model.chains[chain_index];
+        let residue_index = *self
+            .residues
+            .entry((model_index, chain_index, atom.res_seq, atom.i_code))
+            .or_insert_with(|| {
+                chain.residues.push(Residue {
+                    name: atom.res_name.clone(),
+                    seq: atom.res_seq,
+                    i_code: atom.i_code,
+                    hetero,
+                    atoms: Vec::new(),
+                });
+                chain.residues.len() - 1
+            });
+        chain.residues[residue_index].atoms.push(atom.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pdbreader_single, reader::ReaderOptions};
+
+    const CONTENTS: &str = "\
+MODEL        1
+ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N
+ATOM      2  CA AALA A   1      20.987  18.149  27.890  0.40 11.85           C
+ATOM      3  CA BALA A   1      21.987  18.149  27.890  0.60 11.85           C
+ATOM      4  N   GLY A   1A     22.154  16.967  27.462  1.00 11.18           N
+ATOM      5  N   SER B   1      22.154  16.967  27.462  1.00 11.18           N
+TER       6      SER B   1
+HETATM    7  O   HOH A 101      15.123  12.456  30.789  1.00 25.50           O
+ENDMDL
+MODEL        2
+ATOM      1  N   ALA A   1      20.154  16.967  27.462  1.00 11.18           N
+ENDMDL
+";
+
+    #[test]
+    fn test_structure_hierarchy() {
+        let records = ReaderOptions::new().read(CONTENTS).unwrap().records;
+        let structure = Structure::new(&records);
+        assert_eq!(structure.models().len(), 2);
+        assert_eq!(structure.atoms().count(), 7);
+
+        let model = structure.model(1).unwrap();
+        let ids: Vec<_> = model.chains().iter().map(|chain| chain.id).collect();
+        assert_eq!(ids, vec![Some('A'), Some('B')]);
+
+        let chain = model.chain('A').unwrap();
+        assert_eq!(chain.residues().len(), 3);
+        let ala = chain.residue(1, None).unwrap();
+        assert_eq!(ala.atoms().len(), 3);
+        assert_eq!(ala.alt_locs(), vec!['A', 'B']);
+        assert_eq!(ala.atom("CA").unwrap().serial, 3);
+        assert_eq!(ala.conformer('A').count(), 2);
+        assert_eq!(chain.residue(1, 'A').unwrap().name, "GLY");
+        assert!(chain.residue(101, None).unwrap().hetero);
+
+        assert_eq!(structure.model(2).unwrap().atoms().count(), 1);
+    }
+
+    #[test]
+    fn test_to_records() {
+        let records = ReaderOptions::new().read(CONTENTS).unwrap().records;
+        let structure = Structure::new(&records);
+        let flattened = structure.to_records();
+        assert_eq!(flattened.len(), 14);
+        assert!(matches!(
+            flattened[0],
+            Record::Model(ModelRecord { serial: 1 })
+        ));
+        match &flattened[5] {
+            Record::Term(ter) => {
+                assert_eq!((ter.serial, ter.res_name.as_str()), (5, "GLY"));
+                assert_eq!((ter.chain_id, ter.res_seq, ter.i_code), ('A', 1, Some('A')));
+            }
+            other => panic!("expected TER, got {:?}", other),
+        }
+        assert!(matches!(flattened[6], Record::Hetatm(_)));
+        assert!(matches!(flattened[8], Record::Term(_)));
+        assert!(matches!(flattened[9], Record::Endmdl()));
+
+        // Atoms and TER records share one running sequence, so no serial is used twice.
+        let serials: Vec<u32> = flattened[1..9]
+            .iter()
+            .map(|record| match record {
+                Record::Atom(atom) | Record::Hetatm(atom) => atom.serial,
+                Record::Term(ter) => ter.serial,
+                other => panic!("unexpected record {:?}", other),
+            })
+            .collect();
+        assert_eq!(serials, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+        // Reading the records back gives the same structure apart from the serials.
+        let read = Structure::new(&flattened);
+        let serials: Vec<u32> = read.atoms().map(|atom| atom.serial).collect();
+        assert_eq!(serials, [1, 2, 3, 4, 6, 7, 1]);
+        let mut renumbered = structure.clone();
+        for (atom, serial) in renumbered.atoms_mut().zip(serials) {
+            atom.serial = serial;
+        }
+        assert_eq!(read, renumbered);
+
+        // A single model keeps its serial number unless it is the implied 1.
+        let second = Structure {
+            models: vec![structure.models[1].clone()],
+        };
+        let flattened = second.to_records();
+        assert!(matches!(
+            flattened[0],
+            Record::Model(ModelRecord { serial: 2 })
+        ));
+        assert_eq!(Structure::new(&flattened), second);
+        let first = Structure {
+            models: vec![structure.models[0].clone()],
+        };
+        assert!(matches!(first.to_records()[0], Record::Atom(_)));
+    }
+
+    #[test]
+    fn test_implicit_model() {
+        let records = pdbreader_single(
+            "ATOM      1  N   ALA     1      20.154  16.967  27.462  1.00 11.18           N",
+        );
+        let structure = Structure::new(&records);
+        assert_eq!(structure.models()[0].serial, 1);
+        assert!(structure.models()[0].chain(None).is_some());
+    }
+}
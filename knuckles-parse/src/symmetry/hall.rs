@@ -0,0 +1,296 @@
+//! Generation of symmetry operators from Hall symbols.
+//!
+//! A Hall symbol names a lattice, a few generating matrices and an optional change of
+//! basis, for example `-P 2ac 2n` or `P 31 2c (0 0 1)`. Operators are kept exact, with
+//! integer rotation matrices and translations in twelfths, while the group is closed.
+
+use crate::transform::Transform;
+
+/// Translations are stored in units of 1/`DENOMINATOR` of a lattice vector.
+const DENOMINATOR: i32 = 12;
+
+/// A symmetry operator with an integer rotation and a translation in twelfths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Operator {
+    rotation: [[i32; 3]; 3],
+    translation: [i32; 3],
+}
+
+impl Operator {
+    const IDENTITY: Operator = Operator {
+        rotation: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+        translation: [0; 3],
+    };
+
+    fn translation(translation: [i32; 3]) -> Operator {
+        Operator {
+            translation,
+            ..Operator::IDENTITY
+        }
+    }
+
+    fn apply_rotation(&self, vector: [i32; 3]) -> [i32; 3] {
+        std::array::from_fn(|i| (0..3).map(|j| self.rotation[i][j] * vector[j]).sum())
+    }
+
+    /// The operator applying `other` first and then `self`, with the translation reduced
+    /// into the unit cell.
+    fn compose(&self, other: &Operator) -> Operator {
+        let rotation = std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                (0..3)
+                    .map(|k| self.rotation[i][k] * other.rotation[k][j])
+                    .sum()
+            })
+        });
+        let rotated = self.apply_rotation(other.translation);
+        Operator {
+            rotation,
+            translation: std::array::from_fn(|i| {
+                (rotated[i] + self.translation[i]).rem_euclid(DENOMINATOR)
+            }),
+        }
+    }
+
+    /// The operator expressed after shifting the origin by `shift` twelfths.
+    fn change_basis(&self, shift: [i32; 3]) -> Operator {
+        let rotated = self.apply_rotation(shift);
+        Operator {
+            rotation: self.rotation,
+            translation: std::array::from_fn(|i| {
+                (self.translation[i] + shift[i] - rotated[i]).rem_euclid(DENOMINATOR)
+            }),
+        }
+    }
+
+    /// The operator as a transform of fractional coordinates.
+    pub(crate) fn to_transform(self) -> Transform {
+        Transform::new(
+            self.rotation.map(|row| row.map(f64::from)),
+            self.translation
+                .map(|component| f64::from(component) / f64::from(DENOMINATOR)),
+        )
+    }
+}
+
+/// The rotation of order `order` about `axis`, written in the frame of the preceding
+/// axis `reference` for the face- and body-diagonal axes.
+fn rotation(order: u32, axis: char, reference: usize) -> Option<[[i32; 3]; 3]> {
+    // Rotations about z; the x and y versions are obtained by cycling the coordinates.
+    let about_z = match order {
+        1 => [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+        2 => [[-1, 0, 0], [0, -1, 0], [0, 0, 1]],
+        3 => [[0, -1, 0], [1, -1, 0], [0, 0, 1]],
+        4 => [[0, -1, 0], [1, 0, 0], [0, 0, 1]],
+        6 => [[1, -1, 0], [1, 0, 0], [0, 0, 1]],
+        _ => return None,
+    };
+    let (matrix, index) = match (axis, order) {
+        ('x', _) => (about_z, 0),
+        ('y', _) => (about_z, 1),
+        ('z', _) => (about_z, 2),
+        ('\'', 2) => ([[0, -1, 0], [-1, 0, 0], [0, 0, -1]], reference),
+        ('"', 2) => ([[0, 1, 0], [1, 0, 0], [0, 0, -1]], reference),
+        ('*', 3) => return Some([[0, 0, 1], [1, 0, 0], [0, 1, 0]]),
+        _ => return None,
+    };
+    let axes = [(index + 1) % 3, (index + 2) % 3, index];
+    let mut rotated = [[0; 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            rotated[axes[i]][axes[j]] = matrix[i][j];
+        }
+    }
+    Some(rotated)
+}
+
+/// The centring translations of a lattice symbol, including the zero translation.
+fn centring(lattice: char) -> Option<&'static [[i32; 3]]> {
+    Some(match lattice {
+        'P' => &[[0, 0, 0]],
+        'A' => &[[0, 0, 0], [0, 6, 6]],
+        'B' => &[[0, 0, 0], [6, 0, 6]],
+        'C' => &[[0, 0, 0], [6, 6, 0]],
+        'I' => &[[0, 0, 0], [6, 6, 6]],
+        'R' => &[[0, 0, 0], [8, 4, 4], [4, 8, 8]],
+        'F' => &[[0, 0, 0], [0, 6, 6], [6, 0, 6], [6, 6, 0]],
+        _ => return None,
+    })
+}
+
+/// Parse one matrix symbol such as `2ac`, `-4bw` or `31`.
+///
+/// `position` is the index of the symbol after the lattice, and `previous` the order and
+/// axis of the symbol before it, which determine the default axis.
+fn matrix_symbol(
+    symbol: &str,
+    position: usize,
+    previous: Option<(u32, usize)>,
+) -> Option<(Operator, u32, usize)> {
+    let (improper, symbol) = match symbol.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, symbol),
+    };
+    let mut chars = symbol.chars().peekable();
+    let order = chars.next()?.to_digit(10)?;
+
+    let explicit = chars.next_if(|c| matches!(c, 'x' | 'y' | 'z' | '\'' | '"' | '*'));
+    let reference = previous.map_or(2, |(_, axis)| axis);
+    let axis = match (explicit, position, previous) {
+        (Some(axis), _, _) => axis,
+        (None, 0, _) => 'z',
+        (None, 1, Some((2 | 4, _))) if order == 2 => 'x',
+        (None, 1, Some((3 | 6, _))) if order == 2 => '\'',
+        (None, 2, _) if order == 3 => '*',
+        (None, _, _) if order == 1 => 'z',
+        _ => return None,
+    };
+    let axis_index = match axis {
+        'x' => 0,
+        'y' => 1,
+        'z' => 2,
+        _ => reference,
+    };
+    let mut matrix = rotation(order, axis, reference)?;
+    if improper {
+        matrix = matrix.map(|row| row.map(|value| -value));
+    }
+
+    let mut translation = [0; 3];
+    if let Some(screw) = chars.next_if(|c| c.is_ascii_digit()) {
+        let screw = screw.to_digit(10)? as i32;
+        if !matches!(axis, 'x' | 'y' | 'z') || screw >= order as i32 {
+            return None;
+        }
+        translation[axis_index] += screw * DENOMINATOR / order as i32;
+    }
+    for letter in chars {
+        let shift = match letter {
+            'a' => [6, 0, 0],
+            'b' => [0, 6, 0],
+            'c' => [0, 0, 6],
+            'n' => [6, 6, 6],
+            'u' => [3, 0, 0],
+            'v' => [0, 3, 0],
+            'w' => [0, 0, 3],
+            'd' => [3, 3, 3],
+            _ => return None,
+        };
+        for i in 0..3 {
+            translation[i] += shift[i];
+        }
+    }
+
+    let operator = Operator {
+        rotation: matrix,
+        translation: translation.map(|component| component.rem_euclid(DENOMINATOR)),
+    };
+    Some((operator, order, axis_index))
+}
+
+/// Generate every operator of the group named by a Hall symbol, the identity first.
+///
+/// Returns `None` if the symbol is malformed.
+pub(crate) fn operators(hall: &str) -> Option<Vec<Operator>> {
+    let (symbols, basis) = match hall.split_once('(') {
+        Some((symbols, basis)) => (symbols, Some(basis.strip_suffix(')')?)),
+        None => (hall, None),
+    };
+    let mut tokens = symbols.split_whitespace();
+    let lattice = tokens.next()?;
+    let (centrosymmetric, lattice) = match lattice.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, lattice),
+    };
+    let mut lattice_chars = lattice.chars();
+    let centring = centring(lattice_chars.next()?)?;
+    if lattice_chars.next().is_some() {
+        return None;
+    }
+
+    let mut generators: Vec<Operator> = centring
+        .iter()
+        .map(|&translation| Operator::translation(translation))
+        .collect();
+    if centrosymmetric {
+        generators.push(Operator {
+            rotation: [[-1, 0, 0], [0, -1, 0], [0, 0, -1]],
+            translation: [0; 3],
+        });
+    }
+    let mut previous = None;
+    for (position, symbol) in tokens.enumerate() {
+        let (operator, order, axis) = matrix_symbol(symbol, position, previous)?;
+        generators.push(operator);
+        previous = Some((order, axis));
+    }
+
+    if let Some(basis) = basis {
+        let shift: Vec<i32> = basis
+            .split_whitespace()
+            .map(|component| component.parse().ok())
+            .collect::<Option<_>>()?;
+        let shift: [i32; 3] = shift.try_into().ok()?;
+        for generator in &mut generators {
+            *generator = generator.change_basis(shift);
+        }
+    }
+
+    let mut group = vec![Operator::IDENTITY];
+    let mut next = 0;
+    while next < group.len() {
+        let current = group[next];
+        for generator in &generators {
+            let product = generator.compose(&current);
+            if !group.contains(&product) {
+                group.push(product);
+            }
+        }
+        next += 1;
+    }
+    Some(group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operator(rotation: [[i32; 3]; 3], translation: [i32; 3]) -> Operator {
+        Operator {
+            rotation,
+            translation,
+        }
+    }
+
+    #[test]
+    fn test_operators() {
+        let group = operators("P 2ac 2ab").unwrap();
+        assert_eq!(group.len(), 4);
+        assert_eq!(group[0], Operator::IDENTITY);
+        for expected in [
+            operator([[-1, 0, 0], [0, -1, 0], [0, 0, 1]], [6, 0, 6]),
+            operator([[1, 0, 0], [0, -1, 0], [0, 0, -1]], [6, 6, 0]),
+            operator([[-1, 0, 0], [0, 1, 0], [0, 0, -1]], [0, 6, 6]),
+        ] {
+            assert!(group.contains(&expected), "{:?} missing", expected);
+        }
+
+        // P 31 1 2 needs the origin shift to place the twofold axes at z = 1/3.
+        let group = operators("P 31 2c (0 0 1)").unwrap();
+        assert_eq!(group.len(), 6);
+        assert!(group.contains(&operator([[0, -1, 0], [-1, 0, 0], [0, 0, -1]], [0, 0, 8])));
+        assert!(group.contains(&operator([[-1, 1, 0], [0, 1, 0], [0, 0, -1]], [0, 0, 4])));
+
+        assert_eq!(operators("-F 4 2 3").unwrap().len(), 192);
+        assert_eq!(operators("P 3*").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_operators_malformed() {
+        assert!(operators("Q 2").is_none());
+        assert!(operators("P 5").is_none());
+        assert!(operators("P 2 2 2 2").is_none());
+        assert!(operators("P 2q").is_none());
+        assert!(operators("P 2 (0 0)").is_none());
+    }
+}
@@ -0,0 +1,547 @@
+//! Space groups and crystallographic symmetry mates.
+//!
+//! [`SpaceGroup`] looks up the Hermann–Mauguin symbol of a CRYST1 record, such as
+//! `P 21 21 21`, in a table of the 230 space groups and generates its symmetry operators
+//! in fractional coordinates. Together with the [`UnitCell`] these place copies of the
+//! asymmetric unit around the deposited coordinates:
+//!
+//! - [`unit_cell_mates`] gives one copy per operator, moved by whole lattice vectors so
+//!   that its centre lies in the unit cell at the origin;
+//! - [`contact_mates`] gives every copy, over all operators and lattice translations,
+//!   with an atom within a distance of the asymmetric unit. These are the neighbours
+//!   that form crystal packing contacts.
+//!
+//! Only ATOM and HETATM records are copied, so a single model should be passed in for
+//! files with several.
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::{reader::ReaderOptions, symmetry::{self, SpaceGroup}, unit_cell::UnitCell};
+//!
+//! let contents = "\
+//! CRYST1   10.000   12.000   14.000  90.00  90.00  90.00 P 21 21 21    4
+//! ATOM      1  CA  GLY A   1       1.000   1.000   1.000  1.00  0.00           C
+//! ";
+//! let records = ReaderOptions::new().read(contents).unwrap().records;
+//! let cell = UnitCell::from_records(&records).unwrap();
+//! let group = SpaceGroup::from_records(&records).unwrap();
+//!
+//! assert_eq!(group.number, 19);
+//! assert_eq!(group.operators.len(), 4);
+//!
+//! let mates = symmetry::unit_cell_mates(&records, &cell, &group);
+//! assert_eq!(mates.len(), 4);
+//! assert_eq!(mates[1].code().as_deref(), Some("2565"));
+//! ```
+
+mod hall;
+mod table;
+
+use std::collections::HashMap;
+
+use crate::records::{atom::AtomRecord, crystal::CrystalRecord, Record};
+use crate::transform::{transform_atoms, Transform};
+use crate::unit_cell::UnitCell;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A space group and its symmetry operators.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpaceGroup {
+    /// Number in the International Tables, from 1 to 230
+    pub number: u16,
+    /// Full Hermann–Mauguin symbol, with a `:R` suffix for rhombohedral axes
+    pub symbol: String,
+    /// Hall symbol the operators were generated from
+    pub hall: String,
+    /// Symmetry operators acting on fractional coordinates, the identity first
+    pub operators: Vec<Transform>,
+}
+
+impl SpaceGroup {
+    /// Look up a space group by its Hermann–Mauguin symbol.
+    ///
+    /// Whitespace is ignored, so `P212121` and `P 21 21 21` are the same group. Short
+    /// monoclinic symbols such as `P 21` are read as unique axis b, and `H` symbols as
+    /// the hexagonal setting of the rhombohedral groups. Returns `None` for unknown
+    /// symbols, which include the rarer non-standard settings.
+    pub fn from_symbol(symbol: &str) -> Option<SpaceGroup> {
+        let symbol = symbol.trim();
+        let symbol = symbol.strip_suffix(":H").unwrap_or(symbol);
+        let hexagonal;
+        let symbol = match symbol.strip_prefix('H') {
+            Some(rest) => {
+                hexagonal = format!("R{}", rest);
+                hexagonal.as_str()
+            }
+            None => symbol,
+        };
+        let key = compact(symbol);
+        let entry = table::SPACE_GROUPS
+            .iter()
+            .find(|(_, name, _)| compact(name) == key)
+            .or_else(|| {
+                // Short monoclinic symbols give only the unique axis, assumed to be b.
+                let mut parts = symbol.split_whitespace();
+                let (Some(lattice), Some(axis), None) = (parts.next(), parts.next(), parts.next())
+                else {
+                    return None;
+                };
+                let key = compact(&format!("{} 1 {} 1", lattice, axis));
+                table::SPACE_GROUPS
+                    .iter()
+                    .find(|(number, name, _)| *number <= 15 && compact(name) == key)
+            })?;
+        SpaceGroup::from_entry(entry)
+    }
+
+    /// Look up a space group by its number, in the standard setting.
+    pub fn from_number(number: u16) -> Option<SpaceGroup> {
+        table::SPACE_GROUPS
+            .iter()
+            .find(|(entry, _, _)| *entry == number)
+            .and_then(SpaceGroup::from_entry)
+    }
+
+    /// Look up the space group of a CRYST1 record.
+    ///
+    /// Rhombohedral groups are read on rhombohedral axes when the cell has equal edges
+    /// and angles other than the 90°, 90°, 120° of the hexagonal setting.
+    pub fn from_crystal(crystal: &CrystalRecord) -> Option<SpaceGroup> {
+        let rhombohedral_axes = crystal.space_group.trim_start().starts_with('R')
+            && (crystal.gamma - 120.0).abs() > 0.01
+            && (crystal.a - crystal.b).abs() < 1e-3
+            && (crystal.a - crystal.c).abs() < 1e-3
+            && (crystal.alpha - crystal.gamma).abs() < 0.01
+            && (crystal.beta - crystal.gamma).abs() < 0.01;
+        if rhombohedral_axes {
+            SpaceGroup::from_symbol(&format!("{}:R", crystal.space_group.trim()))
+        } else {
+            SpaceGroup::from_symbol(&crystal.space_group)
+        }
+    }
+
+    /// Look up the space group of the first CRYST1 record in `records`.
+    pub fn from_records(records: &[Record]) -> Option<SpaceGroup> {
+        records.iter().find_map(|record| match record {
+            Record::Crystal(crystal) => SpaceGroup::from_crystal(crystal),
+            _ => None,
+        })
+    }
+
+    fn from_entry(&(number, symbol, hall): &(u16, &str, &str)) -> Option<SpaceGroup> {
+        let operators = hall::operators(hall)?
+            .into_iter()
+            .map(hall::Operator::to_transform)
+            .collect();
+        Some(SpaceGroup {
+            number,
+            symbol: symbol.to_string(),
+            hall: hall.to_string(),
+            operators,
+        })
+    }
+}
+
+/// The symbol with all whitespace removed.
+fn compact(symbol: &str) -> String {
+    symbol.split_whitespace().collect()
+}
+
+/// A copy of the asymmetric unit placed by a symmetry operator and a lattice translation.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SymmetryMate {
+    /// Index into [`SpaceGroup::operators`]
+    pub operator: usize,
+    /// Lattice translation applied after the operator, in unit cells along a, b and c
+    pub shift: [i32; 3],
+    /// The complete transform in Cartesian coordinates
+    pub transform: Transform,
+    /// ATOM and HETATM records of the asymmetric unit with transformed coordinates
+    pub records: Vec<Record>,
+}
+
+impl SymmetryMate {
+    /// The symmetry code used in REMARK 290 and validation reports: the operator number
+    /// counted from 1 followed by the translations offset by 5, so `1555` is the
+    /// asymmetric unit itself.
+    ///
+    /// Each translation takes a single digit, so the code only exists for shifts from -5
+    /// to 4; `None` is returned for mates further away.
+    pub fn code(&self) -> Option<String> {
+        let mut code = (self.operator + 1).to_string();
+        for shift in self.shift {
+            code.push(char::from_digit(u32::try_from(shift + 5).ok()?, 10)?);
+        }
+        Some(code)
+    }
+}
+
+/// The coordinates of the ATOM and HETATM records in `records`.
+fn atoms(records: &[Record]) -> impl Iterator<Item = &AtomRecord> {
+    records.iter().filter_map(|record| match record {
+        Record::Atom(atom) | Record::Hetatm(atom) => Some(atom),
+        _ => None,
+    })
+}
+
+fn position(atom: &AtomRecord) -> [f64; 3] {
+    [atom.x, atom.y, atom.z].map(f64::from)
+}
+
+/// The centre of a set of points and the largest distance of a point from it.
+fn bounds(points: &[[f64; 3]]) -> ([f64; 3], f64) {
+    let count = points.len().max(1) as f64;
+    let centre: [f64; 3] =
+        std::array::from_fn(|i| points.iter().map(|point| point[i]).sum::<f64>() / count);
+    let radius = points
+        .iter()
+        .map(|point| distance(*point, centre))
+        .fold(0.0, f64::max);
+    (centre, radius)
+}
+
+fn distance(a: [f64; 3], b: [f64; 3]) -> f64 {
+    (0..3).map(|i| (a[i] - b[i]).powi(2)).sum::<f64>().sqrt()
+}
+
+/// Build the mate for a fractional operator followed by a lattice translation.
+fn mate(
+    records: &[Record],
+    cell: &UnitCell,
+    group: &SpaceGroup,
+    operator: usize,
+    shift: [i32; 3],
+) -> SymmetryMate {
+    let fractional = group.operators[operator];
+    let shifted = Transform::new(
+        fractional.matrix,
+        std::array::from_fn(|i| fractional.vector[i] + f64::from(shift[i])),
+    );
+    let transform = cell
+        .fractionalization()
+        .then(&shifted)
+        .then(&cell.orthogonalization());
+    let records = transform_atoms(records, &transform);
+    SymmetryMate {
+        operator,
+        shift,
+        transform,
+        records,
+    }
+}
+
+/// One copy of the asymmetric unit per symmetry operator, together filling the unit cell.
+///
+/// Each copy is moved by whole lattice vectors so that the centre of its atoms has
+/// fractional coordinates in `[0, 1)`. The first mate is the asymmetric unit itself,
+/// possibly translated.
+pub fn unit_cell_mates(
+    records: &[Record],
+    cell: &UnitCell,
+    group: &SpaceGroup,
+) -> Vec<SymmetryMate> {
+    let points: Vec<[f64; 3]> = atoms(records).map(position).collect();
+    let (centre, _) = bounds(&points);
+    let centre = cell.to_fractional(centre);
+    (0..group.operators.len())
+        .map(|operator| {
+            let image = group.operators[operator].apply(centre);
+            let shift = image.map(|component| -component.floor() as i32);
+            mate(records, cell, group, operator, shift)
+        })
+        .collect()
+}
+
+/// Every copy of the asymmetric unit with an atom within `radius` Ångströms of an atom of
+/// the asymmetric unit itself, excluding the identity.
+///
+/// All operators are combined with the lattice translations that can bring a copy within
+/// reach. Mates are ordered by operator and then by translation.
+pub fn contact_mates(
+    records: &[Record],
+    cell: &UnitCell,
+    group: &SpaceGroup,
+    radius: f64,
+) -> Vec<SymmetryMate> {
+    let points: Vec<[f64; 3]> = atoms(records).map(position).collect();
+    if points.is_empty() || radius <= 0.0 {
+        return Vec::new();
+    }
+    let (centre, extent) = bounds(&points);
+    let reach = 2.0 * extent + radius;
+
+    // Hash the asymmetric unit on a grid of `radius` so that each query only has to
+    // visit the 27 neighbouring cells.
+    let key = |point: [f64; 3]| point.map(|component| (component / radius).floor() as i64);
+    let mut grid: HashMap<[i64; 3], Vec<[f64; 3]>> = HashMap::new();
+    for point in &points {
+        grid.entry(key(*point)).or_default().push(*point);
+    }
+    let near = |point: [f64; 3]| {
+        let [x, y, z] = key(point);
+        (x - 1..=x + 1).any(|i| {
+            (y - 1..=y + 1).any(|j| {
+                (z - 1..=z + 1).any(|k| {
+                    grid.get(&[i, j, k]).is_some_and(|cell| {
+                        cell.iter().any(|other| distance(point, *other) <= radius)
+                    })
+                })
+            })
+        })
+    };
+
+    let fractionalization = cell.fractionalization();
+    let orthogonalization = cell.orthogonalization();
+    let fractional_centre = fractionalization.apply(centre);
+    // A sphere of radius `reach` spans this many cells along each axis.
+    let span: [f64; 3] = std::array::from_fn(|i| {
+        reach
+            * fractionalization.matrix[i]
+                .iter()
+                .map(|value| value * value)
+                .sum::<f64>()
+                .sqrt()
+    });
+
+    let mut mates = Vec::new();
+    for (operator, transform) in group.operators.iter().enumerate() {
+        let image = transform.apply(fractional_centre);
+        let range = |i: usize| {
+            let offset = fractional_centre[i] - image[i];
+            (offset - span[i]).floor() as i32..=(offset + span[i]).ceil() as i32
+        };
+        for a in range(0) {
+            for b in range(1) {
+                for c in range(2) {
+                    let shift = [a, b, c];
+                    if operator == 0 && shift == [0; 3] {
+                        continue;
+                    }
+                    let moved: [f64; 3] = std::array::from_fn(|i| image[i] + f64::from(shift[i]));
+                    if distance(orthogonalization.apply(moved), centre) > reach {
+                        continue;
+                    }
+                    let candidate = mate(records, cell, group, operator, shift);
+                    if atoms(&candidate.records).any(|atom| near(position(atom))) {
+                        mates.push(candidate);
+                    }
+                }
+            }
+        }
+    }
+    mates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The order of the point group of each crystal class, by the last group number in
+    /// the class.
+    const POINT_GROUP_ORDERS: [(u16, usize); 18] = [
+        (1, 1),
+        (2, 2),
+        (9, 2),
+        (15, 4),
+        (46, 4),
+        (74, 8),
+        (82, 4),
+        (122, 8),
+        (142, 16),
+        (146, 3),
+        (161, 6),
+        (167, 12),
+        (174, 6),
+        (190, 12),
+        (194, 24),
+        (199, 12),
+        (220, 24),
+        (230, 48),
+    ];
+
+    fn records(lines: &[&str]) -> Vec<Record> {
+        lines
+            .iter()
+            .map(|line| Record::try_from(*line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_operator_counts() {
+        for (number, symbol, hall) in table::SPACE_GROUPS {
+            let group = SpaceGroup::from_symbol(symbol)
+                .unwrap_or_else(|| panic!("{} ({}) does not parse", symbol, hall));
+            let order = POINT_GROUP_ORDERS
+                .iter()
+                .find(|(last, _)| number <= last)
+                .unwrap()
+                .1;
+            let centring = match symbol.chars().next().unwrap() {
+                'A' | 'B' | 'C' | 'I' => 2,
+                'R' if !symbol.ends_with(":R") => 3,
+                'F' => 4,
+                _ => 1,
+            };
+            assert_eq!(group.operators.len(), order * centring, "{}", symbol);
+            assert!(group.operators[0].is_identity(0.0));
+        }
+        for number in 1..=230 {
+            assert_eq!(SpaceGroup::from_number(number).unwrap().number, number);
+        }
+        assert!(SpaceGroup::from_number(231).is_none());
+    }
+
+    #[test]
+    fn test_from_symbol() {
+        let group = SpaceGroup::from_symbol("P 21 21 21").unwrap();
+        let expected = [
+            (
+                [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+                [0.0, 0.0, 0.0],
+            ),
+            (
+                [[-1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0]],
+                [0.5, 0.0, 0.5],
+            ),
+            (
+                [[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, -1.0]],
+                [0.5, 0.5, 0.0],
+            ),
+            (
+                [[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]],
+                [0.0, 0.5, 0.5],
+            ),
+        ];
+        for (matrix, vector) in expected {
+            assert!(group.operators.contains(&Transform::new(matrix, vector)));
+        }
+
+        let group = SpaceGroup::from_symbol("P 41 21 2").unwrap();
+        assert_eq!(group.number, 92);
+        // -y+1/2, x+1/2, z+1/4
+        let screw = Transform::new(
+            [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+            [0.5, 0.5, 0.25],
+        );
+        assert!(group.operators.contains(&screw));
+
+        let group = SpaceGroup::from_symbol("P 31 2 1").unwrap();
+        // y, x, -z
+        let twofold = Transform::new(
+            [[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, -1.0]],
+            [0.0; 3],
+        );
+        assert!(group.operators.contains(&twofold));
+
+        // Permuted axes: -x, y+1/2, -z+1/2 is a screw axis along b.
+        let group = SpaceGroup::from_symbol("P 2 21 21").unwrap();
+        assert_eq!(group.number, 18);
+        let screw = Transform::new(
+            [[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]],
+            [0.0, 0.5, 0.5],
+        );
+        assert!(group.operators.contains(&screw));
+        // -x, -y, z+1/2 is a screw axis along c.
+        let group = SpaceGroup::from_symbol("P 1 1 21").unwrap();
+        let screw = Transform::new(
+            [[-1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0]],
+            [0.0, 0.0, 0.5],
+        );
+        assert_eq!((group.number, group.operators[1]), (4, screw));
+
+        assert_eq!(SpaceGroup::from_symbol("P212121").unwrap().number, 19);
+        assert_eq!(SpaceGroup::from_symbol("C 2").unwrap().symbol, "C 1 2 1");
+        assert_eq!(SpaceGroup::from_symbol("P 21").unwrap().number, 4);
+        assert_eq!(SpaceGroup::from_symbol("H 3 2").unwrap().symbol, "R 3 2");
+        assert_eq!(SpaceGroup::from_symbol("R 3 :H").unwrap().number, 146);
+        assert!(SpaceGroup::from_symbol("P 7").is_none());
+    }
+
+    #[test]
+    fn test_from_crystal() {
+        let hexagonal =
+            records(&["CRYST1   80.000   80.000   60.000  90.00  90.00 120.00 R 3          9"]);
+        let group = SpaceGroup::from_records(&hexagonal).unwrap();
+        assert_eq!((group.symbol.as_str(), group.operators.len()), ("R 3", 9));
+
+        let rhombohedral =
+            records(&["CRYST1   50.000   50.000   50.000  80.00  80.00  80.00 R 3          3"]);
+        let group = SpaceGroup::from_records(&rhombohedral).unwrap();
+        assert_eq!((group.symbol.as_str(), group.operators.len()), ("R 3:R", 3));
+    }
+
+    #[test]
+    fn test_unit_cell_mates() {
+        let records = records(&[
+            "CRYST1   10.000   12.000   14.000  90.00  90.00  90.00 P 21 21 21    4",
+            "ATOM      1  N   GLY A   1       1.000   2.000   3.000  1.00  0.00           N",
+            "ATOM      2  CA  GLY A   1       2.000   2.000   3.000  1.00  0.00           C",
+            "TER       3      GLY A   1",
+        ]);
+        let cell = UnitCell::from_records(&records).unwrap();
+        let group = SpaceGroup::from_records(&records).unwrap();
+        let mates = unit_cell_mates(&records, &cell, &group);
+        assert_eq!(mates.len(), 4);
+        assert_eq!(mates[0].code().as_deref(), Some("1555"));
+        for mate in &mates {
+            assert_eq!(mate.records.len(), 2);
+            let atoms: Vec<[f64; 3]> = atoms(&mate.records).map(position).collect();
+            let (centre, _) = bounds(&atoms);
+            assert!(cell
+                .to_fractional(centre)
+                .iter()
+                .all(|component| (0.0..1.0).contains(component)));
+        }
+        // -x+1/2, -y, z+1/2 moved up one cell along b.
+        let Record::Atom(atom) = &mates[1].records[0] else {
+            panic!("expected an atom");
+        };
+        assert!((atom.x - 4.0).abs() < 1e-4);
+        assert!((atom.y - 10.0).abs() < 1e-4);
+        assert!((atom.z - 10.0).abs() < 1e-4);
+        assert_eq!(mates[1].shift, [0, 1, 0]);
+    }
+
+    #[test]
+    fn test_contact_mates() {
+        let records = records(&[
+            "CRYST1   10.000   10.000   30.000  90.00  90.00  90.00 P 1           1",
+            "ATOM      1  CA  GLY A   1       1.000   5.000   5.000  1.00  0.00           C",
+            "ATOM      2  CA  GLY A   2       8.000   5.000   5.000  1.00  0.00           C",
+        ]);
+        let cell = UnitCell::from_records(&records).unwrap();
+        let group = SpaceGroup::from_records(&records).unwrap();
+
+        // Neighbours along a bring an atom within 3 Å; those along b and c do not.
+        let mates = contact_mates(&records, &cell, &group, 3.5);
+        let codes: Vec<String> = mates.iter().map(|mate| mate.code().unwrap()).collect();
+        assert_eq!(codes, ["1455", "1655"]);
+
+        // Adding b and the diagonals of the ab face.
+        let mates = contact_mates(&records, &cell, &group, 10.5);
+        assert_eq!(mates.len(), 8);
+        assert!(contact_mates(&records, &cell, &group, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_code() {
+        let code = |operator, shift| {
+            SymmetryMate {
+                operator,
+                shift,
+                transform: Transform::IDENTITY,
+                records: Vec::new(),
+            }
+            .code()
+        };
+        assert_eq!(code(0, [-5, 0, 4]).as_deref(), Some("1059"));
+        assert_eq!(code(11, [0, 0, 0]).as_deref(), Some("12555"));
+        assert_eq!(code(0, [5, 0, 0]), None);
+        assert_eq!(code(0, [0, -6, 0]), None);
+    }
+}
@@ -0,0 +1,343 @@
+//! Entry-level metadata assembled from the title section of a PDB file.
+//!
+//! Most title-section records span several lines, with a continuation number on every
+//! line after the first. A [`TitleSection`] joins those lines back together and splits
+//! the resulting text into its lists: COMPND and SOURCE become one key-value map per
+//! `MOL_ID`, KEYWDS and AUTHOR are split on commas and EXPDTA and MDLTYP on semicolons.
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::{reader::ReaderOptions, title::TitleSection};
+//!
+//! let contents = "\
+//! HEADER    OXYGEN TRANSPORT                        05-SEP-97   1A3N
+//! COMPND    MOL_ID: 1;
+//! COMPND   2 MOLECULE: HEMOGLOBIN (ALPHA CHAIN);
+//! COMPND   3 CHAIN: A, C
+//! SOURCE    MOL_ID: 1;
+//! SOURCE   2 ORGANISM_SCIENTIFIC: HOMO SAPIENS;
+//! SOURCE   3 ORGANISM_TAXID: 9606
+//! EXPDTA    X-RAY DIFFRACTION
+//! ";
+//! let report = ReaderOptions::new().read(contents).unwrap();
+//! let title = TitleSection::new(&report.records);
+//!
+//! assert_eq!(title.id_code, "1A3N");
+//! assert_eq!(title.compounds[0]["MOLECULE"], "HEMOGLOBIN (ALPHA CHAIN)");
+//! assert_eq!(title.organisms().collect::<Vec<_>>(), vec!["HOMO SAPIENS"]);
+//! assert_eq!(title.experimental_methods, vec!["X-RAY DIFFRACTION"]);
+//! ```
+
+use std::collections::HashMap;
+
+use crate::records::Record;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The title section of a PDB file, with continuation lines merged.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TitleSection {
+    /// Classification of the molecule(s), from HEADER
+    pub classification: String,
+    /// Deposition date in `DD-MMM-YY` format, from HEADER
+    pub dep_date: String,
+    /// Four-character PDB identifier, from HEADER
+    pub id_code: String,
+    /// Date the entry was withdrawn, if it is obsolete
+    pub obsolete_date: Option<String>,
+    /// Entries replacing this one, if it is obsolete
+    pub replaced_by: Vec<String>,
+    /// Entries superseded by this one
+    pub superseded: Vec<String>,
+    /// Title of the experiment or analysis
+    pub title: String,
+    /// Entries making up the same large structure
+    pub split: Vec<String>,
+    /// Warning about severe errors in the entry
+    pub caveat: Option<String>,
+    /// COMPND specifications, one map per `MOL_ID`
+    pub compounds: Vec<HashMap<String, String>>,
+    /// SOURCE specifications, one map per `MOL_ID`
+    pub sources: Vec<HashMap<String, String>>,
+    /// Keywords describing the entry
+    pub keywords: Vec<String>,
+    /// Experimental techniques used to determine the structure
+    pub experimental_methods: Vec<String>,
+    /// Model type annotations (e.g., "CA ATOMS ONLY, CHAIN A")
+    pub model_types: Vec<String>,
+    /// Authors of the entry
+    pub authors: Vec<String>,
+    /// Revision history, one entry per modification number
+    pub revisions: Vec<Revision>,
+}
+
+/// A single modification of an entry, assembled from its REVDAT lines.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Revision {
+    /// Modification number
+    pub mod_num: u32,
+    /// Date of the modification in `DD-MMM-YY` format
+    pub mod_date: String,
+    /// Identifier of the modification
+    pub mod_id: String,
+    /// 0 for the initial release, 1 for later modifications
+    pub mod_type: u8,
+    /// Names of the records changed by the modification
+    pub records: Vec<String>,
+}
+
+impl TitleSection {
+    /// Build the title section from parsed records.
+    ///
+    /// Records are taken in file order and every record outside of the title section is
+    /// ignored. Continuation lines are joined with a single space, or with no space after
+    /// a line ending in a hyphen.
+    pub fn new(records: &[Record]) -> TitleSection {
+        let mut section = TitleSection::default();
+        let mut text = TextLines::default();
+        for record in records {
+            match record {
+                Record::Header(header) => {
+                    section.classification = header.classification.clone();
+                    section.dep_date = header.dep_date.clone();
+                    section.id_code = header.id_code.clone();
+                }
+                Record::Obslte(obslte) => {
+                    section
+                        .obsolete_date
+                        .get_or_insert_with(|| obslte.date.clone());
+                    section
+                        .replaced_by
+                        .extend(obslte.replaced_by.iter().cloned());
+                }
+                Record::Sprsde(sprsde) => {
+                    section.superseded.extend(sprsde.superseded.iter().cloned());
+                }
+                Record::Split(split) => section.split.extend(split.id_codes.iter().cloned()),
+                Record::Title(title) => text.title.push(&title.text),
+                Record::Caveat(caveat) => text.caveat.push(&caveat.comment),
+                Record::Compnd(compnd) => text.compnd.push(&compnd.text),
+                Record::Source(source) => text.source.push(&source.text),
+                Record::Keywds(keywds) => text.keywds.push(&keywds.text),
+                Record::Expdta(expdta) => text.expdta.push(&expdta.text),
+                Record::Mdltyp(mdltyp) => text.mdltyp.push(&mdltyp.text),
+                Record::Author(author) => text.author.push(&author.text),
+                Record::Revdat(revdat) => match section.revisions.last_mut() {
+                    Some(revision) if revision.mod_num == revdat.mod_num => {
+                        revision.records.extend(revdat.records.iter().cloned());
+                    }
+                    _ => section.revisions.push(Revision {
+                        mod_num: revdat.mod_num,
+                        mod_date: revdat.mod_date.clone(),
+                        mod_id: revdat.mod_id.clone(),
+                        mod_type: revdat.mod_type,
+                        records: revdat.records.clone(),
+                    }),
+                },
+                _ => {}
+            }
+        }
+
+        section.title = join_continued(&text.title);
+        let caveat = join_continued(&text.caveat);
+        section.caveat = (!caveat.is_empty()).then_some(caveat);
+        section.compounds = parse_specifications(&join_continued(&text.compnd));
+        section.sources = parse_specifications(&join_continued(&text.source));
+        section.keywords = split_list(&join_continued(&text.keywds), ',');
+        section.experimental_methods = split_list(&join_continued(&text.expdta), ';');
+        section.model_types = split_list(&join_continued(&text.mdltyp), ';');
+        section.authors = split_list(&join_continued(&text.author), ',');
+        section
+    }
+
+    /// Iterate over the `ORGANISM_SCIENTIFIC` value of every SOURCE molecule that has one.
+    pub fn organisms(&self) -> impl Iterator<Item = &str> {
+        self.sources
+            .iter()
+            .filter_map(|source| source.get("ORGANISM_SCIENTIFIC"))
+            .map(String::as_str)
+    }
+
+    /// Whether the entry has been withdrawn by an OBSLTE record.
+    pub fn is_obsolete(&self) -> bool {
+        self.obsolete_date.is_some()
+    }
+}
+
+impl From<&[Record]> for TitleSection {
+    fn from(records: &[Record]) -> Self {
+        TitleSection::new(records)
+    }
+}
+
+/// The text of each multi-line record, collected in file order.
+#[derive(Default)]
+struct TextLines<'a> {
+    title: Vec<&'a str>,
+    caveat: Vec<&'a str>,
+    compnd: Vec<&'a str>,
+    source: Vec<&'a str>,
+    keywds: Vec<&'a str>,
+    expdta: Vec<&'a str>,
+    mdltyp: Vec<&'a str>,
+    author: Vec<&'a str>,
+}
+
+/// Join continuation lines with a space, except after a line ending in a hyphen.
+pub(crate) fn join_continued(lines: &[&str]) -> String {
+    let mut text = String::new();
+    for line in lines.iter().map(|line| line.trim()) {
+        if line.is_empty() {
+            continue;
+        }
+        if !text.is_empty() && !text.ends_with('-') {
+            text.push(' ');
+        }
+        text.push_str(line);
+    }
+    text
+}
+
+/// Split a delimited list, dropping empty items.
+fn split_list(text: &str, delimiter: char) -> Vec<String> {
+    text.split(delimiter)
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Parse a COMPND or SOURCE specification list of `TOKEN: value;` pairs into one map per
+/// `MOL_ID`.
+///
+/// Pieces without a colon are treated as part of the previous value, which keeps values
+/// containing a semicolon intact. Text before the first token is ignored.
+fn parse_specifications(text: &str) -> Vec<HashMap<String, String>> {
+    let mut molecules: Vec<HashMap<String, String>> = Vec::new();
+    let mut last_key: Option<String> = None;
+    for piece in text
+        .split(';')
+        .map(str::trim)
+        .filter(|piece| !piece.is_empty())
+    {
+        match piece.split_once(':') {
+            Some((key, value)) if is_token(key) => {
+                let key = key.trim().to_string();
+                if key == "MOL_ID" || molecules.is_empty() {
+                    molecules.push(HashMap::new());
+                }
+                if let Some(molecule) = molecules.last_mut() {
+                    molecule.insert(key.clone(), value.trim().to_string());
+                }
+                last_key = Some(key);
+            }
+            _ => {
+                if let (Some(molecule), Some(key)) = (molecules.last_mut(), &last_key) {
+                    if let Some(value) = molecule.get_mut(key) {
+                        value.push_str("; ");
+                        value.push_str(piece);
+                    }
+                }
+            }
+        }
+    }
+    molecules
+}
+
+/// Whether `key` looks like a specification token such as `MOL_ID` or `EC`.
+fn is_token(key: &str) -> bool {
+    let key = key.trim();
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records(contents: &str) -> Vec<Record> {
+        contents
+            .lines()
+            .map(|line| Record::try_from(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_title_section() {
+        let title = TitleSection::new(&records(
+            "\
+HEADER    HYDROLASE                               12-JUN-98   1BXO
+OBSLTE     31-JAN-00 1BXO      1DEF 1GHI
+TITLE     CRYSTAL STRUCTURE OF A BETA-
+TITLE    2 LACTAMASE MUTANT
+CAVEAT     1BXO    CHIRALITY ERRORS AT
+CAVEAT   2 1BXO    RESIDUE 12
+COMPND    MOL_ID: 1;
+COMPND   2 MOLECULE: BETA-LACTAMASE;
+COMPND   3 CHAIN: A, B;
+COMPND   4 EC: 3.5.2.6;
+COMPND   5 MOL_ID: 2;
+COMPND   6 MOLECULE: INHIBITOR
+SOURCE    MOL_ID: 1;
+SOURCE   2 ORGANISM_SCIENTIFIC: ESCHERICHIA COLI;
+SOURCE   3 ORGANISM_TAXID: 562;
+SOURCE   4 MOL_ID: 2;
+SOURCE   5 SYNTHETIC: YES
+KEYWDS    HYDROLASE, ANTIBIOTIC RESISTANCE,
+KEYWDS   2 BETA-LACTAMASE
+EXPDTA    X-RAY DIFFRACTION; NEUTRON DIFFRACTION
+AUTHOR    A.B.SMITH,C.D.JONES
+REVDAT   2   24-FEB-09 1BXO    1       VERSN
+REVDAT   1   12-JUN-98 1BXO    0
+REVDAT   1 1 12-JUN-98 1BXO    0       JRNL",
+        ));
+        assert_eq!(title.classification, "HYDROLASE");
+        assert_eq!(title.id_code, "1BXO");
+        assert!(title.is_obsolete());
+        assert_eq!(title.replaced_by, vec!["1DEF", "1GHI"]);
+        assert_eq!(title.title, "CRYSTAL STRUCTURE OF A BETA-LACTAMASE MUTANT");
+        assert_eq!(
+            title.caveat.as_deref(),
+            Some("CHIRALITY ERRORS AT RESIDUE 12")
+        );
+        assert_eq!(title.compounds.len(), 2);
+        assert_eq!(title.compounds[0]["MOLECULE"], "BETA-LACTAMASE");
+        assert_eq!(title.compounds[0]["CHAIN"], "A, B");
+        assert_eq!(title.compounds[0]["EC"], "3.5.2.6");
+        assert_eq!(title.compounds[1]["MOL_ID"], "2");
+        assert_eq!(title.sources[1]["SYNTHETIC"], "YES");
+        assert_eq!(
+            title.organisms().collect::<Vec<_>>(),
+            vec!["ESCHERICHIA COLI"]
+        );
+        assert_eq!(
+            title.keywords,
+            vec!["HYDROLASE", "ANTIBIOTIC RESISTANCE", "BETA-LACTAMASE"]
+        );
+        assert_eq!(
+            title.experimental_methods,
+            vec!["X-RAY DIFFRACTION", "NEUTRON DIFFRACTION"]
+        );
+        assert_eq!(title.authors, vec!["A.B.SMITH", "C.D.JONES"]);
+        assert_eq!(title.revisions.len(), 2);
+        assert_eq!(title.revisions[0].records, vec!["VERSN"]);
+        assert_eq!(title.revisions[1].mod_num, 1);
+        assert_eq!(title.revisions[1].records, vec!["JRNL"]);
+    }
+
+    #[test]
+    fn test_parse_specifications() {
+        let molecules =
+            parse_specifications("MOL_ID: 1; MOLECULE: PROTEIN; OTHER_DETAILS: A; B; CHAIN: A");
+        assert_eq!(molecules.len(), 1);
+        assert_eq!(molecules[0]["OTHER_DETAILS"], "A; B");
+        assert_eq!(molecules[0]["CHAIN"], "A");
+        assert!(parse_specifications("").is_empty());
+    }
+}
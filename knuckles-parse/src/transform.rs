@@ -0,0 +1,282 @@
+//! Affine coordinate transforms.
+//!
+//! Several PDB records describe a coordinate transform as the three rows of a 3×4
+//! matrix: SCALEn, ORIGXn, MTRIXn and the BIOMT lines of REMARK 350. A [`Transform`]
+//! holds one such matrix and applies it to points or atoms.
+//!
+//! [`origx`] reads the ORIGXn records, which map the coordinates in the file to the frame
+//! the authors submitted them in; its [`Transform::inverse`] maps them back.
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::transform::Transform;
+//!
+//! let shift = Transform::new(
+//!     [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+//!     [10.0, 0.0, 0.0],
+//! );
+//!
+//! assert_eq!(shift.apply([1.0, 2.0, 3.0]), [8.0, 1.0, 3.0]);
+//! assert_eq!(shift.inverse().unwrap().apply([8.0, 1.0, 3.0]), [1.0, 2.0, 3.0]);
+//! ```
+
+use crate::records::{atom::AtomRecord, origxn::OrigxN, Record};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An affine transform `x' = matrix · x + vector`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Transform {
+    /// Linear part, one row per output coordinate
+    pub matrix: [[f64; 3]; 3],
+    /// Translation applied after the linear part
+    pub vector: [f64; 3],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::IDENTITY
+    }
+}
+
+impl Transform {
+    /// The transform that leaves every point in place.
+    pub const IDENTITY: Transform = Transform {
+        matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        vector: [0.0, 0.0, 0.0],
+    };
+
+    /// Create a transform from its linear part and translation.
+    pub fn new(matrix: [[f64; 3]; 3], vector: [f64; 3]) -> Transform {
+        Transform { matrix, vector }
+    }
+
+    /// Create a transform from three rows as stored in PDB records, each a matrix row
+    /// and the translation component for that row.
+    pub fn from_rows(rows: [([f32; 3], f32); 3]) -> Transform {
+        Transform {
+            matrix: rows.map(|(row, _)| row.map(f64::from)),
+            vector: rows.map(|(_, translation)| f64::from(translation)),
+        }
+    }
+
+    /// Transform a point.
+    pub fn apply(&self, point: [f64; 3]) -> [f64; 3] {
+        std::array::from_fn(|i| {
+            (0..3).map(|j| self.matrix[i][j] * point[j]).sum::<f64>() + self.vector[i]
+        })
+    }
+
+    /// Transform the coordinates of an atom in place.
+    pub fn apply_atom(&self, atom: &mut AtomRecord) {
+        let [x, y, z] = self.apply([atom.x, atom.y, atom.z].map(f64::from));
+        atom.x = x as f32;
+        atom.y = y as f32;
+        atom.z = z as f32;
+    }
+
+    /// Transform the coordinates of every ATOM and HETATM record in place.
+    pub fn apply_records(&self, records: &mut [Record]) {
+        for record in records {
+            if let Record::Atom(atom) | Record::Hetatm(atom) = record {
+                self.apply_atom(atom);
+            }
+        }
+    }
+
+    /// The transform that applies `self` and then `next`.
+    pub fn then(&self, next: &Transform) -> Transform {
+        let matrix = std::array::from_fn(|i| {
+            std::array::from_fn(|j| (0..3).map(|k| next.matrix[i][k] * self.matrix[k][j]).sum())
+        });
+        Transform {
+            matrix,
+            vector: next.apply(self.vector),
+        }
+    }
+
+    /// The determinant of the linear part.
+    pub fn determinant(&self) -> f64 {
+        let m = &self.matrix;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// The transform undoing this one, or `None` if the linear part is singular.
+    pub fn inverse(&self) -> Option<Transform> {
+        let determinant = self.determinant();
+        if determinant.abs() < f64::EPSILON {
+            return None;
+        }
+        let m = &self.matrix;
+        // Adjugate: the transposed matrix of cofactors.
+        let cofactor = |i: usize, j: usize| {
+            let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+            let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+        let matrix: [[f64; 3]; 3] =
+            std::array::from_fn(|i| std::array::from_fn(|j| cofactor(j, i) / determinant));
+        let linear = Transform::new(matrix, [0.0; 3]);
+        Some(Transform {
+            matrix,
+            vector: linear.apply(self.vector).map(|component| -component),
+        })
+    }
+
+    /// The largest absolute difference between corresponding elements of two transforms.
+    pub fn max_difference(&self, other: &Transform) -> f64 {
+        let matrix = (0..3).flat_map(|i| (0..3).map(move |j| (i, j)));
+        matrix
+            .map(|(i, j)| (self.matrix[i][j] - other.matrix[i][j]).abs())
+            .chain((0..3).map(|i| (self.vector[i] - other.vector[i]).abs()))
+            .fold(0.0, f64::max)
+    }
+
+    /// Whether every element is within `tolerance` of the identity transform.
+    pub fn is_identity(&self, tolerance: f64) -> bool {
+        self.max_difference(&Transform::IDENTITY) <= tolerance
+    }
+}
+
+/// The transform held by the ORIGX1, ORIGX2 and ORIGX3 records, from the coordinates in
+/// the file to the coordinates as submitted, or `None` unless all three are present.
+pub fn origx(records: &[Record]) -> Option<Transform> {
+    rows_to_transform(records, |record| match record {
+        Record::OrigxN(OrigxN::Origx1(row)) => Some((0, (row.origxn, row.tn))),
+        Record::OrigxN(OrigxN::Origx2(row)) => Some((1, (row.origxn, row.tn))),
+        Record::OrigxN(OrigxN::Origx3(row)) => Some((2, (row.origxn, row.tn))),
+        _ => None,
+    })
+}
+
+/// The transform held by a set of three row records, such as SCALEn or ORIGXn, or `None`
+/// unless all three are present.
+///
+/// `row` picks out the records of the set, giving the index of the row from its record
+/// type along with its matrix row and translation. Later records replace earlier ones.
+pub(crate) fn rows_to_transform(
+    records: &[Record],
+    row: impl Fn(&Record) -> Option<(usize, ([f32; 3], f32))>,
+) -> Option<Transform> {
+    let mut rows = [None; 3];
+    for (index, values) in records.iter().filter_map(row) {
+        rows[index] = Some(values);
+    }
+    let [Some(first), Some(second), Some(third)] = rows else {
+        return None;
+    };
+    Some(Transform::from_rows([first, second, third]))
+}
+
+/// Copies of the ATOM and HETATM records in `records` moved by `transform`; all other
+/// records are left out.
+pub(crate) fn transform_atoms(records: &[Record], transform: &Transform) -> Vec<Record> {
+    records
+        .iter()
+        .filter_map(|record| {
+            let (mut atom, hetero) = match record {
+                Record::Atom(atom) => (atom.clone(), false),
+                Record::Hetatm(atom) => (atom.clone(), true),
+                _ => return None,
+            };
+            transform.apply_atom(&mut atom);
+            Some(match hetero {
+                true => Record::Hetatm(atom),
+                false => Record::Atom(atom),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: [f64; 3], b: [f64; 3]) {
+        for i in 0..3 {
+            assert!((a[i] - b[i]).abs() < 1e-9, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_inverse_and_then() {
+        let transform = Transform::new(
+            [[0.5, 0.2, 0.0], [-0.1, 1.5, 0.3], [0.0, 0.4, 2.0]],
+            [1.0, -2.0, 3.5],
+        );
+        let point = [4.0, -1.0, 2.5];
+        let inverse = transform.inverse().unwrap();
+        assert_close(inverse.apply(transform.apply(point)), point);
+        assert!(
+            transform
+                .then(&inverse)
+                .max_difference(&Transform::IDENTITY)
+                < 1e-9
+        );
+        assert_close(
+            transform.then(&transform).apply(point),
+            transform.apply(transform.apply(point)),
+        );
+
+        let singular = Transform::new(
+            [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 0.0, 1.0]],
+            [0.0; 3],
+        );
+        assert!(singular.inverse().is_none());
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let transform = Transform::from_rows([
+            ([-1.0, 0.0, 0.0], 5.0),
+            ([0.0, 1.0, 0.0], 0.0),
+            ([0.0, 0.0, -1.0], 0.0),
+        ]);
+        assert_eq!(transform.apply([1.0, 2.0, 3.0]), [4.0, 2.0, -3.0]);
+        assert!(!transform.is_identity(1e-6));
+        assert!(Transform::default().is_identity(0.0));
+    }
+
+    #[test]
+    fn test_origx() {
+        let mut records: Vec<Record> = [
+            "ORIGX1      0.000000 -1.000000  0.000000       10.00000",
+            "ORIGX2      1.000000  0.000000  0.000000        0.00000",
+            "ORIGX3      0.000000  0.000000  1.000000       -2.50000",
+            "ATOM      1  CA  GLY A   1       1.000   2.000   3.000  1.00  0.00           C",
+            "HETATM    2  ZN   ZN A 101       4.000   5.000   6.000  1.00  0.00          ZN",
+        ]
+        .into_iter()
+        .map(|line| Record::try_from(line).unwrap())
+        .collect();
+        let origx = origx(&records).unwrap();
+        origx.apply_records(&mut records);
+        let Record::Atom(atom) = &records[3] else {
+            panic!("expected an atom");
+        };
+        assert_eq!((atom.x, atom.y, atom.z), (8.0, 1.0, 0.5));
+        let Record::Hetatm(atom) = &records[4] else {
+            panic!("expected a heteroatom");
+        };
+        assert_eq!((atom.x, atom.y, atom.z), (5.0, 4.0, 3.5));
+
+        origx.inverse().unwrap().apply_records(&mut records);
+        let Record::Atom(atom) = &records[3] else {
+            panic!("expected an atom");
+        };
+        assert_eq!((atom.x, atom.y, atom.z), (1.0, 2.0, 3.0));
+
+        assert!(super::origx(&records[1..]).is_none());
+
+        // The row follows the record type even when `n` disagrees.
+        if let Record::OrigxN(OrigxN::Origx3(row)) = &mut records[2] {
+            row.n = 7;
+        }
+        assert_eq!(super::origx(&records), Some(origx));
+    }
+}
@@ -0,0 +1,304 @@
+//! Crystallographic unit cells and fractional coordinates.
+//!
+//! A [`UnitCell`] is built from the CRYST1 record and converts coordinates between
+//! Cartesian space and fractional coordinates along the cell edges. The axes follow the
+//! PDB convention: `a` along x, `b` in the xy plane and `c*` along z. The SCALEn records
+//! give the same fractionalization as stored by the depositors, and
+//! [`UnitCell::scale_deviation`] compares the two.
+//!
+//! # Example
+//!
+//! ```rust
+//! use knuckles_parse::{reader::ReaderOptions, unit_cell::{self, UnitCell}};
+//!
+//! let contents = "\
+//! CRYST1   52.000   58.600   61.900  90.00  90.00  90.00 P 21 21 21    8
+//! SCALE1      0.019231  0.000000  0.000000        0.00000
+//! SCALE2      0.000000  0.017065  0.000000        0.00000
+//! SCALE3      0.000000  0.000000  0.016155        0.00000
+//! ";
+//! let records = ReaderOptions::new().read(contents).unwrap().records;
+//! let cell = UnitCell::from_records(&records).unwrap();
+//!
+//! assert_eq!(cell.to_fractional([26.0, 0.0, 0.0]), [0.5, 0.0, 0.0]);
+//! assert!(cell.scale_deviation(&unit_cell::scale(&records).unwrap()) < 1e-6);
+//! ```
+
+use crate::records::{crystal::CrystalRecord, scalen::ScaleN, Record};
+use crate::transform::{rows_to_transform, Transform};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The edge lengths and angles of a crystallographic unit cell.
+///
+/// A cell can only be created through [`UnitCell::new`] and the constructors built on
+/// it, which reject cells without volume, so the conversions between Cartesian and
+/// fractional coordinates always exist. Both are computed once, on construction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "CellParameters", into = "CellParameters")
+)]
+pub struct UnitCell {
+    parameters: CellParameters,
+    orthogonalization: Transform,
+    fractionalization: Transform,
+}
+
+/// The six cell parameters, as serialized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct CellParameters {
+    a: f64,
+    b: f64,
+    c: f64,
+    alpha: f64,
+    beta: f64,
+    gamma: f64,
+}
+
+impl CellParameters {
+    fn volume(&self) -> f64 {
+        let [cos_alpha, cos_beta, cos_gamma] =
+            [self.alpha, self.beta, self.gamma].map(|angle| angle.to_radians().cos());
+        let factor = 1.0 - cos_alpha.powi(2) - cos_beta.powi(2) - cos_gamma.powi(2)
+            + 2.0 * cos_alpha * cos_beta * cos_gamma;
+        self.a * self.b * self.c * factor.max(0.0).sqrt()
+    }
+
+    fn orthogonalization(&self) -> Transform {
+        let [cos_alpha, cos_beta, cos_gamma] =
+            [self.alpha, self.beta, self.gamma].map(|angle| angle.to_radians().cos());
+        let sin_gamma = self.gamma.to_radians().sin();
+        Transform::new(
+            [
+                [self.a, self.b * cos_gamma, self.c * cos_beta],
+                [
+                    0.0,
+                    self.b * sin_gamma,
+                    self.c * (cos_alpha - cos_beta * cos_gamma) / sin_gamma,
+                ],
+                [0.0, 0.0, self.volume() / (self.a * self.b * sin_gamma)],
+            ],
+            [0.0; 3],
+        )
+    }
+}
+
+impl TryFrom<CellParameters> for UnitCell {
+    type Error = &'static str;
+
+    fn try_from(parameters: CellParameters) -> Result<Self, Self::Error> {
+        let CellParameters {
+            a,
+            b,
+            c,
+            alpha,
+            beta,
+            gamma,
+        } = parameters;
+        UnitCell::new(a, b, c, alpha, beta, gamma)
+            .ok_or("unit cell edges must be positive and angles must give a positive volume")
+    }
+}
+
+impl From<UnitCell> for CellParameters {
+    fn from(cell: UnitCell) -> Self {
+        cell.parameters
+    }
+}
+
+impl UnitCell {
+    /// Create a unit cell from its edge lengths in Ångströms and angles in degrees.
+    ///
+    /// Returns `None` unless the edges are positive and the angles describe a cell with
+    /// positive volume.
+    pub fn new(a: f64, b: f64, c: f64, alpha: f64, beta: f64, gamma: f64) -> Option<UnitCell> {
+        let parameters = CellParameters {
+            a,
+            b,
+            c,
+            alpha,
+            beta,
+            gamma,
+        };
+        if !(a > 0.0 && b > 0.0 && c > 0.0 && parameters.volume() > 0.0) {
+            return None;
+        }
+        let orthogonalization = parameters.orthogonalization();
+        Some(UnitCell {
+            parameters,
+            orthogonalization,
+            fractionalization: orthogonalization.inverse()?,
+        })
+    }
+
+    /// Create a unit cell from a CRYST1 record.
+    pub fn from_crystal(crystal: &CrystalRecord) -> Option<UnitCell> {
+        UnitCell::new(
+            crystal.a.into(),
+            crystal.b.into(),
+            crystal.c.into(),
+            crystal.alpha.into(),
+            crystal.beta.into(),
+            crystal.gamma.into(),
+        )
+    }
+
+    /// Create a unit cell from the first CRYST1 record in `records`.
+    pub fn from_records(records: &[Record]) -> Option<UnitCell> {
+        records.iter().find_map(|record| match record {
+            Record::Crystal(crystal) => UnitCell::from_crystal(crystal),
+            _ => None,
+        })
+    }
+
+    /// Edge length a in Ångströms.
+    pub fn a(&self) -> f64 {
+        self.parameters.a
+    }
+
+    /// Edge length b in Ångströms.
+    pub fn b(&self) -> f64 {
+        self.parameters.b
+    }
+
+    /// Edge length c in Ångströms.
+    pub fn c(&self) -> f64 {
+        self.parameters.c
+    }
+
+    /// Angle between b and c in degrees.
+    pub fn alpha(&self) -> f64 {
+        self.parameters.alpha
+    }
+
+    /// Angle between a and c in degrees.
+    pub fn beta(&self) -> f64 {
+        self.parameters.beta
+    }
+
+    /// Angle between a and b in degrees.
+    pub fn gamma(&self) -> f64 {
+        self.parameters.gamma
+    }
+
+    /// The volume of the cell in cubic Ångströms.
+    pub fn volume(&self) -> f64 {
+        self.parameters.volume()
+    }
+
+    /// The transform from fractional to Cartesian coordinates.
+    pub fn orthogonalization(&self) -> Transform {
+        self.orthogonalization
+    }
+
+    /// The transform from Cartesian to fractional coordinates, the matrix SCALEn records
+    /// hold for coordinates in the standard frame.
+    pub fn fractionalization(&self) -> Transform {
+        self.fractionalization
+    }
+
+    /// Convert a Cartesian point in Ångströms to fractional coordinates.
+    pub fn to_fractional(&self, point: [f64; 3]) -> [f64; 3] {
+        self.fractionalization.apply(point)
+    }
+
+    /// Convert fractional coordinates to a Cartesian point in Ångströms.
+    pub fn to_cartesian(&self, point: [f64; 3]) -> [f64; 3] {
+        self.orthogonalization.apply(point)
+    }
+
+    /// The largest absolute difference between a SCALE transform and the
+    /// fractionalization derived from the cell.
+    ///
+    /// SCALEn values are written with six decimals, so deviations around `1e-6` are
+    /// rounding; larger ones mean the coordinates are not in the standard frame or the
+    /// CRYST1 and SCALEn records disagree.
+    pub fn scale_deviation(&self, scale: &Transform) -> f64 {
+        self.fractionalization.max_difference(scale)
+    }
+}
+
+/// The transform held by the SCALE1, SCALE2 and SCALE3 records, or `None` unless all three
+/// are present.
+pub fn scale(records: &[Record]) -> Option<Transform> {
+    rows_to_transform(records, |record| match record {
+        Record::ScaleN(ScaleN::Scale1(row)) => Some((0, (row.scalen, row.un))),
+        Record::ScaleN(ScaleN::Scale2(row)) => Some((1, (row.scalen, row.un))),
+        Record::ScaleN(ScaleN::Scale3(row)) => Some((2, (row.scalen, row.un))),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_volume_and_conversion() {
+        let cell = UnitCell::new(52.0, 58.6, 61.9, 90.0, 90.0, 90.0).unwrap();
+        assert!((cell.volume() - 52.0 * 58.6 * 61.9).abs() < 1e-6);
+
+        let cell = UnitCell::new(30.9, 39.3, 43.4, 102.8, 103.9, 98.6).unwrap();
+        assert!((cell.volume() - 48_731.044).abs() < 1e-3);
+        let point = [12.5, -3.0, 20.25];
+        let back = cell.to_cartesian(cell.to_fractional(point));
+        for i in 0..3 {
+            assert!((back[i] - point[i]).abs() < 1e-9);
+        }
+        let corner = cell.to_cartesian([1.0, 1.0, 1.0]);
+        assert!((cell.to_fractional(corner)[2] - 1.0).abs() < 1e-12);
+        assert!(
+            (cell.to_cartesian([0.0, 0.0, 1.0])[0] - 43.4 * 103.9f64.to_radians().cos()).abs()
+                < 1e-9
+        );
+
+        assert!(UnitCell::new(10.0, 10.0, 10.0, 150.0, 150.0, 150.0).is_none());
+        assert!(UnitCell::new(0.0, 10.0, 10.0, 90.0, 90.0, 90.0).is_none());
+
+        // Deserialized cells go through the same check.
+        let flat = CellParameters {
+            a: 10.0,
+            b: 10.0,
+            c: 10.0,
+            alpha: 150.0,
+            beta: 150.0,
+            gamma: 150.0,
+        };
+        assert!(UnitCell::try_from(flat).is_err());
+        let parameters = CellParameters::from(cell);
+        assert_eq!(UnitCell::try_from(parameters), Ok(cell));
+        assert_eq!((cell.a(), cell.beta()), (30.9, 103.9));
+    }
+
+    #[test]
+    fn test_scale() {
+        let records: Vec<Record> = [
+            "CRYST1   30.900   39.300   43.400 102.80 103.90  98.60 P 1           1",
+            "SCALE1      0.032362  0.004894  0.009676        0.00000",
+            "SCALE2      0.000000  0.025735  0.007166        0.00000",
+            "SCALE3      0.000000  0.000000  0.024640        0.00000",
+        ]
+        .into_iter()
+        .map(|line| Record::try_from(line).unwrap())
+        .collect();
+        let cell = UnitCell::from_records(&records).unwrap();
+        let scale = scale(&records).unwrap();
+        assert!(cell.scale_deviation(&scale) < 1e-6);
+
+        let shifted = Transform::new(scale.matrix, [0.5, 0.0, 0.0]);
+        assert!(cell.scale_deviation(&shifted) > 0.1);
+
+        assert!(super::scale(&records[..3]).is_none());
+
+        // The row follows the record type even when `n` disagrees.
+        let mut records = records;
+        if let Record::ScaleN(ScaleN::Scale1(row)) = &mut records[1] {
+            row.n = 0;
+        }
+        assert_eq!(super::scale(&records), Some(scale));
+    }
+}
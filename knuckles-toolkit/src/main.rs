@@ -1,24 +1,17 @@
-use knuckles_parse::records;
-
-#[cfg(not(feature = "parallel"))]
-use knuckles_parse::pdbreader_single;
-
-#[cfg(feature = "parallel")]
-use knuckles_parse::pdbreader_parallel as pdbreader;
+use knuckles_parse::{compression, reader::ReaderOptions, records::RecordKinds};
 
 fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "tests/4pth.pdb".to_string());
     let contents =
-        std::fs::read_to_string("tests/4pth.pdb").expect("Something went wrong reading the file");
+        compression::read_to_string(&path).expect("Something went wrong reading the file");
+    let options = ReaderOptions::new();
     let mut times = Vec::new();
     for i in 0..50 {
         let start = std::time::Instant::now();
-        #[cfg(feature = "parallel")]
-        let _ = pdbreader(&contents);
-        #[cfg(not(feature = "parallel"))]
-        let _ = pdbreader_single(&contents);
+        let _ = options.read(&contents);
 
-        // let _ = pdbreader_single(&contents);
-        // let _ = pdbreader("tests/4pth.pdb");
         let end = std::time::Instant::now();
         times.push(end - start);
         println!("Run {}: Time: {:?}", i, end - start);
@@ -27,18 +20,20 @@ fn main() {
     let avg = sum / times.len() as u32;
     println!("Total time: {:?}", sum);
     println!("Average time: {:?}", avg);
-    #[cfg(feature = "parallel")]
-    let out = pdbreader(&contents);
-
-    #[cfg(not(feature = "parallel"))]
-    let out = pdbreader_single(&contents);
+    let out = ReaderOptions::new()
+        .kinds(RecordKinds::ATOM)
+        .read(&contents)
+        .expect("Lenient reads never fail");
 
-    // println!("{:?}", out[0]);
-    out.iter()
-        // .filter(|&record| matches!(record, records::Record::DBRef(_)))
-        .filter(|&record| matches!(record, records::Record::Atom(_)))
-        .take(10)
-        .for_each(|record| {
-            println!("{}", record);
-        });
+    // println!("{:?}", out.records[0]);
+    out.records.iter().take(10).for_each(|record| {
+        // `Record` displays as a PDB line; print JSON as before when serde is enabled.
+        #[cfg(feature = "serde")]
+        println!(
+            "{}",
+            serde_json::to_string(record).expect("Records serialize to JSON")
+        );
+        #[cfg(not(feature = "serde"))]
+        println!("{}", record);
+    });
 }
@@ -37,10 +37,12 @@ class AtomRecord:
         The temperature factor of the atom.
     element : Optional[str]
         The element symbol.
-    charge : Optional[str]
-        The charge on the atom.
-    entry : Optional[str]
-        The entry in the PDB file.
+    charge : Optional[int]
+        The formal charge on the atom.
+    segment_id : Optional[str]
+        The segment identifier.
+    auth_chain : Optional[str]
+        The full chain identifier when it is too long for `chain_id`.
 
     """
 
@@ -57,10 +59,12 @@ class AtomRecord:
     occupancy: float
     temp_factor: float
     element: Optional[str]
-    charge: Optional[str]
-    entry: Optional[str]
+    charge: Optional[int]
+    segment_id: Optional[str]
+    auth_chain: Optional[str]
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class AnisotropicRecord:
     """A class to represent an Anisotropic Record in a PDB file.
@@ -100,8 +104,8 @@ class AnisotropicRecord:
         The U(2,3) element of the anisotropic temperature factor.
     element : Optional[str]
         The element symbol.
-    charge : Optional[str]
-        The charge on the atom.
+    charge : Optional[int]
+        The formal charge on the atom.
 
     """
 
@@ -119,9 +123,132 @@ class AnisotropicRecord:
     u02: int
     u12: int
     element: Optional[str]
-    charge: Optional[str]
+    charge: Optional[int]
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class AuthorRecord:
+    """A class to represent an AUTHOR Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    text : str
+        The comma-separated author names on this line.
+
+    """
+
+    continuation: Optional[int]
+    text: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class CaveatRecord:
+    """A class to represent a CAVEAT Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    id_code : str
+        The PDB identifier of the entry.
+    comment : str
+        The description of the problem on this line.
+
+    """
+
+    continuation: Optional[int]
+    id_code: str
+    comment: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class CispepRecord:
+    """A class to represent a CISPEP Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    serial : int
+        The serial number of the record.
+    res_name1 : str
+        The name of the first residue.
+    chain_id1 : str
+        The chain identifier of the first residue.
+    seq_num1 : int
+        The sequence number of the first residue.
+    i_code1 : Optional[str]
+        The insertion code of the first residue.
+    res_name2 : str
+        The name of the second residue.
+    chain_id2 : str
+        The chain identifier of the second residue.
+    seq_num2 : int
+        The sequence number of the second residue.
+    i_code2 : Optional[str]
+        The insertion code of the second residue.
+    mod_num : int
+        The model number, 0 for single-model entries.
+    measure : Optional[float]
+        The omega angle in degrees.
+
+    """
+
+    serial: int
+    res_name1: str
+    chain_id1: str
+    seq_num1: int
+    i_code1: Optional[str]
+    res_name2: str
+    chain_id2: str
+    seq_num2: int
+    i_code2: Optional[str]
+    mod_num: int
+    measure: Optional[float]
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class CompndRecord:
+    """A class to represent a COMPND Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    text : str
+        The specification list describing the macromolecular contents on this line.
+
+    """
+
+    continuation: Optional[int]
+    text: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class ConnectRecord:
     """A class to represent a Connect Record in a PDB file.
@@ -144,6 +271,7 @@ class ConnectRecord:
     connected: list[Optional[int]]
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class CrystalRecord:
     """A class to represent a Crystal Record in a PDB file.
@@ -184,6 +312,7 @@ class CrystalRecord:
     z: int
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class DBRefRecord:
     """A class to represent a DBREF Record in a PDB file.
@@ -239,6 +368,7 @@ class DBRefRecord:
     db_ins_end: Optional[str]
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class DBType(Enum):
     """An enumeration to represent the database type in a DBREF Record."""
@@ -249,6 +379,144 @@ class DBType(Enum):
     UNP = ...
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class ExpdtaRecord:
+    """A class to represent an EXPDTA Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    text : str
+        The semicolon-separated experimental techniques on this line.
+
+    """
+
+    continuation: Optional[int]
+    text: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class FormulRecord:
+    """A class to represent Represents a FORMUL record, giving the chemical formula of a hetero-compound. in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    comp_num : int
+        Component number
+    het_id : str
+        Hetero-compound identifier
+    continuation : Optional[int]
+        Continuation number, absent on the first line
+    water : bool
+        Whether the component is water
+    text : str
+        Chemical formula on this line
+
+    """
+
+    comp_num: int
+    het_id: str
+    continuation: Optional[int]
+    water: bool
+    text: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class HeaderRecord:
+    """A class to represent a HEADER Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    classification : str
+        The classification of the molecule(s).
+    dep_date : str
+        The deposition date.
+    id_code : str
+        The PDB identifier.
+
+    """
+
+    classification: str
+    dep_date: str
+    id_code: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class HelixRecord:
+    """A class to represent a HELIX Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    serial : int
+        The serial number of the helix.
+    helix_id : str
+        The helix identifier.
+    init_res_name : str
+        The name of the first residue.
+    init_chain_id : str
+        The chain identifier of the first residue.
+    init_seq_num : int
+        The sequence number of the first residue.
+    init_i_code : Optional[str]
+        The insertion code of the first residue.
+    end_res_name : str
+        The name of the last residue.
+    end_chain_id : str
+        The chain identifier of the last residue.
+    end_seq_num : int
+        The sequence number of the last residue.
+    end_i_code : Optional[str]
+        The insertion code of the last residue.
+    helix_class : Optional[int]
+        The helix class, from 1 (right-handed alpha) to 10 (polyproline).
+    comment : Optional[str]
+        A comment about the helix.
+    length : Optional[int]
+        The number of residues in the helix.
+
+    """
+
+    serial: int
+    helix_id: str
+    init_res_name: str
+    init_chain_id: str
+    init_seq_num: int
+    init_i_code: Optional[str]
+    end_res_name: str
+    end_chain_id: str
+    end_seq_num: int
+    end_i_code: Optional[str]
+    helix_class: Optional[int]
+    comment: Optional[str]
+    length: Optional[int]
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class HetRecord:
     """A class to represent a HET Record in a PDB file.
@@ -283,6 +551,7 @@ class HetRecord:
     text: Optional[str]
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 # pub struct HetnamRecord {
 #     pub continuation: Option<String>,
@@ -313,6 +582,191 @@ class HetnamRecord:
     text: str
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class HetsynRecord:
+    """A class to represent Represents a HETSYN record, listing synonyms for a hetero-compound. in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[str]
+        Continuation number, absent on the first line
+    het_id : str
+        Hetero-compound identifier
+    text : str
+        Semicolon-separated synonyms on this line
+
+    """
+
+    continuation: Optional[str]
+    het_id: str
+    text: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class KeywdsRecord:
+    """A class to represent a KEYWDS Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    text : str
+        The comma-separated keywords on this line.
+
+    """
+
+    continuation: Optional[int]
+    text: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class LinkRecord:
+    """A class to represent a LINK Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    name1 : str
+        The name of the first atom.
+    alt_loc1 : Optional[str]
+        The alternate location indicator of the first atom.
+    res_name1 : str
+        The residue name of the first atom.
+    chain_id1 : str
+        The chain identifier of the first atom.
+    res_seq1 : int
+        The residue sequence number of the first atom.
+    i_code1 : Optional[str]
+        The insertion code of the first atom.
+    name2 : str
+        The name of the second atom.
+    alt_loc2 : Optional[str]
+        The alternate location indicator of the second atom.
+    res_name2 : str
+        The residue name of the second atom.
+    chain_id2 : str
+        The chain identifier of the second atom.
+    res_seq2 : int
+        The residue sequence number of the second atom.
+    i_code2 : Optional[str]
+        The insertion code of the second atom.
+    sym1 : Optional[str]
+        The symmetry operator applied to the first atom.
+    sym2 : Optional[str]
+        The symmetry operator applied to the second atom.
+    length : Optional[float]
+        The link distance in Angstroms.
+
+    """
+
+    name1: str
+    alt_loc1: Optional[str]
+    res_name1: str
+    chain_id1: str
+    res_seq1: int
+    i_code1: Optional[str]
+    name2: str
+    alt_loc2: Optional[str]
+    res_name2: str
+    chain_id2: str
+    res_seq2: int
+    i_code2: Optional[str]
+    sym1: Optional[str]
+    sym2: Optional[str]
+    length: Optional[float]
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class MasterRecord:
+    """A class to represent Represents a MASTER record, counting the records of selected types in the file. in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    num_remark : int
+        Number of REMARK records
+    num_het : int
+        Number of HET records
+    num_helix : int
+        Number of HELIX records
+    num_sheet : int
+        Number of SHEET records
+    num_turn : int
+        Number of TURN records
+    num_site : int
+        Number of SITE records
+    num_xform : int
+        Number of ORIGX, SCALE and MTRIX records
+    num_coord : int
+        Number of ATOM and HETATM records
+    num_ter : int
+        Number of TER records
+    num_conect : int
+        Number of CONECT records
+    num_seq : int
+        Number of SEQRES records
+
+    """
+
+    num_remark: int
+    num_het: int
+    num_helix: int
+    num_sheet: int
+    num_turn: int
+    num_site: int
+    num_xform: int
+    num_coord: int
+    num_ter: int
+    num_conect: int
+    num_seq: int
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class MdltypRecord:
+    """A class to represent an MDLTYP Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    text : str
+        The semicolon-separated model type annotations on this line.
+
+    """
+
+    continuation: Optional[int]
+    text: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class ModelRecord:
     """A class to represent a Model Record in a PDB file.
@@ -332,6 +786,7 @@ class ModelRecord:
     serial_number: int
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class ModresRecord:
     """A class to represent a MODRES Record in a PDB file.
@@ -369,6 +824,7 @@ class ModresRecord:
     comment: str
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class MtrixnRecord:
     """A class to represent an MTRIXn Record in a PDB file.
@@ -400,6 +856,7 @@ class MtrixnRecord:
     i_given: bool
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class NummdlRecord:
     """A class to represent a NUMMDL Record in a PDB file.
@@ -419,6 +876,36 @@ class NummdlRecord:
     num_models: int
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class ObslteRecord:
+    """A class to represent an OBSLTE Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    date : str
+        The date the entry was withdrawn.
+    id_code : str
+        The PDB identifier of the withdrawn entry.
+    replaced_by : list[str]
+        The PDB identifiers of the replacement entries.
+
+    """
+
+    continuation: Optional[int]
+    date: str
+    id_code: str
+    replaced_by: list[str]
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class OrigxnRecord:
     """A class to represent an ORIGXn Record in a PDB file.
@@ -444,6 +931,65 @@ class OrigxnRecord:
     tn: float
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class RemarkRecord:
+    """A class to represent a REMARK Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    number : int
+        The REMARK number.
+    text : str
+        The text of the line from column 12, with leading whitespace preserved.
+
+    """
+
+    number: int
+    text: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class RevdatRecord:
+    """A class to represent a REVDAT Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    mod_num : int
+        The modification number.
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    mod_date : str
+        The date of the modification.
+    mod_id : str
+        The identifier of the modification.
+    mod_type : int
+        0 for the initial release, 1 for later modifications.
+    records : list[str]
+        The names of the records changed by the modification.
+
+    """
+
+    mod_num: int
+    continuation: Optional[int]
+    mod_date: str
+    mod_id: str
+    mod_type: int
+    records: list[str]
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class ScalenRecord:
     """A class to represent a SCALEn Record in a PDB file.
@@ -469,6 +1015,7 @@ class ScalenRecord:
     un: float
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class SeqAdvRecord:
     """A class to represent a SEQADV Record in a PDB file.
@@ -515,6 +1062,7 @@ class SeqAdvRecord:
     conflict: str
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class SeqresRecord:
     """A class to represent a SEQRES Record in a PDB file.
@@ -544,6 +1092,276 @@ class SeqresRecord:
     res_names: list[str]
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class SheetRecord:
+    """A class to represent a SHEET Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    strand : int
+        The strand number, starting at 1 for each sheet.
+    sheet_id : str
+        The sheet identifier.
+    num_strands : int
+        The number of strands in the sheet.
+    init_res_name : str
+        The name of the first residue.
+    init_chain_id : str
+        The chain identifier of the first residue.
+    init_seq_num : int
+        The sequence number of the first residue.
+    init_i_code : Optional[str]
+        The insertion code of the first residue.
+    end_res_name : str
+        The name of the last residue.
+    end_chain_id : str
+        The chain identifier of the last residue.
+    end_seq_num : int
+        The sequence number of the last residue.
+    end_i_code : Optional[str]
+        The insertion code of the last residue.
+    sense : int
+        0 for the first strand, 1 for parallel and -1 for anti-parallel.
+    cur_atom : Optional[str]
+        The registration atom name in the current strand.
+    cur_res_name : Optional[str]
+        The registration residue name in the current strand.
+    cur_chain_id : Optional[str]
+        The registration chain identifier in the current strand.
+    cur_res_seq : Optional[int]
+        The registration residue sequence number in the current strand.
+    cur_i_code : Optional[str]
+        The registration insertion code in the current strand.
+    prev_atom : Optional[str]
+        The registration atom name in the previous strand.
+    prev_res_name : Optional[str]
+        The registration residue name in the previous strand.
+    prev_chain_id : Optional[str]
+        The registration chain identifier in the previous strand.
+    prev_res_seq : Optional[int]
+        The registration residue sequence number in the previous strand.
+    prev_i_code : Optional[str]
+        The registration insertion code in the previous strand.
+
+    """
+
+    strand: int
+    sheet_id: str
+    num_strands: int
+    init_res_name: str
+    init_chain_id: str
+    init_seq_num: int
+    init_i_code: Optional[str]
+    end_res_name: str
+    end_chain_id: str
+    end_seq_num: int
+    end_i_code: Optional[str]
+    sense: int
+    cur_atom: Optional[str]
+    cur_res_name: Optional[str]
+    cur_chain_id: Optional[str]
+    cur_res_seq: Optional[int]
+    cur_i_code: Optional[str]
+    prev_atom: Optional[str]
+    prev_res_name: Optional[str]
+    prev_chain_id: Optional[str]
+    prev_res_seq: Optional[int]
+    prev_i_code: Optional[str]
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class SiteRecord:
+    """A class to represent Represents a SITE record, listing residues that make up a site. in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    seq_num : int
+        Line number within the site
+    site_id : str
+        Site identifier
+    num_res : int
+        Total number of residues in the site
+    residues : list[SiteResidue]
+        Up to four residues of the site on this line
+
+    """
+
+    seq_num: int
+    site_id: str
+    num_res: int
+    residues: list[SiteResidue]
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class SiteResidue:
+    """A class to represent A residue belonging to a site. in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    res_name : str
+        Residue name
+    chain_id : str
+        Chain identifier
+    seq_num : int
+        Residue sequence number
+    i_code : Optional[str]
+        Insertion code
+
+    """
+
+    res_name: str
+    chain_id: str
+    seq_num: int
+    i_code: Optional[str]
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class SourceRecord:
+    """A class to represent a SOURCE Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    text : str
+        The specification list describing the biological source on this line.
+
+    """
+
+    continuation: Optional[int]
+    text: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class SplitRecord:
+    """A class to represent a SPLIT Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    id_codes : list[str]
+        The PDB identifiers of the related entries.
+
+    """
+
+    continuation: Optional[int]
+    id_codes: list[str]
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class SprsdeRecord:
+    """A class to represent a SPRSDE Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    date : str
+        The date this entry superseded the others.
+    id_code : str
+        The PDB identifier of this entry.
+    superseded : list[str]
+        The PDB identifiers of the superseded entries.
+
+    """
+
+    continuation: Optional[int]
+    date: str
+    id_code: str
+    superseded: list[str]
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class SsbondRecord:
+    """A class to represent an SSBOND Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    serial : int
+        The serial number of the bond.
+    res_name1 : str
+        The name of the first residue.
+    chain_id1 : str
+        The chain identifier of the first residue.
+    seq_num1 : int
+        The sequence number of the first residue.
+    i_code1 : Optional[str]
+        The insertion code of the first residue.
+    res_name2 : str
+        The name of the second residue.
+    chain_id2 : str
+        The chain identifier of the second residue.
+    seq_num2 : int
+        The sequence number of the second residue.
+    i_code2 : Optional[str]
+        The insertion code of the second residue.
+    sym1 : Optional[str]
+        The symmetry operator applied to the first residue.
+    sym2 : Optional[str]
+        The symmetry operator applied to the second residue.
+    length : Optional[float]
+        The bond distance in Angstroms.
+
+    """
+
+    serial: int
+    res_name1: str
+    chain_id1: str
+    seq_num1: int
+    i_code1: Optional[str]
+    res_name2: str
+    chain_id2: str
+    seq_num2: int
+    i_code2: Optional[str]
+    sym1: Optional[str]
+    sym2: Optional[str]
+    length: Optional[float]
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class TermRecord:
     """A class to represent a TER Record in a PDB file.
@@ -563,6 +1381,53 @@ class TermRecord:
     serial: int
 
     def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class TitleRecord:
+    """A class to represent a TITLE Record in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    continuation : Optional[int]
+        The continuation number, absent on the first line.
+    text : str
+        The title text on this line.
+
+    """
+
+    continuation: Optional[int]
+    text: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class UnknownRecord:
+    """A class to represent Represents a line whose record name is not recognized, kept verbatim. in a PDB file.
+
+    Parameters
+    ----------
+    line : str
+        A string representing a line in the PDB file.
+
+    Attributes
+    ----------
+    tag : str
+        Record name from columns 1-6
+    line : str
+        The complete line as read
+
+    """
+
+    tag: str
+    line: str
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 class Record:
     """A class to represents a single line in a PDB file.
@@ -582,20 +1447,44 @@ class Record:
     ) -> Union[
         AnisotropicRecord,
         AtomRecord,
+        AuthorRecord,
+        CaveatRecord,
+        CispepRecord,
+        CompndRecord,
         ConnectRecord,
         CrystalRecord,
         DBRefRecord,
-        HetRecord,
+        ExpdtaRecord,
+        FormulRecord,
+        HeaderRecord,
+        HelixRecord,
         HetnamRecord,
-        MtrixnRecord,
+        HetRecord,
+        HetsynRecord,
+        KeywdsRecord,
+        LinkRecord,
+        MasterRecord,
+        MdltypRecord,
         ModelRecord,
         ModresRecord,
+        MtrixnRecord,
         NummdlRecord,
+        ObslteRecord,
         OrigxnRecord,
+        RemarkRecord,
+        RevdatRecord,
         ScalenRecord,
-        SeqresRecord,
         SeqAdvRecord,
+        SeqresRecord,
+        SheetRecord,
+        SiteRecord,
+        SourceRecord,
+        SplitRecord,
+        SprsdeRecord,
+        SsbondRecord,
         TermRecord,
+        TitleRecord,
+        UnknownRecord,
         None,
     ]:
         """Getter method that returns the specific variant of the Record.
@@ -603,15 +1492,24 @@ class Record:
         Returns
         -------
         Union[
-                AnisotropicRecord, AtomRecord, ConnectRecord, CrystalRecord,
-                DBRefRecord, HetRecrd, MtrixnRecord, ModelRecord, ModresRecord,
-                NummdlRecord, OrigxnRecord, ScalenRecord, SeqresRecord, SeqAdvRecord,
-                TermRecord, HetnamRecord, None]
+                AnisotropicRecord, AtomRecord, AuthorRecord, CaveatRecord,
+                CispepRecord, CompndRecord, ConnectRecord, CrystalRecord, DBRefRecord,
+                ExpdtaRecord, FormulRecord, HeaderRecord, HelixRecord, HetnamRecord,
+                HetRecord, HetsynRecord, KeywdsRecord, LinkRecord, MasterRecord,
+                MdltypRecord, ModelRecord, ModresRecord, MtrixnRecord, NummdlRecord,
+                ObslteRecord, OrigxnRecord, RemarkRecord, RevdatRecord, ScalenRecord,
+                SeqAdvRecord, SeqresRecord, SheetRecord, SiteRecord, SourceRecord,
+                SplitRecord, SprsdeRecord, SsbondRecord, TermRecord, TitleRecord,
+                UnknownRecord, None]
             The specific variant of the Record.
 
         """
         ...
 
+    def __str__(self) -> str:
+        """Return the record as a fixed-column PDB line."""
+        ...
+
 def pdbreader(contents: str) -> list[Record]:
     """Read the contents of a PDB file.
 
@@ -627,3 +1525,19 @@ def pdbreader(contents: str) -> list[Record]:
 
     """
     ...
+
+def pdbwriter(records: list[Record]) -> str:
+    """Write records as fixed-column PDB text.
+
+    Parameters
+    ----------
+    records : list[Record]
+        The records to write, in order.
+
+    Returns
+    -------
+    str
+        One 80-column PDB line per record, each terminated by a newline.
+
+    """
+    ...